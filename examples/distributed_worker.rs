@@ -0,0 +1,12 @@
+use anyhow::Result;
+use concurrency::{DistributedOptions, run_worker_with};
+
+// 启动一个分布式乘法工作节点：cargo run --example distributed_worker -- 127.0.0.1:7878
+fn main() -> Result<()> {
+    let addr = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "127.0.0.1:7878".to_string());
+    println!("worker listening on {addr}");
+    let options = DistributedOptions::new().on_error(|e| eprintln!("connection error: {e:?}"));
+    run_worker_with(addr, options)
+}
@@ -1,7 +1,8 @@
-use anyhow::{Result, anyhow};
-use std::sync::mpsc::Sender;
+use anyhow::Result;
+use concurrency::channel::Sender;
+use concurrency::pipeline::ProducerConsumer;
+use std::thread;
 use std::time::Duration;
-use std::{sync::mpsc, thread};
 
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -17,42 +18,35 @@ impl Message {
 }
 
 const NUM_PRODUCERS: usize = 4;
+const CHANNEL_CAPACITY: usize = 16;
+
 fn main() -> Result<()> {
-    let (tx, rx) = mpsc::channel();
+    let mut pipeline = ProducerConsumer::new(CHANNEL_CAPACITY);
 
     // 创建producers
-    for i in 0..NUM_PRODUCERS {
-        let tx = tx.clone();
-        thread::spawn(move || {
-            let _ = producer(i, tx);
-        });
-    }
-    drop(tx); // 释放 tx，否则 rx 无法结束
+    pipeline.spawn_producers(NUM_PRODUCERS, producer);
 
-    // 创建 consumer
-    let consumer = thread::spawn(move || {
-        for msg in rx {
+    // 创建 consumer，所有 producer 退出后通道关闭，consumer 随之结束
+    let secrets = pipeline.run(1, |rx| {
+        for msg in rx.iter() {
             println!("consumer: {:?}", msg);
         }
         println!("consumer exit");
         42 // 在结束时可以返回一个数据，可以是任何data struct
-    });
-    let secret = consumer
-        .join()
-        .map_err(|e| anyhow!("Thread join error: {:?}", e))?;
+    })?;
 
-    println!("secret: {secret}");
+    println!("secret: {}", secrets[0]);
 
     Ok(())
 }
 
-fn producer(index: usize, tx: Sender<Message>) -> Result<()> {
+fn producer(index: usize, tx: &Sender<Message>) -> Result<()> {
     loop {
         let value = rand::random::<i32>();
         tx.send(Message::new(index, value as usize))?;
         let sleep_time = rand::random::<u8>() as u64 * 10;
         thread::sleep(Duration::from_millis(sleep_time));
-        if rand::random::<u8>() % 5 == 0 {
+        if rand::random::<u8>().is_multiple_of(5) {
             println!("producer {} exit", index);
             break;
         }
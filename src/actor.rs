@@ -0,0 +1,210 @@
+use std::fmt;
+use std::thread::{self, JoinHandle};
+use thiserror::Error;
+
+use crate::channel::{self, Receiver, Sender, oneshot};
+
+/// 邮箱的默认容量
+pub const DEFAULT_MAILBOX_CAPACITY: usize = 64;
+
+/// Actor：独占自身状态，按顺序逐条处理邮箱中的消息
+///
+/// 与矩阵乘法中 `Msg` 携带一次性通道返回结果的做法相同，
+/// `ActorAddr::ask` 会为每条消息附带一次性通道，用于取回 `handle` 的返回值
+pub trait Actor: Send + 'static {
+    /// 消息类型
+    type Msg: Send + 'static;
+    /// 处理结果类型
+    type Reply: Send + 'static;
+
+    /// 处理一条消息
+    fn handle(&mut self, msg: Self::Msg) -> Self::Reply;
+
+    /// 开始处理消息前调用
+    fn started(&mut self) {}
+
+    /// 邮箱关闭、所有消息处理完毕后调用
+    fn stopped(&mut self) {}
+}
+
+/// Actor 通信错误
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum ActorError {
+    /// Actor 已停止，不再接收消息
+    #[error("actor has stopped")]
+    Stopped,
+    /// Actor 在处理消息时 panic
+    #[error("actor panicked")]
+    Panicked,
+}
+
+/// 邮箱中的消息，`reply` 为 `None` 时不需要返回结果
+struct Envelope<A: Actor> {
+    msg: A::Msg,
+    reply: Option<oneshot::Sender<A::Reply>>,
+}
+
+/// Actor 的地址，可以克隆后在线程间共享
+pub struct ActorAddr<A: Actor> {
+    mailbox: Sender<Envelope<A>>,
+}
+
+/// Actor 的所有权句柄，用于停止 Actor 并取回其状态
+pub struct ActorHandle<A: Actor> {
+    addr: ActorAddr<A>,
+    thread: JoinHandle<A>,
+}
+
+/// 在独立线程上启动 Actor
+///
+/// # 参数
+/// * `actor`: Actor 的初始状态
+/// * `capacity`: 邮箱容量，邮箱已满时发送方阻塞
+pub fn spawn_actor<A: Actor>(mut actor: A, capacity: usize) -> ActorHandle<A> {
+    let (mailbox, rx) = channel::bounded(capacity);
+    let thread = thread::spawn(move || {
+        run(&mut actor, rx);
+        actor
+    });
+    ActorHandle {
+        addr: ActorAddr { mailbox },
+        thread,
+    }
+}
+
+fn run<A: Actor>(actor: &mut A, rx: Receiver<Envelope<A>>) {
+    actor.started();
+    for Envelope { msg, reply } in rx.iter() {
+        let value = actor.handle(msg);
+        if let Some(reply) = reply {
+            // 调用方已放弃等待时发送失败是正常情况
+            let _ = reply.send(value);
+        }
+    }
+    actor.stopped();
+}
+
+impl<A: Actor> ActorAddr<A> {
+    /// 发送消息，不等待处理结果，邮箱已满时阻塞
+    pub fn send(&self, msg: A::Msg) -> Result<(), ActorError> {
+        self.mailbox
+            .send(Envelope { msg, reply: None })
+            .map_err(|_| ActorError::Stopped)
+    }
+
+    /// 发送消息并等待处理结果
+    pub fn ask(&self, msg: A::Msg) -> Result<A::Reply, ActorError> {
+        let (tx, rx) = oneshot::channel();
+        self.mailbox
+            .send(Envelope {
+                msg,
+                reply: Some(tx),
+            })
+            .map_err(|_| ActorError::Stopped)?;
+        rx.recv().map_err(|_| ActorError::Stopped)
+    }
+
+    /// Actor 是否已停止接收消息
+    pub fn is_stopped(&self) -> bool {
+        self.mailbox.is_closed()
+    }
+}
+
+impl<A: Actor> ActorHandle<A> {
+    /// Actor 的地址
+    pub fn addr(&self) -> ActorAddr<A> {
+        self.addr.clone()
+    }
+
+    /// 关闭邮箱，等待 Actor 处理完已收到的消息后退出，并返回其最终状态
+    ///
+    /// # 返回值
+    /// Actor 在处理消息时 panic 则返回 `ActorError::Panicked`
+    pub fn stop(self) -> Result<A, ActorError> {
+        self.addr.mailbox.close();
+        self.thread.join().map_err(|_| ActorError::Panicked)
+    }
+}
+
+impl<A: Actor> Clone for ActorAddr<A> {
+    fn clone(&self) -> Self {
+        Self {
+            mailbox: self.mailbox.clone(),
+        }
+    }
+}
+
+impl<A: Actor> fmt::Debug for ActorAddr<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ActorAddr").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[derive(Default)]
+    struct Counter {
+        total: i64,
+        stopped: bool,
+    }
+
+    impl Actor for Counter {
+        type Msg = i64;
+        type Reply = i64;
+
+        fn handle(&mut self, msg: i64) -> i64 {
+            self.total += msg;
+            self.total
+        }
+
+        fn stopped(&mut self) {
+            self.stopped = true;
+        }
+    }
+
+    #[test]
+    fn test_actor_send_and_ask() -> Result<()> {
+        let handle = spawn_actor(Counter::default(), 2);
+        let senders: Vec<_> = (0..4)
+            .map(|_| {
+                let addr = handle.addr();
+                thread::spawn(move || {
+                    for _ in 0..100 {
+                        addr.send(1).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for sender in senders {
+            sender.join().unwrap();
+        }
+        let addr = handle.addr();
+        assert_eq!(addr.ask(0)?, 400);
+
+        let counter = handle.stop()?;
+        assert_eq!(counter.total, 400);
+        assert!(counter.stopped);
+        assert!(addr.is_stopped());
+        assert_eq!(addr.send(1), Err(ActorError::Stopped));
+        Ok(())
+    }
+
+    #[test]
+    fn test_actor_panic() {
+        struct Fragile;
+        impl Actor for Fragile {
+            type Msg = ();
+            type Reply = ();
+            fn handle(&mut self, _: ()) {
+                panic!("fragile actor");
+            }
+        }
+
+        let handle = spawn_actor(Fragile, DEFAULT_MAILBOX_CAPACITY);
+        assert_eq!(handle.addr().ask(()), Err(ActorError::Stopped));
+        assert!(matches!(handle.stop(), Err(ActorError::Panicked)));
+    }
+}
@@ -0,0 +1,75 @@
+//! 工作线程的 CPU 亲和性
+//!
+//! 目前只在 Linux 上通过 `sched_setaffinity` 实现，其它平台上绑定操作不生效
+
+/// 当前进程允许运行的 CPU 核心编号，按编号升序排列
+///
+/// 无法查询时返回空列表
+pub fn available_cores() -> Vec<usize> {
+    imp::available_cores()
+}
+
+/// 将当前线程绑定到 `core` 号核心，返回是否成功
+pub fn pin_current_thread(core: usize) -> bool {
+    imp::pin_current_thread(core)
+}
+
+#[cfg(target_os = "linux")]
+mod imp {
+    use std::mem;
+
+    pub fn available_cores() -> Vec<usize> {
+        // SAFETY: cpu_set_t 是纯数据结构，全零即空集合；sched_getaffinity 只写入该结构
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            if libc::sched_getaffinity(0, mem::size_of::<libc::cpu_set_t>(), &mut set) != 0 {
+                return Vec::new();
+            }
+            (0..libc::CPU_SETSIZE as usize)
+                .filter(|&core| libc::CPU_ISSET(core, &set))
+                .collect()
+        }
+    }
+
+    pub fn pin_current_thread(core: usize) -> bool {
+        if core >= libc::CPU_SETSIZE as usize {
+            return false;
+        }
+        // SAFETY: 同上，pid 为 0 表示当前线程
+        unsafe {
+            let mut set: libc::cpu_set_t = mem::zeroed();
+            libc::CPU_SET(core, &mut set);
+            libc::sched_setaffinity(0, mem::size_of::<libc::cpu_set_t>(), &set) == 0
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod imp {
+    pub fn available_cores() -> Vec<usize> {
+        Vec::new()
+    }
+
+    pub fn pin_current_thread(_core: usize) -> bool {
+        false
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pin_current_thread() {
+        let cores = available_cores();
+        assert!(!cores.is_empty());
+        let core = *cores.last().unwrap();
+        let pinned = std::thread::spawn(move || {
+            assert!(pin_current_thread(core));
+            available_cores()
+        })
+        .join()
+        .unwrap();
+        assert_eq!(pinned, vec![core]);
+    }
+}
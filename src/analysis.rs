@@ -0,0 +1,371 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::error::MatrixError;
+use crate::linalg::{lu_decompose, symmetric_eigen};
+use crate::matrix::{Matrix, NUM_THREADS, columns, multiply};
+use crate::par;
+use crate::pool::ThreadPool;
+
+/// 样本协方差矩阵
+///
+/// 先用并行的列归约求出每个特征的均值并按行并行中心化，
+/// 再用并发矩阵乘法计算中心化数据的格拉姆矩阵 `Xcᵀ·Xc`，最后除以 `n - 1`
+///
+/// # 参数
+/// * `data`: 数据矩阵，每行一个样本、每列一个特征
+///
+/// # 返回值
+/// 返回 `特征数 × 特征数` 的对称矩阵，样本数少于 2 时结果全为零
+pub fn covariance(data: &Matrix<f64>) -> Result<Matrix<f64>, MatrixError> {
+    let pool = ThreadPool::new(NUM_THREADS);
+    let mean = col_means(data, &pool)?;
+    centered_covariance(&center(data, mean, &pool)?, &pool)
+}
+
+/// 主成分分析的结果，由 `pca` 返回
+///
+/// # 字段
+/// * `components`: 主成分，`k × 特征数`，每行一个单位向量，按解释的方差从大到小排列
+/// * `explained_variance`: 每个主成分方向上的样本方差
+/// * `mean`: 每个特征的均值，投影新数据前需要先减去
+/// * `projected`: 中心化后的数据在主成分上的投影，`样本数 × k`
+#[derive(Debug, PartialEq)]
+pub struct Pca {
+    pub components: Matrix<f64>,
+    pub explained_variance: Vec<f64>,
+    pub mean: Vec<f64>,
+    pub projected: Matrix<f64>,
+}
+
+impl Pca {
+    /// 用同样的均值和主成分投影新数据
+    ///
+    /// # 返回值
+    /// 列数与特征数不一致时返回 `MatrixError::DimensionMismatch`
+    pub fn transform(&self, data: &Matrix<f64>) -> Result<Matrix<f64>, MatrixError> {
+        if data.col != self.mean.len() {
+            return Err(MatrixError::DimensionMismatch {
+                a: (data.row, data.col),
+                b: (self.components.col, self.components.row),
+            });
+        }
+        let pool = ThreadPool::new(NUM_THREADS);
+        let centered = center(data, self.mean.clone(), &pool)?;
+        multiply(&centered, &self.components.transpose())
+    }
+}
+
+/// 主成分分析
+///
+/// 由 `covariance` 同样的并行中心化和格拉姆矩阵得到协方差矩阵，
+/// 用 `symmetric_eigen` 分解后取前 `k` 个特征向量，再用并发矩阵乘法把中心化数据投影到主成分上
+///
+/// # 参数
+/// * `data`: 数据矩阵，每行一个样本、每列一个特征
+/// * `k`: 保留的主成分个数，超过特征数时按特征数计算
+pub fn pca(data: &Matrix<f64>, k: usize) -> Result<Pca, MatrixError> {
+    let pool = ThreadPool::new(NUM_THREADS);
+    let mean = col_means(data, &pool)?;
+    let centered = center(data, mean.clone(), &pool)?;
+    let eigen = symmetric_eigen(&centered_covariance(&centered, &pool)?)?;
+
+    let k = k.min(data.col);
+    // 特征向量按列排列，转置后前 k 行即为主成分
+    let components = Matrix {
+        data: columns(&eigen.vectors)[..k * data.col].to_vec(),
+        row: k,
+        col: data.col,
+    };
+    let projected = multiply(&centered, &components.transpose())?;
+    Ok(Pca {
+        explained_variance: eigen.values[..k].to_vec(),
+        components,
+        mean,
+        projected,
+    })
+}
+
+/// 线性回归的结果，由 `linreg` 返回
+///
+/// # 字段
+/// * `coefficients`: 每个特征的回归系数
+/// * `residual_norm`: 残差 `y - X·β` 的欧几里得范数
+#[derive(Debug, Clone, PartialEq)]
+pub struct LinearRegression {
+    pub coefficients: Vec<f64>,
+    pub residual_norm: f64,
+}
+
+/// 用正规方程求解最小二乘线性回归
+///
+/// 用并发矩阵乘法计算 `XᵀX` 和 `Xᵀy`，再用 LU 分解求解 `(XᵀX)·β = Xᵀy`。
+/// 不自动加入截距项，需要截距时在 `x` 中加入一列全为 1 的特征
+///
+/// # 参数
+/// * `x`: 特征矩阵，每行一个样本
+/// * `y`: 目标值，长度必须等于样本数
+///
+/// # 返回值
+/// 长度不匹配时返回 `MatrixError::LengthMismatch`，
+/// 特征线性相关导致 `XᵀX` 奇异时返回 `MatrixError::Singular`
+pub fn linreg(x: &Matrix<f64>, y: &[f64]) -> Result<LinearRegression, MatrixError> {
+    if x.row != y.len() {
+        return Err(MatrixError::LengthMismatch {
+            a: x.row,
+            b: y.len(),
+        });
+    }
+    let xt = x.transpose();
+    let y = Matrix {
+        data: y.to_vec(),
+        row: y.len(),
+        col: 1,
+    };
+    let xtx = multiply(&xt, x)?;
+    let xty = multiply(&xt, &y)?;
+    let coefficients = lu_decompose(&xtx)?.solve(&xty.data)?;
+
+    let fitted = multiply(
+        x,
+        &Matrix {
+            data: coefficients.clone(),
+            row: coefficients.len(),
+            col: 1,
+        },
+    )?;
+    let residual_norm = y
+        .data
+        .iter()
+        .zip(&fitted.data)
+        .map(|(y, f)| (y - f) * (y - f))
+        .sum::<f64>()
+        .sqrt();
+    Ok(LinearRegression {
+        coefficients,
+        residual_norm,
+    })
+}
+
+/// 高斯随机投影降维
+///
+/// 生成 `特征数 × target_dim` 的随机矩阵，元素独立服从均值为 0、方差为 `1 / target_dim` 的正态分布，
+/// 使投影前后样本间的距离在期望意义下保持不变，再用并发矩阵乘法完成投影。
+/// 相同的 `seed` 总是生成相同的随机矩阵，因此用同一个种子投影新数据可以得到一致的结果
+///
+/// # 参数
+/// * `data`: 数据矩阵，每行一个样本
+/// * `target_dim`: 投影后的维数
+/// * `seed`: 随机数种子
+///
+/// # 返回值
+/// 返回 `样本数 × target_dim` 的投影结果
+pub fn random_projection(
+    data: &Matrix<f64>,
+    target_dim: usize,
+    seed: u64,
+) -> Result<Matrix<f64>, MatrixError> {
+    random_projection_with_rng(data, target_dim, &mut StdRng::seed_from_u64(seed))
+}
+
+/// 使用调用方提供的随机数生成器进行随机投影，其余与 `random_projection` 相同
+///
+/// # 参数
+/// * `data`: 数据矩阵，每行一个样本
+/// * `target_dim`: 投影后的维数
+/// * `rng`: 生成投影矩阵的随机数生成器
+pub fn random_projection_with_rng<R: Rng + ?Sized>(
+    data: &Matrix<f64>,
+    target_dim: usize,
+    rng: &mut R,
+) -> Result<Matrix<f64>, MatrixError> {
+    let std = 1.0 / (target_dim.max(1) as f64).sqrt();
+    let projection = Matrix {
+        data: (0..data.col * target_dim)
+            .map(|_| standard_normal(rng) * std)
+            .collect(),
+        row: data.col,
+        col: target_dim,
+    };
+    multiply(data, &projection)
+}
+
+/// 用 Box-Muller 变换生成一个标准正态分布的随机数
+fn standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    // 1 - [0, 1) 落在 (0, 1]，避免对零取对数
+    let u = 1.0 - rng.random::<f64>();
+    let v = rng.random::<f64>();
+    (-2.0 * u.ln()).sqrt() * (2.0 * std::f64::consts::PI * v).cos()
+}
+
+/// 每列的均值，空列的均值为零
+fn col_means(data: &Matrix<f64>, pool: &ThreadPool) -> Result<Vec<f64>, MatrixError> {
+    par::map_cols(data, pool, |col: &[f64]| {
+        col.iter().sum::<f64>() / col.len().max(1) as f64
+    })
+}
+
+/// 每列减去对应的均值
+fn center(
+    data: &Matrix<f64>,
+    mean: Vec<f64>,
+    pool: &ThreadPool,
+) -> Result<Matrix<f64>, MatrixError> {
+    par::map_rows(data, pool, move |row| {
+        for (x, mean) in row.iter_mut().zip(&mean) {
+            *x -= mean;
+        }
+    })
+}
+
+/// 由中心化的数据计算样本协方差矩阵 `Xcᵀ·Xc / (n - 1)`
+fn centered_covariance(
+    centered: &Matrix<f64>,
+    pool: &ThreadPool,
+) -> Result<Matrix<f64>, MatrixError> {
+    let gram = multiply(&centered.transpose(), centered)?;
+    let scale = 1.0 / centered.row.saturating_sub(1).max(1) as f64;
+    par::map_rows(&gram, pool, move |row| {
+        row.iter_mut().for_each(|x| *x *= scale);
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_covariance() -> Result<()> {
+        // 第二列是第一列的两倍，第三列为常数
+        let data = Matrix::new(
+            [1.0, 2.0, 7.0, 2.0, 4.0, 7.0, 3.0, 6.0, 7.0, 6.0, 12.0, 7.0],
+            4,
+            3,
+        );
+        let cov = covariance(&data)?;
+        // 第一列均值 3，离差平方和 14，样本方差 14 / 3
+        let var = 14.0 / 3.0;
+        let expected = [
+            var,
+            2.0 * var,
+            0.0,
+            2.0 * var,
+            4.0 * var,
+            0.0,
+            0.0,
+            0.0,
+            0.0,
+        ];
+        assert_eq!((cov.row, cov.col), (3, 3));
+        for (x, e) in cov.data.iter().zip(expected) {
+            assert!((x - e).abs() < 1e-12);
+        }
+
+        let single = covariance(&Matrix::new([1.0, 2.0], 1, 2))?;
+        assert_eq!(single, Matrix::new([0.0; 4], 2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pca() -> Result<()> {
+        // 样本分布在直线 y = 2x 附近，第一主成分接近 (1, 2) / √5
+        let data = Matrix::new(
+            [1.0, 2.1, 2.0, 3.9, 3.0, 6.1, 4.0, 7.9, 5.0, 10.0, 6.0, 12.0],
+            6,
+            2,
+        );
+        let result = pca(&data, 1)?;
+        assert_eq!((result.components.row, result.components.col), (1, 2));
+        let direction = [1.0 / 5f64.sqrt(), 2.0 / 5f64.sqrt()];
+        for (c, d) in result.components.data.iter().zip(direction) {
+            assert!((c - d).abs() < 1e-2);
+        }
+        assert_eq!(result.mean, [3.5, 7.0]);
+        assert_eq!((result.projected.row, result.projected.col), (6, 1));
+
+        // 投影后的方差等于对应的特征值
+        let p = &result.projected.data;
+        let var = p.iter().map(|x| x * x).sum::<f64>() / 5.0;
+        assert!((var - result.explained_variance[0]).abs() < 1e-9);
+        assert_eq!(result.transform(&data)?, result.projected);
+
+        // k 超过特征数时保留全部主成分，总方差不变
+        let full = pca(&data, 5)?;
+        let total = covariance(&data)?;
+        assert_eq!(full.explained_variance.len(), 2);
+        let trace = total.data[0] + total.data[3];
+        assert!((full.explained_variance.iter().sum::<f64>() - trace).abs() < 1e-9);
+        assert!(result.transform(&Matrix::new([1.0], 1, 1)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_linreg() -> Result<()> {
+        // y = 1 + 2·x1 - 3·x2，第一列为截距
+        let x = Matrix::new(
+            [
+                1.0, 0.0, 0.0, 1.0, 1.0, 0.0, 1.0, 0.0, 1.0, 1.0, 2.0, 1.0, 1.0, 3.0, 5.0,
+            ],
+            5,
+            3,
+        );
+        let y = [1.0, 3.0, -2.0, 2.0, -8.0];
+        let fit = linreg(&x, &y)?;
+        for (b, e) in fit.coefficients.iter().zip([1.0, 2.0, -3.0]) {
+            assert!((b - e).abs() < 1e-9);
+        }
+        assert!(fit.residual_norm < 1e-9);
+
+        // 有噪声时残差为正
+        let noisy = [1.5, 3.0, -2.0, 2.0, -8.0];
+        assert!(linreg(&x, &noisy)?.residual_norm > 0.1);
+
+        assert!(matches!(
+            linreg(&x, &y[..4]),
+            Err(MatrixError::LengthMismatch { a: 5, b: 4 })
+        ));
+        let collinear = Matrix::new([1.0, 2.0, 2.0, 4.0, 3.0, 6.0], 3, 2);
+        assert_eq!(
+            linreg(&collinear, &[1.0, 2.0, 3.0]),
+            Err(MatrixError::Singular)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_projection() -> Result<()> {
+        let data = Matrix::new((0..200).map(|x| (x % 17) as f64).collect::<Vec<_>>(), 4, 50);
+        let projected = random_projection(&data, 8, 42)?;
+        assert_eq!((projected.row, projected.col), (4, 8));
+        assert_eq!(random_projection(&data, 8, 42)?, projected);
+        assert_ne!(random_projection(&data, 8, 7)?, projected);
+
+        // 维数较高时样本间距离大致保持
+        let data = Matrix::new(
+            (0..2 * 400)
+                .map(|x| ((x * 37) % 101) as f64)
+                .collect::<Vec<_>>(),
+            2,
+            400,
+        );
+        let distance = |m: &Matrix<f64>| {
+            (0..m.col)
+                .map(|j| (m.data[j] - m.data[m.col + j]).powi(2))
+                .sum::<f64>()
+                .sqrt()
+        };
+        let ratio = distance(&random_projection(&data, 256, 1)?) / distance(&data);
+        assert!((0.7..1.3).contains(&ratio));
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let samples = (0..10_000)
+            .map(|_| standard_normal(&mut rng))
+            .collect::<Vec<_>>();
+        let mean = samples.iter().sum::<f64>() / 10_000.0;
+        let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / 10_000.0;
+        assert!(mean.abs() < 0.05);
+        assert!((var - 1.0).abs() < 0.05);
+        Ok(())
+    }
+}
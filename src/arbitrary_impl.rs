@@ -0,0 +1,93 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+use std::fmt;
+
+use crate::matrix::Matrix;
+
+/// `Arbitrary` 生成的矩阵行数和列数的上限
+pub const ARBITRARY_MAX_DIM: usize = 16;
+
+/// 可以相乘的一对矩阵，`a` 为 `m×k`，`b` 为 `k×n`
+///
+/// # 字段
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+pub struct Multiplicable<T> {
+    pub a: Matrix<T>,
+    pub b: Matrix<T>,
+}
+
+impl<T: fmt::Display> fmt::Debug for Multiplicable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Multiplicable")
+            .field("a", &self.a)
+            .field("b", &self.b)
+            .finish()
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Matrix<T> {
+    /// 行数和列数取自 `0..=ARBITRARY_MAX_DIM`，包含空矩阵和单行单列等边界形状
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let row = u.int_in_range(0..=ARBITRARY_MAX_DIM)?;
+        let col = u.int_in_range(0..=ARBITRARY_MAX_DIM)?;
+        shaped(u, row, col)
+    }
+}
+
+impl<'a, T: Arbitrary<'a>> Arbitrary<'a> for Multiplicable<T> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let m = u.int_in_range(0..=ARBITRARY_MAX_DIM)?;
+        let k = u.int_in_range(0..=ARBITRARY_MAX_DIM)?;
+        let n = u.int_in_range(0..=ARBITRARY_MAX_DIM)?;
+        Ok(Self {
+            a: shaped(u, m, k)?,
+            b: shaped(u, k, n)?,
+        })
+    }
+}
+
+/// 生成 `row×col` 的矩阵，元素依次取自 `u`
+fn shaped<'a, T: Arbitrary<'a>>(
+    u: &mut Unstructured<'a>,
+    row: usize,
+    col: usize,
+) -> Result<Matrix<T>> {
+    let data = (0..row * col)
+        .map(|_| T::arbitrary(u))
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Matrix { data, row, col })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{multiply, multiply_seq};
+
+    #[test]
+    fn test_arbitrary_matrix() -> anyhow::Result<()> {
+        let bytes = (0..4096).map(|i| (i * 31 % 251) as u8).collect::<Vec<_>>();
+        let mut u = Unstructured::new(&bytes);
+        for _ in 0..20 {
+            let m = Matrix::<i8>::arbitrary(&mut u)?;
+            assert!(m.row <= ARBITRARY_MAX_DIM && m.col <= ARBITRARY_MAX_DIM);
+            assert_eq!(m.data.len(), m.row * m.col);
+
+            let Multiplicable { a, b } = Multiplicable::<i8>::arbitrary(&mut u)?;
+            assert_eq!(a.col, b.row);
+            let (a, b) = (
+                Matrix::new(
+                    a.data.iter().map(|&v| v as i64).collect::<Vec<_>>(),
+                    a.row,
+                    a.col,
+                ),
+                Matrix::new(
+                    b.data.iter().map(|&v| v as i64).collect::<Vec<_>>(),
+                    b.row,
+                    b.col,
+                ),
+            );
+            assert_eq!(multiply(&a, &b)?, multiply_seq(&a, &b)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,367 @@
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{AddAssign, Mul};
+use num_traits::Zero;
+use num_traits::float::FloatCore;
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// 带状矩阵
+///
+/// 只存储主对角线以下 `lower` 条、以上 `upper` 条对角线，按行保存：
+/// 第 `i` 行存放第 `i - lower` 到 `i + upper` 列的元素，超出矩阵范围的位置补零。
+/// `n` 阶矩阵只占用 `n × (lower + upper + 1)` 个元素
+///
+/// # 字段
+/// * `data`: 按行排列的带内元素
+/// * `n`: 矩阵阶数
+/// * `lower`: 下带宽
+/// * `upper`: 上带宽
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix<T> {
+    data: Vec<T>,
+    n: usize,
+    lower: usize,
+    upper: usize,
+}
+
+impl<T: Clone + Zero> BandedMatrix<T> {
+    /// 创建带内元素全为零的 `n` 阶带状矩阵
+    ///
+    /// 超过 `n - 1` 的带宽没有意义，会被截断为 `n - 1`
+    ///
+    /// # 参数
+    /// * `n`: 矩阵阶数
+    /// * `lower`: 下带宽
+    /// * `upper`: 上带宽
+    ///
+    /// # Panics
+    /// 带内元素个数超出 `usize` 范围时 panic
+    pub fn zeros(n: usize, lower: usize, upper: usize) -> Self {
+        let max = n.saturating_sub(1);
+        let (lower, upper) = (lower.min(max), upper.min(max));
+        let len = (lower + upper)
+            .checked_add(1)
+            .and_then(|width| width.checked_mul(n))
+            .expect("banded matrix size overflows usize");
+        Self {
+            data: vec![T::zero(); len],
+            n,
+            lower,
+            upper,
+        }
+    }
+
+    /// 由三条对角线创建三对角矩阵
+    ///
+    /// # 参数
+    /// * `lower`: 主对角线下方的对角线，长度为 `n - 1`
+    /// * `diag`: 主对角线，长度为 `n`
+    /// * `upper`: 主对角线上方的对角线，长度为 `n - 1`
+    ///
+    /// # 返回值
+    /// 对角线长度不匹配时返回 `MatrixError::LengthMismatch`
+    pub fn tridiagonal(lower: &[T], diag: &[T], upper: &[T]) -> Result<Self, MatrixError> {
+        let n = diag.len();
+        let off = n.saturating_sub(1);
+        for side in [lower, upper] {
+            if side.len() != off {
+                return Err(MatrixError::LengthMismatch {
+                    a: off,
+                    b: side.len(),
+                });
+            }
+        }
+        let mut m = Self::zeros(n, 1, 1);
+        for i in 0..n {
+            m.data[i * 3 + 1] = diag[i].clone();
+            if i > 0 {
+                m.data[i * 3] = lower[i - 1].clone();
+            }
+            if i + 1 < n {
+                m.data[i * 3 + 2] = upper[i].clone();
+            }
+        }
+        Ok(m)
+    }
+
+    /// 由稠密方阵创建带状矩阵
+    ///
+    /// # 返回值
+    /// 矩阵不是方阵时返回 `MatrixError::ShapeMismatch`，
+    /// 带外有非零元素时返回 `MatrixError::InvalidSparse`
+    pub fn from_dense(m: &Matrix<T>, lower: usize, upper: usize) -> Result<Self, MatrixError> {
+        let n = m.row;
+        if m.col != n {
+            return Err(MatrixError::ShapeMismatch {
+                expected: (n, n),
+                actual: (m.row, m.col),
+            });
+        }
+        let mut banded = Self::zeros(n, lower, upper);
+        for i in 0..n {
+            for j in 0..n {
+                let value = &m.data[i * n + j];
+                match banded.slot(i, j) {
+                    Some(slot) => banded.data[slot] = value.clone(),
+                    None if !value.is_zero() => {
+                        return Err(MatrixError::InvalidSparse(format!(
+                            "({}, {}) is outside the band ({} lower, {} upper) but nonzero",
+                            i, j, lower, upper
+                        )));
+                    }
+                    None => {}
+                }
+            }
+        }
+        Ok(banded)
+    }
+
+    /// 转换为稠密矩阵
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.n, self.n);
+        for i in 0..self.n {
+            for j in self.band(i) {
+                m.data[i * self.n + j] = self.data[self.index(i, j)].clone();
+            }
+        }
+        m
+    }
+}
+
+impl<T> BandedMatrix<T> {
+    /// 矩阵阶数
+    pub fn size(&self) -> usize {
+        self.n
+    }
+
+    /// 下带宽和上带宽
+    pub fn bandwidth(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    /// 读取第 `i` 行第 `j` 列的元素，位置在带外或越界时返回 None
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        self.slot(i, j).map(|slot| &self.data[slot])
+    }
+
+    /// 设置第 `i` 行第 `j` 列的元素
+    ///
+    /// # 返回值
+    /// 位置在带外或越界时返回 `MatrixError::IndexOutOfBounds`
+    pub fn set(&mut self, i: usize, j: usize, value: T) -> Result<(), MatrixError> {
+        let slot = self.slot(i, j).ok_or(MatrixError::IndexOutOfBounds {
+            index: (i, j),
+            shape: (self.n, self.n),
+        })?;
+        self.data[slot] = value;
+        Ok(())
+    }
+
+    /// 第 `i` 行在带内的列范围
+    fn band(&self, i: usize) -> core::ops::Range<usize> {
+        i.saturating_sub(self.lower)..(i + self.upper + 1).min(self.n)
+    }
+
+    /// 带内位置 `(i, j)` 在 `data` 中的下标，调用方保证位置在带内
+    fn index(&self, i: usize, j: usize) -> usize {
+        i * (self.lower + self.upper + 1) + j + self.lower - i
+    }
+
+    fn slot(&self, i: usize, j: usize) -> Option<usize> {
+        (i < self.n && self.band(i).contains(&j)).then(|| self.index(i, j))
+    }
+}
+
+impl<T> BandedMatrix<T>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    /// 计算矩阵乘以向量，只访问带内元素
+    ///
+    /// # 返回值
+    /// 向量长度与阶数不一致时返回 `MatrixError::LengthMismatch`
+    pub fn mul_vec(&self, x: &[T]) -> Result<Vec<T>, MatrixError> {
+        if x.len() != self.n {
+            return Err(MatrixError::LengthMismatch {
+                a: self.n,
+                b: x.len(),
+            });
+        }
+        Ok((0..self.n)
+            .map(|i| {
+                let mut sum = T::zero();
+                for j in self.band(i) {
+                    sum += self.data[self.index(i, j)].clone() * x[j].clone();
+                }
+                sum
+            })
+            .collect())
+    }
+}
+
+impl<T: FloatCore> BandedMatrix<T> {
+    /// 求解 `A·x = b`
+    ///
+    /// 用带部分选主元的带状高斯消元：每一步在主元下方的带内选绝对值最大的元素作为主元，
+    /// 行交换最多使上带宽增加 `lower`，因此在上带宽加宽的副本上消元，
+    /// 耗时为 O(n × 下带宽 × (下带宽 + 上带宽))
+    ///
+    /// # 返回值
+    /// `b` 的长度与阶数不一致时返回 `MatrixError::LengthMismatch`，
+    /// 主元相对矩阵最大元素小到数值上不可靠时返回 `MatrixError::Singular`
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let n = self.n;
+        if b.len() != n {
+            return Err(MatrixError::LengthMismatch { a: n, b: b.len() });
+        }
+        let mut a = Self::zeros(n, self.lower, self.lower + self.upper);
+        for i in 0..n {
+            for j in self.band(i) {
+                let slot = a.index(i, j);
+                a.data[slot] = self.data[self.index(i, j)];
+            }
+        }
+        let max = self.data.iter().fold(T::zero(), |max, x| max.max(x.abs()));
+        let tolerance = max * num_traits::cast(n).unwrap_or_else(T::max_value) * T::epsilon();
+        let mut x = b.to_vec();
+
+        for k in 0..n {
+            let rows = k..(k + self.lower + 1).min(n);
+            // 选当前列带内绝对值最大的元素作为主元
+            let pivot = rows
+                .clone()
+                .max_by(|&i, &j| {
+                    let (p, q) = (a.data[a.index(i, k)].abs(), a.data[a.index(j, k)].abs());
+                    p.partial_cmp(&q).unwrap_or(Ordering::Equal)
+                })
+                .unwrap_or(k);
+            if a.data[a.index(pivot, k)].abs() <= tolerance {
+                return Err(MatrixError::Singular);
+            }
+            // 第 k 行之前的列在剩余各行中已经消为零，只需交换 k 之后的带内元素
+            let cols = k..(k + a.upper + 1).min(n);
+            if pivot != k {
+                for j in cols.clone() {
+                    let (kj, pj) = (a.index(k, j), a.index(pivot, j));
+                    a.data.swap(kj, pj);
+                }
+                x.swap(k, pivot);
+            }
+            let diag = a.data[a.index(k, k)];
+            for i in rows.skip(1) {
+                let factor = a.data[a.index(i, k)] / diag;
+                for j in cols.clone() {
+                    let (ij, kj) = (a.index(i, j), a.index(k, j));
+                    a.data[ij] = a.data[ij] - factor * a.data[kj];
+                }
+                x[i] = x[i] - factor * x[k];
+            }
+        }
+        for i in (0..n).rev() {
+            let mut sum = x[i];
+            let end = (i + a.upper + 1).min(n);
+            for (j, xj) in x.iter().enumerate().take(end).skip(i + 1) {
+                sum = sum - a.data[a.index(i, j)] * *xj;
+            }
+            x[i] = sum / a.data[a.index(i, i)];
+        }
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_banded_storage() -> Result<()> {
+        let dense = Matrix::new([1, 2, 0, 0, 3, 4, 5, 0, 0, 6, 7, 8, 0, 0, 9, 10], 4, 4);
+        let banded = BandedMatrix::from_dense(&dense, 1, 1)?;
+        assert_eq!(banded.bandwidth(), (1, 1));
+        assert_eq!(banded.get(2, 1), Some(&6));
+        assert_eq!(banded.get(0, 2), None);
+        assert_eq!(banded.to_dense(), dense);
+        assert_eq!(
+            banded,
+            BandedMatrix::tridiagonal(&[3, 6, 9], &[1, 4, 7, 10], &[2, 5, 8])?
+        );
+        assert_eq!(banded.mul_vec(&[1, 1, 1, 1])?, [3, 12, 21, 19]);
+
+        let mut upper = BandedMatrix::zeros(3, 0, 2);
+        upper.set(0, 2, 5)?;
+        assert!(upper.set(1, 0, 1).is_err());
+        assert_eq!(
+            upper.to_dense(),
+            Matrix::new([0, 0, 5, 0, 0, 0, 0, 0, 0], 3, 3)
+        );
+
+        assert!(matches!(
+            BandedMatrix::from_dense(&dense, 0, 1),
+            Err(MatrixError::InvalidSparse(_))
+        ));
+        assert!(BandedMatrix::tridiagonal(&[1], &[1, 2, 3], &[1, 2]).is_err());
+        // 超过阶数的带宽会被截断
+        let wide = BandedMatrix::<i32>::zeros(3, usize::MAX, usize::MAX);
+        assert_eq!(wide.bandwidth(), (2, 2));
+        assert!(banded.mul_vec(&[1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_banded_solve() -> Result<()> {
+        // 一维泊松方程的有限差分矩阵
+        let n = 6;
+        let a = BandedMatrix::tridiagonal(&[-1.0; 5], &[2.0; 6], &[-1.0; 5])?;
+        let expected = (0..n).map(|i| i as f64 + 1.0).collect::<Vec<_>>();
+        let b = a.mul_vec(&expected)?;
+        let x = a.solve(&b)?;
+        for (x, e) in x.iter().zip(&expected) {
+            assert!((x - e).abs() < 1e-12);
+        }
+
+        // 非对称的更宽带
+        let dense = Matrix::new(
+            [
+                4.0, 1.0, 2.0, 0.0, 1.0, 5.0, 1.0, 2.0, 0.0, 1.0, 6.0, 1.0, 0.0, 0.0, 2.0, 7.0,
+            ],
+            4,
+            4,
+        );
+        let banded = BandedMatrix::from_dense(&dense, 1, 2)?;
+        let x = banded.solve(&banded.mul_vec(&[1.0, -1.0, 2.0, 0.5])?)?;
+        for (x, e) in x.iter().zip([1.0f64, -1.0, 2.0, 0.5]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+
+        // 主对角线为零但非奇异，需要行交换
+        let swapped = BandedMatrix::tridiagonal(&[1.0], &[0.0, 0.0], &[1.0])?;
+        assert_eq!(swapped.solve(&[2.0, 3.0])?, [3.0, 2.0]);
+
+        // 行交换使上带宽增加，消元不能越过加宽后的带
+        let dense = Matrix::new(
+            [
+                1e-3, 1.0, 0.0, 0.0, 2.0, 1.0, 3.0, 0.0, 0.0, 4.0, 1.0, 5.0, 0.0, 0.0, 6.0, 1.0,
+            ],
+            4,
+            4,
+        );
+        let banded = BandedMatrix::from_dense(&dense, 1, 1)?;
+        let expected = [1.0, 2.0, -1.0, 3.0];
+        let x = banded.solve(&banded.mul_vec(&expected)?)?;
+        for (x, e) in x.iter().zip(expected) {
+            assert!((x - e).abs() < 1e-12);
+        }
+
+        let singular = BandedMatrix::tridiagonal(&[1.0], &[1.0, 1.0], &[1.0])?;
+        assert_eq!(singular.solve(&[1.0, 1.0]), Err(MatrixError::Singular));
+        // 数值上接近奇异的主元同样拒绝
+        let tiny = BandedMatrix::tridiagonal(&[1.0], &[1.0, 1.0 + f64::EPSILON], &[1.0])?;
+        assert_eq!(tiny.solve(&[1.0, 1.0]), Err(MatrixError::Singular));
+        Ok(())
+    }
+}
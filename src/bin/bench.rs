@@ -0,0 +1,217 @@
+//! 比较不同矩阵规模、线程数和任务粒度下的乘法吞吐量
+//!
+//! ```text
+//! bench [--sizes 64,128,256] [--threads 1,2,4] [--strategies row,cell,block:32] [--repeat 3]
+//! ```
+//!
+//! 每个组合重复 `repeat` 次取最快的一次，按 `2·n³ / 耗时` 计算 GFLOP/s，
+//! 第一行为不使用线程池的串行实现，作为计算加速比的基准
+
+use anyhow::{Context, Result, anyhow, bail};
+use concurrency::{
+    ChunkStrategy, Matrix, MultiplyOptions, ThreadPool, multiply_sequential, multiply_with,
+};
+use rand::distr::Uniform;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "usage: bench [--sizes N,..] [--threads N,..] [--strategies row|cell|block:TILE,..] [--repeat N]";
+
+/// 命令行参数
+#[derive(Debug, PartialEq)]
+struct Args {
+    sizes: Vec<usize>,
+    threads: Vec<usize>,
+    strategies: Vec<ChunkStrategy>,
+    repeat: usize,
+}
+
+/// 一个组合的测量结果，`threads` 为 0 表示串行基准
+#[derive(Debug)]
+struct Sample {
+    size: usize,
+    threads: usize,
+    strategy: Option<ChunkStrategy>,
+    best: Duration,
+}
+
+impl Sample {
+    fn gflops(&self) -> f64 {
+        let flops = 2.0 * (self.size as f64).powi(3);
+        flops / self.best.as_secs_f64().max(f64::MIN_POSITIVE) / 1e9
+    }
+}
+
+fn main() -> Result<()> {
+    let args = parse_args(std::env::args().skip(1))?;
+    println!(
+        "{:>6} {:>8} {:>12} {:>12} {:>10} {:>8}",
+        "size", "threads", "strategy", "best", "GFLOP/s", "speedup"
+    );
+    for size in &args.sizes {
+        let samples = run_size(*size, &args)?;
+        let baseline = samples[0].best.as_secs_f64();
+        for s in &samples {
+            let (threads, strategy) = match s.strategy {
+                Some(strategy) => (s.threads.to_string(), strategy_name(strategy)),
+                None => ("-".into(), "sequential".into()),
+            };
+            println!(
+                "{:>6} {:>8} {:>12} {:>12.3?} {:>10.3} {:>7.2}x",
+                s.size,
+                threads,
+                strategy,
+                s.best,
+                s.gflops(),
+                baseline / s.best.as_secs_f64().max(f64::MIN_POSITIVE)
+            );
+        }
+    }
+    Ok(())
+}
+
+/// 测量一个规模下的串行基准和所有线程数与粒度的组合
+fn run_size(size: usize, args: &Args) -> Result<Vec<Sample>> {
+    let dist = Uniform::new(-1.0, 1.0)?;
+    let a = Matrix::<f64>::random_seeded(size, size, &dist, 1);
+    let b = Matrix::<f64>::random_seeded(size, size, &dist, 2);
+
+    let mut samples = vec![Sample {
+        size,
+        threads: 0,
+        strategy: None,
+        best: best_of(args.repeat, || multiply_sequential(&a, &b).map(drop))?,
+    }];
+    for &threads in &args.threads {
+        let pool = ThreadPool::new(threads);
+        for &strategy in &args.strategies {
+            let best = best_of(args.repeat, || {
+                let options = MultiplyOptions::new()
+                    .pool(&pool)
+                    .sequential_threshold(0)
+                    .chunk_strategy(strategy);
+                multiply_with(&a, &b, options).map(drop)
+            })?;
+            samples.push(Sample {
+                size,
+                threads,
+                strategy: Some(strategy),
+                best,
+            });
+        }
+    }
+    Ok(samples)
+}
+
+fn best_of<E>(repeat: usize, mut f: impl FnMut() -> Result<(), E>) -> Result<Duration>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let mut best = Duration::MAX;
+    for _ in 0..repeat {
+        let start = Instant::now();
+        f()?;
+        best = best.min(start.elapsed());
+    }
+    Ok(best)
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args> {
+    let mut parsed = Args {
+        sizes: vec![64, 128, 256],
+        threads: vec![1, 2, 4],
+        strategies: vec![
+            ChunkStrategy::PerRow,
+            ChunkStrategy::PerCell,
+            ChunkStrategy::Blocked { tile: 32 },
+        ],
+        repeat: 3,
+    };
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let value = args
+            .next()
+            .ok_or_else(|| anyhow!("{arg} needs a value\n{USAGE}"))?;
+        match arg.as_str() {
+            "--sizes" => parsed.sizes = parse_list(&value, |s| Ok(s.parse()?))?,
+            "--threads" => parsed.threads = parse_list(&value, |s| Ok(s.parse()?))?,
+            "--strategies" => parsed.strategies = parse_list(&value, parse_strategy)?,
+            "--repeat" => parsed.repeat = value.parse().context("invalid --repeat")?,
+            _ => bail!("unknown option {arg}\n{USAGE}"),
+        }
+    }
+    if parsed.threads.contains(&0) || parsed.repeat == 0 {
+        bail!("--threads and --repeat must be at least 1");
+    }
+    Ok(parsed)
+}
+
+fn parse_list<T>(s: &str, parse: impl Fn(&str) -> Result<T>) -> Result<Vec<T>> {
+    s.split(',')
+        .map(|item| parse(item.trim()).with_context(|| format!("invalid list item {item:?}")))
+        .collect()
+}
+
+/// 解析 `row`、`cell` 或 `block:TILE`
+fn parse_strategy(s: &str) -> Result<ChunkStrategy> {
+    match s {
+        "row" => Ok(ChunkStrategy::PerRow),
+        "cell" => Ok(ChunkStrategy::PerCell),
+        _ => {
+            let tile = s.strip_prefix("block:").ok_or_else(|| {
+                anyhow!("unknown strategy {s:?}, expected row, cell or block:TILE")
+            })?;
+            Ok(ChunkStrategy::Blocked {
+                tile: tile.parse().context("invalid block tile size")?,
+            })
+        }
+    }
+}
+
+fn strategy_name(strategy: ChunkStrategy) -> String {
+    match strategy {
+        ChunkStrategy::PerRow => "row".into(),
+        ChunkStrategy::PerCell => "cell".into(),
+        ChunkStrategy::Blocked { tile } => format!("block:{tile}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<Args> {
+        parse_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<()> {
+        let parsed = args("--sizes 8,16 --threads 2 --strategies row,block:4 --repeat 1")?;
+        assert_eq!(
+            parsed,
+            Args {
+                sizes: vec![8, 16],
+                threads: vec![2],
+                strategies: vec![ChunkStrategy::PerRow, ChunkStrategy::Blocked { tile: 4 }],
+                repeat: 1,
+            }
+        );
+        assert_eq!(args("")?.sizes, [64, 128, 256]);
+        assert!(args("--sizes 8,x").is_err());
+        assert!(args("--threads 0").is_err());
+        assert!(args("--repeat").is_err());
+        assert!(args("--strategies diagonal").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_size() -> Result<()> {
+        let parsed = args("--sizes 6 --threads 1,3 --strategies row,cell --repeat 1")?;
+        let samples = run_size(6, &parsed)?;
+        // 串行基准加上 2 种线程数 × 2 种粒度
+        assert_eq!(samples.len(), 5);
+        assert_eq!(samples[0].strategy, None);
+        assert_eq!(samples[4].threads, 3);
+        assert!(samples.iter().all(|s| s.gflops() > 0.0));
+        Ok(())
+    }
+}
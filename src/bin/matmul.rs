@@ -0,0 +1,199 @@
+//! 从文件读取两个矩阵，相乘后写出结果
+//!
+//! ```text
+//! matmul A B [-o OUT] [--threads N] [--strategy row|cell|block:TILE] [--deterministic]
+//! ```
+//!
+//! 输入输出按扩展名选择格式：`.npy` 为 NumPy 格式，其余按 CSV 处理；
+//! 输入为 `-` 时从标准输入读取 CSV，省略 `-o` 时把结果以 CSV 写到标准输出
+
+use anyhow::{Context, Result, anyhow, bail};
+use concurrency::{ChunkStrategy, Matrix, MultiplyOptions, ThreadPool, multiply_with};
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+const USAGE: &str =
+    "usage: matmul A B [-o OUT] [--threads N] [--strategy row|cell|block:TILE] [--deterministic]";
+
+/// 命令行参数
+#[derive(Debug, PartialEq)]
+struct Args {
+    a: String,
+    b: String,
+    output: Option<String>,
+    threads: usize,
+    strategy: ChunkStrategy,
+    deterministic: bool,
+}
+
+fn main() -> Result<()> {
+    let args = parse_args(std::env::args().skip(1))?;
+    let a = read_matrix(&args.a)?;
+    let b = read_matrix(&args.b)?;
+
+    let pool = ThreadPool::new(args.threads);
+    let options = MultiplyOptions::new()
+        .pool(&pool)
+        .chunk_strategy(args.strategy)
+        .deterministic(args.deterministic);
+    let c = multiply_with(&a, &b, options)?;
+
+    match &args.output {
+        Some(path) => write_matrix(&c, path)?,
+        None => {
+            let mut out = BufWriter::new(io::stdout().lock());
+            c.to_csv(&mut out)?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Args> {
+    let mut inputs = Vec::new();
+    let mut output = None;
+    let mut threads = std::thread::available_parallelism().map_or(4, |n| n.get());
+    let mut strategy = ChunkStrategy::default();
+    let mut deterministic = false;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| {
+            args.next()
+                .ok_or_else(|| anyhow!("{name} needs a value\n{USAGE}"))
+        };
+        match arg.as_str() {
+            "-o" | "--output" => output = Some(value("--output")?),
+            "--threads" => {
+                threads = value("--threads")?.parse().context("invalid --threads")?;
+                if threads == 0 {
+                    bail!("--threads must be at least 1");
+                }
+            }
+            "--strategy" => strategy = parse_strategy(&value("--strategy")?)?,
+            "--deterministic" => deterministic = true,
+            "-h" | "--help" => bail!("{USAGE}"),
+            _ if arg.starts_with("--") => bail!("unknown option {arg}\n{USAGE}"),
+            _ => inputs.push(arg),
+        }
+    }
+
+    let [a, b]: [String; 2] = inputs
+        .try_into()
+        .map_err(|_| anyhow!("expected exactly two input files\n{USAGE}"))?;
+    if a == "-" && b == "-" {
+        bail!("only one input can be read from stdin");
+    }
+    Ok(Args {
+        a,
+        b,
+        output,
+        threads,
+        strategy,
+        deterministic,
+    })
+}
+
+/// 解析 `row`、`cell` 或 `block:TILE`
+fn parse_strategy(s: &str) -> Result<ChunkStrategy> {
+    match s {
+        "row" => Ok(ChunkStrategy::PerRow),
+        "cell" => Ok(ChunkStrategy::PerCell),
+        _ => {
+            let tile = s.strip_prefix("block:").ok_or_else(|| {
+                anyhow!("unknown strategy {s:?}, expected row, cell or block:TILE")
+            })?;
+            Ok(ChunkStrategy::Blocked {
+                tile: tile.parse().context("invalid block tile size")?,
+            })
+        }
+    }
+}
+
+fn is_npy(path: &str) -> bool {
+    Path::new(path).extension().is_some_and(|ext| ext == "npy")
+}
+
+fn read_matrix(path: &str) -> Result<Matrix<f64>> {
+    let m = if path == "-" {
+        Matrix::from_csv(io::stdin().lock())
+    } else if is_npy(path) {
+        Matrix::from_npy(path)
+    } else {
+        let file = File::open(path).with_context(|| format!("cannot open {path}"))?;
+        Matrix::from_csv(BufReader::new(file))
+    };
+    m.with_context(|| format!("cannot read matrix from {path}"))
+}
+
+fn write_matrix(m: &Matrix<f64>, path: &str) -> Result<()> {
+    if is_npy(path) {
+        m.to_npy(path)?;
+    } else {
+        let mut out = BufWriter::new(File::create(path)?);
+        m.to_csv(&mut out)?;
+        out.flush()?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Result<Args> {
+        parse_args(s.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn test_parse_args() -> Result<()> {
+        let parsed = args("a.csv b.npy -o c.npy --threads 3 --strategy block:8 --deterministic")?;
+        assert_eq!(
+            parsed,
+            Args {
+                a: "a.csv".into(),
+                b: "b.npy".into(),
+                output: Some("c.npy".into()),
+                threads: 3,
+                strategy: ChunkStrategy::Blocked { tile: 8 },
+                deterministic: true,
+            }
+        );
+        assert_eq!(
+            args("- b.csv --strategy cell")?.strategy,
+            ChunkStrategy::PerCell
+        );
+
+        assert!(args("a.csv").is_err());
+        assert!(args("a.csv b.csv c.csv").is_err());
+        assert!(args("a.csv b.csv --threads 0").is_err());
+        assert!(args("a.csv b.csv --strategy diagonal").is_err());
+        assert!(args("a.csv b.csv --threads").is_err());
+        assert!(args("- -").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_round_trip_files() -> Result<()> {
+        let dir = std::env::temp_dir().join(format!("matmul-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir)?;
+        let a_path = dir.join("a.csv");
+        std::fs::write(&a_path, "1,2,3\n4,5,6\n")?;
+        let b_path = dir.join("b.npy");
+        Matrix::new([7.0, 8.0, 9.0, 10.0, 11.0, 12.0], 3, 2).to_npy(&b_path)?;
+
+        let a = read_matrix(&a_path.to_string_lossy())?;
+        let b = read_matrix(&b_path.to_string_lossy())?;
+        let c = multiply_with(&a, &b, MultiplyOptions::new())?;
+        let c_path = dir.join("c.npy").to_string_lossy().into_owned();
+        write_matrix(&c, &c_path)?;
+        assert_eq!(
+            read_matrix(&c_path)?,
+            Matrix::new([58.0, 64.0, 139.0, 154.0], 2, 2)
+        );
+
+        std::fs::remove_dir_all(&dir)?;
+        Ok(())
+    }
+}
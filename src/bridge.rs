@@ -0,0 +1,203 @@
+use num_traits::Zero;
+use std::fmt;
+use std::future::Future;
+use std::ops::{AddAssign, Mul};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::OnceLock;
+
+use crate::channel::oneshot;
+use crate::error::{MatrixError, WorkerError};
+use crate::matrix::{Matrix, NUM_THREADS, multiply_with};
+use crate::options::MultiplyOptions;
+use crate::pool::ThreadPool;
+
+/// 异步乘法共享的两个线程池
+///
+/// * `dispatch`: 执行每次乘法的分发与收集，最多同时进行 `NUM_THREADS` 个乘法，其余排队
+/// * `compute`: 所有异步乘法共用的计算线程池
+///
+/// 分发任务只等待计算线程池，计算任务从不等待其它任务，因此不会互相死锁；
+/// 无论并发多少个异步乘法，额外的线程总数都固定为 `2 × NUM_THREADS`
+struct SharedPools {
+    dispatch: ThreadPool,
+    compute: ThreadPool,
+}
+
+fn shared_pools() -> &'static SharedPools {
+    static POOLS: OnceLock<SharedPools> = OnceLock::new();
+    POOLS.get_or_init(|| SharedPools {
+        dispatch: ThreadPool::new(NUM_THREADS),
+        compute: ThreadPool::new(NUM_THREADS),
+    })
+}
+
+/// 在共享计算线程池上同步计算乘积，panic 以 `MatrixError::WorkerFailed` 返回
+fn multiply_shared<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    let options = MultiplyOptions::new().pool(&shared_pools().compute);
+    panic::catch_unwind(AssertUnwindSafe(|| multiply_with(a, b, options)))
+        .unwrap_or_else(|payload| Err(WorkerError::panicked(0, payload).into()))
+}
+
+/// 在异步代码中计算矩阵乘法，不阻塞调用方的执行器
+///
+/// 乘法在进程内共享的有界线程池上进行，完成后通过一次性通道唤醒等待的任务，
+/// 因此可以在任意异步运行时中 `.await`；并发调用只会排队，不会创建新的线程
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回的 future 输出乘积或 `multiply` 的错误，计算 panic 时输出 `MatrixError::WorkerFailed`
+pub fn multiply_async<T>(
+    a: Matrix<T>,
+    b: Matrix<T>,
+) -> impl Future<Output = Result<Matrix<T>, MatrixError>> + Send
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+    let submitted = shared_pools().dispatch.execute(move || {
+        // future 已被 drop 时发送失败是正常情况
+        let _ = tx.send(multiply_shared(&a, &b));
+    });
+    async move {
+        submitted.map_err(|_| MatrixError::from(WorkerError::disconnected(0)))?;
+        rx.await
+            .unwrap_or_else(|_| Err(WorkerError::disconnected(0).into()))
+    }
+}
+
+/// 通过 tokio 运行时的 `spawn_blocking` 计算矩阵乘法
+///
+/// 阻塞的分发与收集在 `handle` 的阻塞线程池中进行，计算在共享的计算线程池上进行，
+/// 结果经一次性通道返回，Web 服务等可以直接 `.await` 而不会卡住执行器
+///
+/// # 参数
+/// * `handle`: tokio 运行时句柄
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回的 future 输出乘积或 `multiply` 的错误，计算 panic 时输出 `MatrixError::WorkerFailed`
+#[cfg(feature = "tokio")]
+pub fn multiply_blocking_on<T>(
+    handle: &tokio::runtime::Handle,
+    a: Matrix<T>,
+    b: Matrix<T>,
+) -> impl Future<Output = Result<Matrix<T>, MatrixError>> + Send
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    let (tx, rx) = oneshot::channel();
+    // 结果通过通道返回，不需要等待 JoinHandle
+    drop(handle.spawn_blocking(move || {
+        let _ = tx.send(multiply_shared(&a, &b));
+    }));
+    async move {
+        rx.await
+            .unwrap_or_else(|_| Err(WorkerError::disconnected(0).into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::task::{Context, Poll, Wake};
+    use std::thread;
+
+    /// 最简单的执行器：轮询一次，未完成时挂起当前线程直到被唤醒
+    struct ThreadWaker(thread::Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Arc::new(ThreadWaker(thread::current())).into();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+            thread::park();
+        }
+    }
+
+    #[test]
+    fn test_multiply_async() -> anyhow::Result<()> {
+        let a = Matrix::new((0..12).collect::<Vec<i64>>(), 3, 4);
+        let b = Matrix::new((0..8).collect::<Vec<i64>>(), 4, 2);
+        let expected = multiply_sequential(&a, &b)?;
+        assert_eq!(block_on(multiply_async(a, b))?, expected);
+
+        let a = Matrix::new([1, 2], 1, 2);
+        assert!(matches!(
+            block_on(multiply_async(a, Matrix::new([1, 2], 1, 2))),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_async_concurrent() -> anyhow::Result<()> {
+        let a = Matrix::new(
+            (0..64 * 64).map(|v| v as i64 % 13).collect::<Vec<_>>(),
+            64,
+            64,
+        );
+        let expected = multiply_sequential(&a, &a)?;
+        let futures = (0..32)
+            .map(|_| {
+                multiply_async(
+                    Matrix::new(a.data.clone(), 64, 64),
+                    Matrix::new(a.data.clone(), 64, 64),
+                )
+            })
+            .collect::<Vec<_>>();
+        for future in futures {
+            assert_eq!(block_on(future)?, expected);
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_multiply_blocking_on() -> anyhow::Result<()> {
+        let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+        let a = Matrix::new((0..12).collect::<Vec<i64>>(), 3, 4);
+        let b = Matrix::new((0..8).collect::<Vec<i64>>(), 4, 2);
+        let expected = multiply_sequential(&a, &b)?;
+        let c = runtime.block_on(multiply_blocking_on(runtime.handle(), a, b))?;
+        assert_eq!(c, expected);
+
+        let a = Matrix::new([1, 2], 1, 2);
+        let result = runtime.block_on(multiply_blocking_on(
+            runtime.handle(),
+            a,
+            Matrix::new([1, 2], 1, 2),
+        ));
+        assert!(matches!(result, Err(MatrixError::DimensionMismatch { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn test_oneshot_receiver_await() {
+        let (tx, rx) = oneshot::channel();
+        thread::spawn(move || tx.send(7));
+        assert_eq!(block_on(rx), Ok(7));
+
+        let (tx, rx) = oneshot::channel::<i32>();
+        thread::spawn(move || drop(tx));
+        assert!(block_on(rx).is_err());
+    }
+}
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 取消令牌
+///
+/// 可以克隆后在线程间共享，任意一方调用 `cancel()` 后，
+/// 所有持有该令牌的计算都会在下一个任务边界处停止，并返回 `MatrixError::Cancelled`
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// 创建一个未被取消的令牌
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 发出取消信号
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// 是否已经发出取消信号
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
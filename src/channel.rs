@@ -0,0 +1,295 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use thiserror::Error;
+
+pub mod oneshot;
+
+/// 创建容量为 `capacity` 的有界多生产者多消费者通道
+///
+/// 发送端和接收端都可以克隆。通道已满时 `send` 阻塞，形成背压；
+/// 所有发送端被 drop 或任意一端调用 `close` 后通道关闭，
+/// 接收端仍可取完剩余消息，之后 `recv` 返回错误；
+/// 所有接收端被 drop 后 `send` 立即返回错误
+///
+/// # 参数
+/// * `capacity`: 通道容量，至少为 1
+pub fn bounded<T>(capacity: usize) -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            queue: VecDeque::with_capacity(capacity.max(1)),
+            senders: 1,
+            receivers: 1,
+            closed: false,
+        }),
+        not_empty: Condvar::new(),
+        not_full: Condvar::new(),
+        capacity: capacity.max(1),
+    });
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// 通道已关闭，消息原样返回
+#[derive(Clone, Copy, PartialEq, Eq, Error)]
+#[error("sending on a closed channel")]
+pub struct SendError<T>(pub T);
+
+impl<T> fmt::Debug for SendError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SendError(..)")
+    }
+}
+
+/// 通道已关闭且没有剩余消息
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("receiving on a closed channel")]
+pub struct RecvError;
+
+/// `try_recv` 的失败原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum TryRecvError {
+    /// 通道暂时没有消息
+    #[error("receiving on an empty channel")]
+    Empty,
+    /// 通道已关闭且没有剩余消息
+    #[error("receiving on a closed channel")]
+    Closed,
+}
+
+/// 通道发送端
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// 通道接收端
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// 发送端和接收端共享的状态
+///
+/// # 字段
+/// * `state`: 消息队列及两端计数
+/// * `not_empty`: 有新消息或通道关闭时通知接收端
+/// * `not_full`: 有消息被取走或通道关闭时通知发送端
+/// * `capacity`: 通道容量
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+struct State<T> {
+    queue: VecDeque<T>,
+    senders: usize,
+    receivers: usize,
+    closed: bool,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+}
+
+impl<T> Sender<T> {
+    /// 发送消息，通道已满时阻塞
+    ///
+    /// # 返回值
+    /// 通道已关闭或所有接收端已被 drop 时返回错误，消息随错误返回
+    pub fn send(&self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        loop {
+            if state.closed || state.receivers == 0 {
+                return Err(SendError(value));
+            }
+            if state.queue.len() < self.shared.capacity {
+                state.queue.push_back(value);
+                self.shared.not_empty.notify_one();
+                return Ok(());
+            }
+            state = self
+                .shared
+                .not_full
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 关闭通道，已发送的消息仍可被接收
+    pub fn close(&self) {
+        self.shared.close();
+    }
+
+    /// 通道是否已关闭或所有接收端已被 drop，此时 `send` 必然失败
+    pub fn is_closed(&self) -> bool {
+        let state = self.shared.lock();
+        state.closed || state.receivers == 0
+    }
+}
+
+impl<T> Receiver<T> {
+    /// 接收消息，通道为空时阻塞
+    ///
+    /// # 返回值
+    /// 通道已关闭且没有剩余消息时返回错误
+    pub fn recv(&self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = state.queue.pop_front() {
+                self.shared.not_full.notify_one();
+                return Ok(value);
+            }
+            if state.closed || state.senders == 0 {
+                return Err(RecvError);
+            }
+            state = self
+                .shared
+                .not_empty
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 不阻塞地接收消息
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.queue.pop_front() {
+            Some(value) => {
+                self.shared.not_full.notify_one();
+                Ok(value)
+            }
+            None if state.closed || state.senders == 0 => Err(TryRecvError::Closed),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// 关闭通道，剩余消息仍可被接收
+    pub fn close(&self) {
+        self.shared.close();
+    }
+
+    /// 阻塞迭代接收到的消息，通道关闭且消息取完后结束
+    pub fn iter(&self) -> impl Iterator<Item = T> + '_ {
+        std::iter::from_fn(|| self.recv().ok())
+    }
+}
+
+impl<T> Clone for Sender<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().senders += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Clone for Receiver<T> {
+    fn clone(&self) -> Self {
+        self.shared.lock().receivers += 1;
+        Self {
+            shared: self.shared.clone(),
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.senders -= 1;
+        if state.senders == 0 {
+            self.shared.not_empty.notify_all();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.receivers -= 1;
+        if state.receivers == 0 {
+            self.shared.not_full.notify_all();
+        }
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_channel_mpmc() {
+        let (tx, rx) = bounded(4);
+        let producers: Vec<_> = (0..4)
+            .map(|i| {
+                let tx = tx.clone();
+                thread::spawn(move || {
+                    for j in 0..100 {
+                        tx.send(i * 100 + j).unwrap();
+                    }
+                })
+            })
+            .collect();
+        drop(tx);
+        let consumers: Vec<_> = (0..3)
+            .map(|_| {
+                let rx = rx.clone();
+                thread::spawn(move || rx.iter().collect::<Vec<_>>())
+            })
+            .collect();
+        drop(rx);
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        let mut received: Vec<_> = consumers
+            .into_iter()
+            .flat_map(|c| c.join().unwrap())
+            .collect();
+        received.sort();
+        assert_eq!(received, (0..400).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_channel_close() {
+        let (tx, rx) = bounded(2);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        tx.send(1).unwrap();
+        tx.send(2).unwrap();
+        rx.close();
+        assert_eq!(tx.send(3), Err(SendError(3)));
+        assert_eq!(rx.recv(), Ok(1));
+        assert_eq!(rx.try_recv(), Ok(2));
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+        assert_eq!(rx.recv(), Err(RecvError));
+
+        let (tx, rx) = bounded::<i32>(1);
+        drop(rx);
+        assert!(tx.is_closed());
+        assert_eq!(tx.send(1), Err(SendError(1)));
+    }
+}
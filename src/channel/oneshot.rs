@@ -0,0 +1,243 @@
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+use super::{RecvError, SendError, TryRecvError};
+
+/// 创建一次性通道，只能发送和接收一条消息
+///
+/// 发送端在发送前被 drop 时，接收端收到断开错误；
+/// 接收端被 drop 后，发送失败并原样返回消息。
+/// 接收端既可以阻塞接收，也可以在异步代码中直接 `.await`
+pub fn channel<T>() -> (Sender<T>, Receiver<T>) {
+    let shared = Arc::new(Shared {
+        state: Mutex::new(State {
+            value: None,
+            sender_dropped: false,
+            receiver_dropped: false,
+            waker: None,
+        }),
+        ready: Condvar::new(),
+    });
+    (
+        Sender {
+            shared: shared.clone(),
+        },
+        Receiver { shared },
+    )
+}
+
+/// 带超时的接收失败原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum RecvTimeoutError {
+    /// 超时前没有收到消息
+    #[error("timed out waiting on a oneshot channel")]
+    Timeout,
+    /// 发送端已被 drop 且没有发送消息
+    #[error("receiving on a closed oneshot channel")]
+    Disconnected,
+}
+
+/// 一次性通道的发送端
+pub struct Sender<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// 一次性通道的接收端
+pub struct Receiver<T> {
+    shared: Arc<Shared<T>>,
+}
+
+/// # 字段
+/// * `state`: 消息及两端是否存活
+/// * `ready`: 消息到达或发送端被 drop 时通知接收端
+struct Shared<T> {
+    state: Mutex<State<T>>,
+    ready: Condvar,
+}
+
+struct State<T> {
+    value: Option<T>,
+    sender_dropped: bool,
+    receiver_dropped: bool,
+    /// 异步接收时最近一次 `poll` 登记的唤醒器
+    waker: Option<Waker>,
+}
+
+impl<T> Shared<T> {
+    fn lock(&self) -> MutexGuard<'_, State<T>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T> Sender<T> {
+    /// 发送消息并消耗发送端
+    ///
+    /// # 返回值
+    /// 接收端已被 drop 时返回错误，消息随错误返回
+    pub fn send(self, value: T) -> Result<(), SendError<T>> {
+        let mut state = self.shared.lock();
+        if state.receiver_dropped {
+            return Err(SendError(value));
+        }
+        state.value = Some(value);
+        let waker = state.waker.take();
+        drop(state);
+        self.shared.ready.notify_one();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+        Ok(())
+    }
+}
+
+impl<T> Receiver<T> {
+    /// 阻塞等待消息
+    ///
+    /// # 返回值
+    /// 发送端没有发送就被 drop 时返回错误
+    pub fn recv(self) -> Result<T, RecvError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = state.value.take() {
+                return Ok(value);
+            }
+            if state.sender_dropped {
+                return Err(RecvError);
+            }
+            state = self
+                .shared
+                .ready
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 不阻塞地接收消息
+    pub fn try_recv(&self) -> Result<T, TryRecvError> {
+        let mut state = self.shared.lock();
+        match state.value.take() {
+            Some(value) => Ok(value),
+            None if state.sender_dropped => Err(TryRecvError::Closed),
+            None => Err(TryRecvError::Empty),
+        }
+    }
+
+    /// 最多等待 `timeout`
+    pub fn recv_timeout(&self, timeout: Duration) -> Result<T, RecvTimeoutError> {
+        self.recv_deadline(Instant::now() + timeout)
+    }
+
+    /// 最多等待到 `deadline`
+    pub fn recv_deadline(&self, deadline: Instant) -> Result<T, RecvTimeoutError> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(value) = state.value.take() {
+                return Ok(value);
+            }
+            if state.sender_dropped {
+                return Err(RecvTimeoutError::Disconnected);
+            }
+            let now = Instant::now();
+            if now >= deadline {
+                return Err(RecvTimeoutError::Timeout);
+            }
+            state = self
+                .shared
+                .ready
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+/// 异步接收，发送端没有发送就被 drop 时返回错误
+impl<T> Future for Receiver<T> {
+    type Output = Result<T, RecvError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.shared.lock();
+        if let Some(value) = state.value.take() {
+            return Poll::Ready(Ok(value));
+        }
+        if state.sender_dropped {
+            return Poll::Ready(Err(RecvError));
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let mut state = self.shared.lock();
+        state.sender_dropped = true;
+        let waker = state.waker.take();
+        drop(state);
+        self.shared.ready.notify_one();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Receiver<T> {
+    fn drop(&mut self) {
+        self.shared.lock().receiver_dropped = true;
+    }
+}
+
+impl<T> fmt::Debug for Sender<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sender").finish_non_exhaustive()
+    }
+}
+
+impl<T> fmt::Debug for Receiver<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Receiver").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_oneshot_send_recv() {
+        let (tx, rx) = channel();
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Empty));
+        let handle = thread::spawn(move || tx.send(42));
+        assert_eq!(rx.recv(), Ok(42));
+        assert_eq!(handle.join().unwrap(), Ok(()));
+    }
+
+    #[test]
+    fn test_oneshot_drop_semantics() {
+        let (tx, rx) = channel::<i32>();
+        drop(tx);
+        assert_eq!(rx.try_recv(), Err(TryRecvError::Closed));
+        assert_eq!(rx.recv(), Err(RecvError));
+
+        let (tx, rx) = channel();
+        drop(rx);
+        assert_eq!(tx.send(1), Err(SendError(1)));
+
+        let (tx, rx) = channel::<i32>();
+        assert_eq!(
+            rx.recv_timeout(Duration::from_millis(5)),
+            Err(RecvTimeoutError::Timeout)
+        );
+        thread::spawn(move || drop(tx));
+        assert_eq!(
+            rx.recv_timeout(Duration::from_secs(5)),
+            Err(RecvTimeoutError::Disconnected)
+        );
+    }
+}
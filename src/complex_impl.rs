@@ -0,0 +1,66 @@
+use core::ops::Neg;
+
+use alloc::vec::Vec;
+use num_complex::Complex;
+
+use crate::matrix::Matrix;
+
+impl<T: Clone + Neg<Output = T>> Matrix<Complex<T>> {
+    /// 共轭转置（厄米转置），结果的 `(j, i)` 元素为原矩阵 `(i, j)` 元素的共轭
+    pub fn conjugate_transpose(&self) -> Self {
+        let mut data = Vec::with_capacity(self.data.len());
+        for j in 0..self.col {
+            data.extend(
+                self.data[j..]
+                    .iter()
+                    .step_by(self.col)
+                    .map(|z| Complex::new(z.re.clone(), -z.im.clone())),
+            );
+        }
+        Matrix {
+            data,
+            row: self.col,
+            col: self.row,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+
+    #[test]
+    fn test_conjugate_transpose() {
+        let c = |re, im| Complex::new(re, im);
+        let a = Matrix::new([c(1.0, 2.0), c(3.0, -1.0), c(0.0, 1.0)], 1, 3);
+        let ah = a.conjugate_transpose();
+        assert_eq!(
+            ah,
+            Matrix::new([c(1.0, -2.0), c(3.0, 1.0), c(0.0, -1.0)], 3, 1)
+        );
+
+        // a · aᴴ 为各元素模的平方和
+        let Ok(norm) = multiply_sequential(&a, &ah) else {
+            panic!("multiply should succeed");
+        };
+        assert_eq!(norm, Matrix::new([c(16.0, 0.0)], 1, 1));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_multiply_complex() -> anyhow::Result<()> {
+        use crate::matrix::multiply_with;
+        use crate::options::MultiplyOptions;
+
+        let c = |re, im| Complex::new(re, im);
+        let a = Matrix::new([c(1.0, 1.0), c(0.0, 2.0), c(2.0, 0.0), c(1.0, -1.0)], 2, 2);
+        let b = Matrix::new([c(1.0, 0.0), c(0.0, 1.0), c(1.0, 1.0), c(2.0, 0.0)], 2, 2);
+        let expected = Matrix::new([c(-1.0, 3.0), c(-1.0, 5.0), c(4.0, 0.0), c(2.0, 0.0)], 2, 2);
+        for threshold in [0, usize::MAX] {
+            let options = MultiplyOptions::new().sequential_threshold(threshold);
+            assert_eq!(multiply_with(&a, &b, options)?, expected);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+use num_traits::Zero;
+use std::ops::{AddAssign, Mul};
+use std::sync::Arc;
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, NUM_THREADS};
+use crate::par::run_tasks;
+use crate::pool::ThreadPool;
+
+/// 二维卷积的边界处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Padding {
+    /// 只保留卷积核完全落在输入内的位置，结果为 `(行数 - 核行数 + 1) × (列数 - 核列数 + 1)`，
+    /// 卷积核大于输入时结果为空
+    #[default]
+    Valid,
+    /// 输入四周补零，结果与输入形状相同；偶数尺寸的卷积核偏向左上方对齐
+    Same,
+}
+
+/// 二维卷积
+///
+/// 按数学定义翻转卷积核后滑动求和，与 `scipy.signal.convolve2d` 一致，
+/// 对称的卷积核（如模糊、锐化滤波器）与互相关的结果相同。
+/// 结果行按连续区间平均分配给私有线程池的工作线程，每个区间由一个任务计算
+///
+/// # 参数
+/// * `input`: 输入矩阵
+/// * `kernel`: 卷积核，为空时结果全为零
+/// * `padding`: 边界处理方式
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，计算中的 panic 以 `MatrixError::WorkerFailed` 返回
+pub fn convolve2d<T>(
+    input: &Matrix<T>,
+    kernel: &Matrix<T>,
+    padding: Padding,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    convolve2d_on(input, kernel, padding, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算二维卷积
+///
+/// # 参数
+/// * `input`: 输入矩阵
+/// * `kernel`: 卷积核
+/// * `padding`: 边界处理方式
+/// * `pool`: 执行计算的线程池
+pub fn convolve2d_on<T>(
+    input: &Matrix<T>,
+    kernel: &Matrix<T>,
+    padding: Padding,
+    pool: &ThreadPool,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    let (kh, kw) = (kernel.row.max(1), kernel.col.max(1));
+    // offset 为结果 (0, 0) 在完整卷积结果中的位置
+    let (row, col, offset) = match padding {
+        Padding::Valid => (
+            (input.row + 1).saturating_sub(kh),
+            (input.col + 1).saturating_sub(kw),
+            (kh - 1, kw - 1),
+        ),
+        Padding::Same => (input.row, input.col, ((kh - 1) / 2, (kw - 1) / 2)),
+    };
+    if row == 0 || col == 0 {
+        return Ok(Matrix {
+            data: Vec::new(),
+            row,
+            col,
+        });
+    }
+
+    let input = Arc::new(Matrix {
+        data: input.data.clone(),
+        row: input.row,
+        col: input.col,
+    });
+    let kernel = Arc::new(Matrix {
+        data: kernel.data.clone(),
+        row: kernel.row,
+        col: kernel.col,
+    });
+    let rows_per_task = row.div_ceil(pool.size()).max(1);
+    let blocks = run_tasks(
+        pool,
+        (0..row).step_by(rows_per_task).map(|start| {
+            let end = (start + rows_per_task).min(row);
+            let input = input.clone();
+            let kernel = kernel.clone();
+            (start * col, move || {
+                let mut out = Vec::with_capacity((end - start) * col);
+                for i in start..end {
+                    for j in 0..col {
+                        out.push(full_cell(&input, &kernel, i + offset.0, j + offset.1));
+                    }
+                }
+                out
+            })
+        }),
+    )?;
+    Ok(Matrix {
+        data: blocks.into_iter().flatten().collect(),
+        row,
+        col,
+    })
+}
+
+/// 完整卷积结果中 `(i, j)` 处的值，超出输入范围的元素视为零
+fn full_cell<T>(input: &Matrix<T>, kernel: &Matrix<T>, i: usize, j: usize) -> T
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    let mut sum = T::zero();
+    for p in 0..kernel.row.min(i + 1) {
+        let x = i - p;
+        if x >= input.row {
+            continue;
+        }
+        for q in 0..kernel.col.min(j + 1) {
+            let y = j - q;
+            if y >= input.col {
+                continue;
+            }
+            sum += input.data[x * input.col + y].clone() * kernel.data[p * kernel.col + q].clone();
+        }
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_convolve2d_valid_and_same() -> Result<()> {
+        let input = Matrix::new((1..=16).collect::<Vec<i64>>(), 4, 4);
+        let blur = Matrix::new([1; 9], 3, 3);
+        assert_eq!(
+            convolve2d(&input, &blur, Padding::Valid)?,
+            Matrix::new([54, 63, 90, 99], 2, 2)
+        );
+        assert_eq!(
+            convolve2d(&input, &blur, Padding::Same)?,
+            Matrix::new(
+                [
+                    14, 24, 30, 22, 33, 54, 63, 45, 57, 90, 99, 69, 46, 72, 78, 54
+                ],
+                4,
+                4
+            )
+        );
+
+        // 卷积核按定义翻转
+        let shift = Matrix::new([0, 0, 1, 0], 2, 2);
+        let input = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        assert_eq!(
+            convolve2d(&input, &shift, Padding::Valid)?,
+            Matrix::new([2, 3], 1, 2)
+        );
+        assert_eq!(
+            convolve2d(&input, &shift, Padding::Same)?,
+            Matrix::new([0, 0, 0, 1, 2, 3], 2, 3)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_convolve2d_edge_shapes() -> Result<()> {
+        let input = Matrix::new([1, 2, 3, 4], 2, 2);
+        let large = Matrix::new([1; 9], 3, 3);
+        let valid = convolve2d(&input, &large, Padding::Valid)?;
+        assert_eq!((valid.row, valid.col), (0, 0));
+        assert_eq!(
+            convolve2d(&input, &large, Padding::Same)?,
+            Matrix::new([10; 4], 2, 2)
+        );
+
+        let empty = Matrix::<i32>::new([], 0, 0);
+        assert_eq!(
+            convolve2d(&input, &empty, Padding::Valid)?,
+            Matrix::new([0; 4], 2, 2)
+        );
+
+        // 结果行数少于线程数时也能正确切分
+        let pool = ThreadPool::new(3);
+        let input = Matrix::new((0..10).collect::<Vec<i32>>(), 1, 10);
+        let diff = Matrix::new([1, -1], 1, 2);
+        assert_eq!(
+            convolve2d_on(&input, &diff, Padding::Valid, &pool)?,
+            Matrix::new([1; 9], 1, 9)
+        );
+        Ok(())
+    }
+}
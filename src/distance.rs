@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, NUM_THREADS};
+use crate::par::run_tasks;
+use crate::pool::ThreadPool;
+
+/// 两个样本之间的距离度量
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Metric {
+    /// 欧几里得距离
+    #[default]
+    Euclidean,
+    /// 余弦距离 `1 - cos θ`，取值范围 `[0, 2]`；零向量与任意向量的相似度按 0 计算
+    Cosine,
+}
+
+/// 两组样本之间的距离矩阵
+///
+/// 结果第 `i` 行第 `j` 列为 `a` 的第 `i` 行与 `b` 的第 `j` 行之间的距离，
+/// `a` 的行按连续区间平均分配给私有线程池的工作线程，每个区间由一个任务计算
+///
+/// # 参数
+/// * `a`: `n` 个样本，每行一个
+/// * `b`: `m` 个样本，每行一个，特征数必须与 `a` 相同
+/// * `metric`: 距离度量
+///
+/// # 返回值
+/// 返回 `n × m` 的距离矩阵，特征数不同时返回 `MatrixError::DimensionMismatch`
+pub fn pairwise_distances(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    metric: Metric,
+) -> Result<Matrix<f64>, MatrixError> {
+    pairwise_distances_on(a, b, metric, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算两组样本之间的距离矩阵
+///
+/// # 参数
+/// * `a`: `n` 个样本，每行一个
+/// * `b`: `m` 个样本，每行一个
+/// * `metric`: 距离度量
+/// * `pool`: 执行计算的线程池
+pub fn pairwise_distances_on(
+    a: &Matrix<f64>,
+    b: &Matrix<f64>,
+    metric: Metric,
+    pool: &ThreadPool,
+) -> Result<Matrix<f64>, MatrixError> {
+    if a.col != b.col {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+    let (n, m, dim) = (a.row, b.row, a.col);
+    if n == 0 || m == 0 {
+        return Ok(Matrix {
+            data: Vec::new(),
+            row: n,
+            col: m,
+        });
+    }
+
+    let b = Arc::new(b.data.clone());
+    // 余弦距离需要的每个样本的范数只计算一次
+    let b_norms = Arc::new(match metric {
+        Metric::Euclidean => Vec::new(),
+        Metric::Cosine => (0..m).map(|j| norm(&b[j * dim..(j + 1) * dim])).collect(),
+    });
+    let rows_per_task = n.div_ceil(pool.size()).max(1);
+    let blocks = run_tasks(
+        pool,
+        (0..n).step_by(rows_per_task).map(|start| {
+            let end = (start + rows_per_task).min(n);
+            let rows = a.data[start * dim..end * dim].to_vec();
+            let (b, b_norms) = (b.clone(), b_norms.clone());
+            (start * m, move || {
+                let mut out = Vec::with_capacity((end - start) * m);
+                for i in 0..end - start {
+                    let x = &rows[i * dim..(i + 1) * dim];
+                    let x_norm = norm(x);
+                    for j in 0..m {
+                        let y = &b[j * dim..(j + 1) * dim];
+                        out.push(match metric {
+                            Metric::Euclidean => x
+                                .iter()
+                                .zip(y)
+                                .map(|(x, y)| (x - y) * (x - y))
+                                .sum::<f64>()
+                                .sqrt(),
+                            Metric::Cosine => {
+                                let denom = x_norm * b_norms[j];
+                                if denom == 0.0 {
+                                    1.0
+                                } else {
+                                    let dot = x.iter().zip(y).map(|(x, y)| x * y).sum::<f64>();
+                                    1.0 - dot / denom
+                                }
+                            }
+                        });
+                    }
+                }
+                out
+            })
+        }),
+    )?;
+    Ok(Matrix {
+        data: blocks.into_iter().flatten().collect(),
+        row: n,
+        col: m,
+    })
+}
+
+/// 向量的欧几里得范数
+fn norm(x: &[f64]) -> f64 {
+    x.iter().map(|x| x * x).sum::<f64>().sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_pairwise_distances() -> Result<()> {
+        let a = Matrix::new([0.0, 0.0, 3.0, 4.0, 1.0, 0.0], 3, 2);
+        let b = Matrix::new([0.0, 0.0, 0.0, 2.0], 2, 2);
+        assert_eq!(
+            pairwise_distances(&a, &b, Metric::Euclidean)?,
+            Matrix::new([0.0, 2.0, 5.0, 13f64.sqrt(), 1.0, 5f64.sqrt()], 3, 2)
+        );
+
+        let cosine = pairwise_distances(&a, &b, Metric::Cosine)?;
+        assert_eq!((cosine.row, cosine.col), (3, 2));
+        // 与零向量的距离为 1，(3, 4) 与 (0, 2) 的夹角余弦为 0.8，(1, 0) 与 (0, 2) 正交
+        let expected = [1.0, 1.0, 1.0, 0.2, 1.0, 1.0];
+        for (d, e) in cosine.data.iter().zip(expected) {
+            assert!((d - e).abs() < 1e-12);
+        }
+
+        // 样本数少于线程数、以及空输入
+        let pool = ThreadPool::new(5);
+        let same = pairwise_distances_on(&a, &a, Metric::Euclidean, &pool)?;
+        assert!((0..3).all(|i| same.data[i * 3 + i] == 0.0));
+        let empty = Matrix::<f64>::new([], 0, 2);
+        let d = pairwise_distances(&empty, &b, Metric::Cosine)?;
+        assert_eq!((d.row, d.col), (0, 2));
+
+        assert!(matches!(
+            pairwise_distances(&a, &Matrix::new([1.0], 1, 1), Metric::Euclidean),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        Ok(())
+    }
+}
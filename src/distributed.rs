@@ -0,0 +1,524 @@
+use anyhow::{Result, anyhow};
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+pub use crate::element::WireElement;
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, NUM_THREADS, multiply_with};
+use crate::options::MultiplyOptions;
+use crate::pool::ThreadPool;
+use crate::sync::Semaphore;
+
+/// 协议魔数，用于识别合法的请求
+const MAGIC: &[u8; 4] = b"CMUL";
+
+/// 响应状态：成功
+const STATUS_OK: u8 = 0;
+/// 响应状态：失败，后跟错误信息
+const STATUS_ERR: u8 = 1;
+
+/// 默认的单个矩阵负载上限（字节），超过时拒绝请求而不是分配内存
+pub const DEFAULT_MAX_PAYLOAD_BYTES: usize = 1 << 30;
+
+/// 默认的读写超时
+pub const DEFAULT_IO_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 工作节点默认同时处理的最大连接数
+pub const DEFAULT_MAX_CONNECTIONS: usize = 16;
+
+/// 错误信息的最大字节数
+const MAX_ERROR_MESSAGE_LEN: usize = 64 * 1024;
+
+/// 连接错误回调类型
+pub type ErrorHandler = Arc<dyn Fn(anyhow::Error) + Send + Sync>;
+
+/// 分布式乘法的可选配置
+///
+/// 协调端和工作节点共用：长度字段来自网络，按 `max_payload_bytes` 校验后才分配内存，
+/// 每个连接都设置读写超时，避免失联的对端让调用永远挂起
+#[derive(Clone)]
+pub struct DistributedOptions {
+    pub(crate) max_payload_bytes: usize,
+    pub(crate) max_connections: usize,
+    pub(crate) io_timeout: Option<Duration>,
+    pub(crate) on_error: Option<ErrorHandler>,
+}
+
+impl Default for DistributedOptions {
+    fn default() -> Self {
+        Self {
+            max_payload_bytes: DEFAULT_MAX_PAYLOAD_BYTES,
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            io_timeout: Some(DEFAULT_IO_TIMEOUT),
+            on_error: None,
+        }
+    }
+}
+
+impl DistributedOptions {
+    /// 创建默认配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置单个矩阵负载的字节数上限
+    pub fn max_payload_bytes(mut self, bytes: usize) -> Self {
+        self.max_payload_bytes = bytes;
+        self
+    }
+
+    /// 设置工作节点同时处理的最大连接数，为 0 时按 1 处理
+    ///
+    /// 超出的连接在接受前等待，工作节点的内存占用因此不超过约 `3 × 连接数 × 负载上限`
+    pub fn max_connections(mut self, connections: usize) -> Self {
+        self.max_connections = connections.max(1);
+        self
+    }
+
+    /// 设置连接的读写超时，`None` 表示不限时
+    pub fn io_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.io_timeout = timeout;
+        self
+    }
+
+    /// 设置工作节点的连接错误回调，未设置时错误被丢弃
+    pub fn on_error(mut self, handler: impl Fn(anyhow::Error) + Send + Sync + 'static) -> Self {
+        self.on_error = Some(Arc::new(handler));
+        self
+    }
+}
+
+/// 在已绑定的监听器上运行工作节点
+///
+/// 每个连接携带一个行块任务：A 的若干行与完整的 B，
+/// 工作节点在所有连接共享的线程池上计算乘积后返回结果行块。
+/// 每个连接由独立线程处理，同时处理的连接数不超过 `DEFAULT_MAX_CONNECTIONS`
+///
+/// # 参数
+/// * `listener`: 已绑定的 TCP 监听器
+///
+/// # 返回值
+/// 只在监听器出错时返回
+pub fn serve_worker(listener: TcpListener) -> Result<()> {
+    serve_worker_with(listener, DistributedOptions::default())
+}
+
+/// 按给定配置在已绑定的监听器上运行工作节点
+///
+/// # 参数
+/// * `listener`: 已绑定的 TCP 监听器
+/// * `options`: 负载上限、最大连接数、读写超时和连接错误回调
+///
+/// # 返回值
+/// 只在监听器出错时返回，此时会先等待正在处理的连接结束；单个连接的错误交给 `on_error` 回调
+pub fn serve_worker_with(listener: TcpListener, options: DistributedOptions) -> Result<()> {
+    let pool = ThreadPool::new(NUM_THREADS);
+    let connections = Semaphore::new(options.max_connections);
+    thread::scope(|s| {
+        for stream in listener.incoming() {
+            // 先取得许可再接受下一个连接，处理中的连接数达到上限时在这里等待
+            let permit = connections.acquire();
+            let stream = stream?;
+            let (options, pool) = (&options, &pool);
+            s.spawn(move || {
+                let _permit = permit;
+                if let Err(e) = handle_connection(stream, options, pool)
+                    && let Some(handler) = &options.on_error
+                {
+                    handler(e);
+                }
+            });
+        }
+        Ok(())
+    })
+}
+
+/// 绑定地址并运行工作节点
+///
+/// # 参数
+/// * `addr`: 监听地址
+pub fn run_worker(addr: impl ToSocketAddrs) -> Result<()> {
+    serve_worker(TcpListener::bind(addr)?)
+}
+
+/// 按给定配置绑定地址并运行工作节点
+///
+/// # 参数
+/// * `addr`: 监听地址
+/// * `options`: 负载上限、读写超时和连接错误回调
+pub fn run_worker_with(addr: impl ToSocketAddrs, options: DistributedOptions) -> Result<()> {
+    serve_worker_with(TcpListener::bind(addr)?, options)
+}
+
+/// 分布式矩阵乘法
+///
+/// 将 A 按行切分成与工作节点数相同的行块，每个行块连同完整的 B 发送给一个工作节点，
+/// 所有行块并行计算，最后按顺序拼接结果
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `workers`: 工作节点地址列表
+///
+/// # 返回值
+/// 返回Result<Matrix<T>>，任一工作节点失败时返回错误
+pub fn multiply_distributed<T: WireElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    workers: &[SocketAddr],
+) -> Result<Matrix<T>> {
+    multiply_distributed_with(a, b, workers, &DistributedOptions::default())
+}
+
+/// 按给定配置进行分布式矩阵乘法
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `workers`: 工作节点地址列表
+/// * `options`: 负载上限和读写超时
+///
+/// # 返回值
+/// 返回Result<Matrix<T>>，任一工作节点失败或超时时返回错误
+pub fn multiply_distributed_with<T: WireElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    workers: &[SocketAddr],
+    options: &DistributedOptions,
+) -> Result<Matrix<T>> {
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        }
+        .into());
+    }
+    if workers.is_empty() {
+        return Err(anyhow!("Distributed multiply error: no workers"));
+    }
+
+    // A 没有元素时不产生任何行块，结果全为默认值
+    if a.data.is_empty() {
+        return Ok(Matrix {
+            data: vec![T::zero(); a.row * b.col],
+            row: a.row,
+            col: b.col,
+        });
+    }
+
+    // 每个工作节点分到的行数，最后一个行块可能较少
+    let rows_per_worker = a.row.div_ceil(workers.len());
+    let panels = thread::scope(|s| {
+        let handles = a
+            .data
+            .chunks(rows_per_worker * a.col)
+            .zip(workers)
+            .map(|(panel, addr)| {
+                let rows = panel.len() / a.col;
+                s.spawn(move || request_panel(*addr, panel, rows, a.col, b, options))
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|h| {
+                h.join().map_err(|_| {
+                    anyhow!("Distributed multiply error: coordinator thread panicked")
+                })?
+            })
+            .collect::<Result<Vec<_>>>()
+    })?;
+
+    let mut data = Vec::with_capacity(a.row * b.col);
+    for panel in panels {
+        data.extend(panel);
+    }
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: b.col,
+    })
+}
+
+/// 向工作节点发送一个行块任务并等待结果
+fn request_panel<T: WireElement>(
+    addr: SocketAddr,
+    panel: &[T],
+    rows: usize,
+    k: usize,
+    b: &Matrix<T>,
+    options: &DistributedOptions,
+) -> Result<Vec<T>> {
+    let stream = match options.io_timeout {
+        Some(timeout) => TcpStream::connect_timeout(&addr, timeout)?,
+        None => TcpStream::connect(addr)?,
+    };
+    stream.set_read_timeout(options.io_timeout)?;
+    stream.set_write_timeout(options.io_timeout)?;
+    let mut writer = BufWriter::new(stream.try_clone()?);
+    let mut buf = Vec::with_capacity(4 + 1 + 24 + (panel.len() + b.data.len()) * T::SIZE);
+    buf.extend_from_slice(MAGIC);
+    buf.push(T::TAG);
+    for dim in [rows, k, b.col] {
+        buf.extend_from_slice(&(dim as u64).to_le_bytes());
+    }
+    for value in panel.iter().chain(&b.data) {
+        value.write_le(&mut buf);
+    }
+    writer.write_all(&buf)?;
+    writer.flush()?;
+
+    let mut reader = BufReader::new(stream);
+    match read_u8(&mut reader)? {
+        STATUS_OK => {
+            let len = checked_len::<T>(rows, b.col, options.max_payload_bytes)?;
+            read_elements(&mut reader, len)
+        }
+        _ => {
+            let len = read_dim(&mut reader)?;
+            if len > MAX_ERROR_MESSAGE_LEN {
+                return Err(anyhow!(
+                    "Worker {} error: message of {} bytes exceeds the limit of {} bytes",
+                    addr,
+                    len,
+                    MAX_ERROR_MESSAGE_LEN
+                ));
+            }
+            let mut message = vec![0u8; len];
+            reader.read_exact(&mut message)?;
+            Err(anyhow!(
+                "Worker {} error: {}",
+                addr,
+                String::from_utf8_lossy(&message)
+            ))
+        }
+    }
+}
+
+/// 处理一个连接上的行块任务
+fn handle_connection(
+    stream: TcpStream,
+    options: &DistributedOptions,
+    pool: &ThreadPool,
+) -> Result<()> {
+    stream.set_read_timeout(options.io_timeout)?;
+    stream.set_write_timeout(options.io_timeout)?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut writer = BufWriter::new(stream);
+
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != MAGIC {
+        return Err(anyhow!("Invalid request magic"));
+    }
+    let tag = read_u8(&mut reader)?;
+    let result = match tag {
+        i32::TAG => compute_panel::<i32>(&mut reader, options.max_payload_bytes, pool),
+        i64::TAG => compute_panel::<i64>(&mut reader, options.max_payload_bytes, pool),
+        u32::TAG => compute_panel::<u32>(&mut reader, options.max_payload_bytes, pool),
+        u64::TAG => compute_panel::<u64>(&mut reader, options.max_payload_bytes, pool),
+        f32::TAG => compute_panel::<f32>(&mut reader, options.max_payload_bytes, pool),
+        f64::TAG => compute_panel::<f64>(&mut reader, options.max_payload_bytes, pool),
+        _ => Err(anyhow!("Unknown element tag {}", tag)),
+    };
+
+    match result {
+        Ok(bytes) => {
+            writer.write_all(&[STATUS_OK])?;
+            writer.write_all(&bytes)?;
+        }
+        Err(e) => {
+            let mut message = e.to_string();
+            if message.len() > MAX_ERROR_MESSAGE_LEN {
+                message.truncate(message.floor_char_boundary(MAX_ERROR_MESSAGE_LEN));
+            }
+            writer.write_all(&[STATUS_ERR])?;
+            writer.write_all(&(message.len() as u64).to_le_bytes())?;
+            writer.write_all(message.as_bytes())?;
+        }
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// 读取行块任务并返回编码后的结果
+///
+/// 维度来自网络，输入与输出的字节数都先按 `max_bytes` 校验，超出时返回错误而不分配内存
+fn compute_panel<T: WireElement>(
+    reader: &mut impl Read,
+    max_bytes: usize,
+    pool: &ThreadPool,
+) -> Result<Vec<u8>> {
+    let rows = read_dim(reader)?;
+    let k = read_dim(reader)?;
+    let n = read_dim(reader)?;
+    let panel_len = checked_len::<T>(rows, k, max_bytes)?;
+    let b_len = checked_len::<T>(k, n, max_bytes)?;
+    checked_len::<T>(rows, n, max_bytes)?;
+    let panel = Matrix::new(read_elements::<T>(reader, panel_len)?, rows, k);
+    let b = Matrix::new(read_elements::<T>(reader, b_len)?, k, n);
+    let c = multiply_with(&panel, &b, MultiplyOptions::new().pool(pool))?;
+    let mut buf = Vec::with_capacity(c.data.len() * T::SIZE);
+    for value in &c.data {
+        value.write_le(&mut buf);
+    }
+    Ok(buf)
+}
+
+fn read_u8(reader: &mut impl Read) -> Result<u8> {
+    let mut byte = [0u8; 1];
+    reader.read_exact(&mut byte)?;
+    Ok(byte[0])
+}
+
+fn read_u64(reader: &mut impl Read) -> Result<u64> {
+    let mut raw = [0u8; 8];
+    reader.read_exact(&mut raw)?;
+    Ok(u64::from_le_bytes(raw))
+}
+
+/// 读取一个 u64 长度字段并转换为 usize
+fn read_dim(reader: &mut impl Read) -> Result<usize> {
+    let value = read_u64(reader)?;
+    usize::try_from(value).map_err(|_| anyhow!("Dimension {} does not fit in usize", value))
+}
+
+/// 计算 `row × col` 矩阵的元素个数，溢出或字节数超过 `max_bytes` 时返回错误
+fn checked_len<T: WireElement>(row: usize, col: usize, max_bytes: usize) -> Result<usize> {
+    row.checked_mul(col)
+        .filter(|&len| {
+            len.checked_mul(T::SIZE)
+                .is_some_and(|bytes| bytes <= max_bytes)
+        })
+        .ok_or_else(|| {
+            anyhow!(
+                "Payload of {}x{} elements exceeds the limit of {} bytes",
+                row,
+                col,
+                max_bytes
+            )
+        })
+}
+
+fn read_elements<T: WireElement>(reader: &mut impl Read, len: usize) -> Result<Vec<T>> {
+    let mut bytes = vec![0u8; len * T::SIZE];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes.chunks_exact(T::SIZE).map(T::read_le).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply;
+
+    fn spawn_worker() -> Result<SocketAddr> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        thread::spawn(move || serve_worker(listener));
+        Ok(addr)
+    }
+
+    #[test]
+    fn test_multiply_distributed() -> Result<()> {
+        let workers = [spawn_worker()?, spawn_worker()?];
+        let a = Matrix::new((0..15).map(|x| x as f64).collect::<Vec<_>>(), 5, 3);
+        let b = Matrix::new((0..6).map(|x| x as f64).collect::<Vec<_>>(), 3, 2);
+        let c = multiply_distributed(&a, &b, &workers)?;
+        assert_eq!(c, multiply(&a, &b)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_worker_rejects_oversized_request() -> Result<()> {
+        let addr = spawn_worker()?;
+        let mut stream = TcpStream::connect(addr)?;
+        let mut request = MAGIC.to_vec();
+        request.push(f64::TAG);
+        for dim in [u64::MAX / 2, 4, 1] {
+            request.extend_from_slice(&dim.to_le_bytes());
+        }
+        stream.write_all(&request)?;
+        assert_eq!(read_u8(&mut stream)?, STATUS_ERR);
+        let len = read_dim(&mut stream)?;
+        let mut message = vec![0u8; len];
+        stream.read_exact(&mut message)?;
+        assert!(String::from_utf8_lossy(&message).contains("exceeds the limit"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_distributed_rejects_long_error_message() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        thread::spawn(move || -> Result<()> {
+            let (mut stream, _) = listener.accept()?;
+            stream.write_all(&[STATUS_ERR])?;
+            stream.write_all(&u64::MAX.to_le_bytes())?;
+            Ok(())
+        });
+        let a = Matrix::new([1i64, 2], 1, 2);
+        let b = Matrix::new([3i64, 4], 2, 1);
+        let err = multiply_distributed(&a, &b, &[addr]).unwrap_err();
+        assert!(err.to_string().contains("exceeds the limit"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_distributed_times_out() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        // 接受连接后不响应，模拟失联的工作节点
+        let (done_tx, done_rx) = std::sync::mpsc::channel::<()>();
+        thread::spawn(move || {
+            let accepted = listener.accept();
+            let _ = done_rx.recv();
+            drop(accepted);
+        });
+        let a = Matrix::new([1i64, 2], 1, 2);
+        let b = Matrix::new([3i64, 4], 2, 1);
+        let options = DistributedOptions::new().io_timeout(Some(Duration::from_millis(100)));
+        assert!(multiply_distributed_with(&a, &b, &[addr], &options).is_err());
+        drop(done_tx);
+        Ok(())
+    }
+
+    #[test]
+    fn test_worker_limits_connections() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let options = DistributedOptions::new()
+            .max_connections(1)
+            .io_timeout(Some(Duration::from_millis(200)));
+        thread::spawn(move || serve_worker_with(listener, options));
+
+        // 第一个连接不发送请求，占住唯一的许可直到读超时
+        let idle = TcpStream::connect(addr)?;
+        thread::sleep(Duration::from_millis(50));
+        let started = std::time::Instant::now();
+        let a = Matrix::new([1i64, 2], 1, 2);
+        let b = Matrix::new([3i64, 4], 2, 1);
+        let c = multiply_distributed(&a, &b, &[addr])?;
+        assert_eq!(c, Matrix::new([11i64], 1, 1));
+        assert!(started.elapsed() >= Duration::from_millis(100));
+        drop(idle);
+        Ok(())
+    }
+
+    #[test]
+    fn test_worker_reports_connection_errors() -> Result<()> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let addr = listener.local_addr()?;
+        let (tx, rx) = std::sync::mpsc::channel();
+        let tx = std::sync::Mutex::new(tx);
+        let options = DistributedOptions::new().on_error(move |e| {
+            let _ = tx.lock().unwrap().send(e.to_string());
+        });
+        thread::spawn(move || serve_worker_with(listener, options));
+        TcpStream::connect(addr)?.write_all(b"NOPE")?;
+        let message = rx.recv_timeout(Duration::from_secs(5))?;
+        assert!(message.contains("magic"));
+        Ok(())
+    }
+}
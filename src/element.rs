@@ -0,0 +1,52 @@
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{AddAssign, Mul};
+use num_traits::Zero;
+
+/// 可以按固定字节序编码的矩阵元素类型
+///
+/// 元素按小端字节序编码，`TAG` 用于在二进制格式和网络协议中识别元素类型
+pub trait WireElement:
+    fmt::Debug + Zero + Copy + AddAssign + Mul<Output = Self> + Send + Sync + 'static
+{
+    /// 类型标记
+    const TAG: u8;
+    /// 编码后的字节数
+    const SIZE: usize;
+
+    /// 追加编码后的字节
+    fn write_le(&self, buf: &mut Vec<u8>);
+
+    /// 从恰好 `SIZE` 个字节解码
+    fn read_le(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_wire_element {
+    ($($ty:ty => $tag:expr),* $(,)?) => {
+        $(
+            impl WireElement for $ty {
+                const TAG: u8 = $tag;
+                const SIZE: usize = size_of::<$ty>();
+
+                fn write_le(&self, buf: &mut Vec<u8>) {
+                    buf.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut raw = [0u8; size_of::<$ty>()];
+                    raw.copy_from_slice(bytes);
+                    <$ty>::from_le_bytes(raw)
+                }
+            }
+        )*
+    };
+}
+
+impl_wire_element! {
+    i32 => 1,
+    i64 => 2,
+    u32 => 3,
+    u64 => 4,
+    f32 => 5,
+    f64 => 6,
+}
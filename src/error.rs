@@ -0,0 +1,136 @@
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::any::Any;
+use core::fmt;
+use thiserror::Error;
+
+/// 矩阵运算错误
+///
+/// 库的调用方可以直接匹配错误类型，而不必解析错误信息
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum MatrixError {
+    /// 两个矩阵的维度不满足乘法要求，字段为 `(行数, 列数)`
+    #[error("Matrix multiply error: a is {}x{} but b is {}x{}", a.0, a.1, b.0, b.1)]
+    DimensionMismatch {
+        a: (usize, usize),
+        b: (usize, usize),
+    },
+    /// 输出矩阵的形状不正确，字段为 `(行数, 列数)`
+    #[error(
+        "Matrix multiply error: out is {}x{} but {}x{} is required",
+        actual.0, actual.1, expected.0, expected.1
+    )]
+    OutputShapeMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// 矩阵形状与要求不符，字段为 `(行数, 列数)`
+    #[error(
+        "Matrix shape error: expected {}x{} but got {}x{}",
+        expected.0, expected.1, actual.0, actual.1
+    )]
+    ShapeMismatch {
+        expected: (usize, usize),
+        actual: (usize, usize),
+    },
+    /// 元素位置超出矩阵范围，字段为 `(行, 列)` 和 `(行数, 列数)`
+    #[error(
+        "Matrix index error: ({}, {}) is outside {}x{}",
+        index.0, index.1, shape.0, shape.1
+    )]
+    IndexOutOfBounds {
+        index: (usize, usize),
+        shape: (usize, usize),
+    },
+    /// 稀疏矩阵的存储数组不合法
+    #[error("Invalid sparse matrix: {0}")]
+    InvalidSparse(String),
+    /// 置换的下标数组不是合法的排列
+    #[error("Invalid permutation: {0}")]
+    InvalidPermutation(String),
+    /// 两个向量长度不同
+    #[error("Dot product error: a.len {a} != b.len {b}")]
+    LengthMismatch { a: usize, b: usize },
+    /// 结果矩阵中索引为 `idx` 的单元计算溢出
+    #[error("Matrix multiply error: arithmetic overflow at cell {idx}")]
+    Overflow { idx: usize },
+    /// 工作线程执行失败
+    #[error(transparent)]
+    WorkerFailed(#[from] WorkerError),
+    /// 计算被取消令牌中止
+    #[error("Matrix multiply cancelled")]
+    Cancelled,
+    /// 计算超时
+    #[error("Matrix multiply timed out")]
+    Timeout,
+    /// 矩阵奇异（或数值上接近奇异），无法求解线性方程组
+    #[error("Matrix is singular")]
+    Singular,
+}
+
+/// 工作线程执行任务失败时返回的错误
+///
+/// # 字段
+/// * `idx`: 失败任务在结果矩阵中的位置索引
+/// * `kind`: 失败原因
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("{}", self.describe())]
+pub struct WorkerError {
+    pub idx: usize,
+    pub kind: WorkerErrorKind,
+}
+
+/// 工作线程失败原因
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WorkerErrorKind {
+    /// 计算返回了错误
+    Failed(String),
+    /// 计算过程中发生 panic，内容为 panic 信息
+    Panicked(String),
+    /// 工作线程已退出，任务没有得到处理
+    Disconnected,
+}
+
+impl WorkerError {
+    /// 由计算错误创建
+    pub fn failed(idx: usize, err: impl fmt::Display) -> Self {
+        Self {
+            idx,
+            kind: WorkerErrorKind::Failed(err.to_string()),
+        }
+    }
+
+    /// 由 `catch_unwind` 捕获的 panic 负载创建
+    pub fn panicked(idx: usize, payload: Box<dyn Any + Send>) -> Self {
+        let message = if let Some(s) = payload.downcast_ref::<&str>() {
+            s.to_string()
+        } else if let Some(s) = payload.downcast_ref::<String>() {
+            s.clone()
+        } else {
+            "unknown panic".to_string()
+        };
+        Self {
+            idx,
+            kind: WorkerErrorKind::Panicked(message),
+        }
+    }
+
+    /// 工作线程已退出
+    pub fn disconnected(idx: usize) -> Self {
+        Self {
+            idx,
+            kind: WorkerErrorKind::Disconnected,
+        }
+    }
+
+    fn describe(&self) -> String {
+        match &self.kind {
+            WorkerErrorKind::Failed(e) => format!("Worker failed at cell {}: {}", self.idx, e),
+            WorkerErrorKind::Panicked(e) => format!("Worker panicked at cell {}: {}", self.idx, e),
+            WorkerErrorKind::Disconnected => {
+                format!("Worker disconnected before cell {} was computed", self.idx)
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use anyhow::{Result, anyhow};
+use std::sync::{OnceLock, mpsc};
+use wgpu::util::DeviceExt;
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, multiply};
+
+/// 工作组边长，需与 matmul.wgsl 中的 `@workgroup_size` 保持一致
+const WORKGROUP_SIZE: u32 = 8;
+
+/// GPU 计算上下文
+///
+/// 持有设备、队列和编译好的计算管线，可在多次乘法间复用
+pub struct GpuContext {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    max_binding_size: u64,
+}
+
+/// 使用 GPU 计算 f32 矩阵乘法
+///
+/// 首次调用时初始化全局的 `GpuContext`；找不到可用的 GPU 适配器，
+/// 或矩阵超出设备的存储缓冲区限制时，回退到 CPU 线程池计算
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<f32>>，包含乘积结果或错误信息
+pub fn multiply_gpu(a: &Matrix<f32>, b: &Matrix<f32>) -> Result<Matrix<f32>> {
+    static CONTEXT: OnceLock<Option<GpuContext>> = OnceLock::new();
+    match CONTEXT.get_or_init(GpuContext::new) {
+        Some(context) if context.supports(a, b) => context.multiply(a, b),
+        _ => Ok(multiply(a, b)?),
+    }
+}
+
+impl GpuContext {
+    /// 请求默认 GPU 适配器并编译计算管线
+    ///
+    /// # 返回值
+    /// 没有可用的适配器或设备时返回 None
+    pub fn new() -> Option<Self> {
+        pollster::block_on(Self::init())
+    }
+
+    async fn init() -> Option<Self> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::new_without_display_handle());
+        let adapter = instance
+            .request_adapter(&wgpu::RequestAdapterOptions::default())
+            .await
+            .ok()?;
+        let (device, queue) = adapter
+            .request_device(&wgpu::DeviceDescriptor::default())
+            .await
+            .ok()?;
+        let module = device.create_shader_module(wgpu::include_wgsl!("matmul.wgsl"));
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("matmul"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+        let max_binding_size = device.limits().max_storage_buffer_binding_size;
+        Some(Self {
+            device,
+            queue,
+            pipeline,
+            max_binding_size,
+        })
+    }
+
+    /// 判断设备能否处理这组矩阵
+    ///
+    /// 空矩阵无法创建缓冲区，过大的矩阵超出存储缓冲区绑定限制
+    pub fn supports(&self, a: &Matrix<f32>, b: &Matrix<f32>) -> bool {
+        let sizes = [a.data.len(), b.data.len(), a.row * b.col];
+        sizes
+            .iter()
+            .all(|&len| len > 0 && (len * size_of::<f32>()) as u64 <= self.max_binding_size)
+            && a.row.max(b.col).div_ceil(WORKGROUP_SIZE as usize) <= u16::MAX as usize
+    }
+
+    /// 在 GPU 上计算矩阵乘法
+    ///
+    /// # 参数
+    /// * `a`: 左操作数矩阵
+    /// * `b`: 右操作数矩阵
+    ///
+    /// # 返回值
+    /// 返回Result<Matrix<f32>>，包含乘积结果或错误信息
+    pub fn multiply(&self, a: &Matrix<f32>, b: &Matrix<f32>) -> Result<Matrix<f32>> {
+        if a.col != b.row {
+            return Err(MatrixError::DimensionMismatch {
+                a: (a.row, a.col),
+                b: (b.row, b.col),
+            }
+            .into());
+        }
+        if !self.supports(a, b) {
+            return Err(anyhow!(
+                "Matrix multiply error: matrix too large for GPU buffers"
+            ));
+        }
+
+        let output_size = (a.row * b.col * size_of::<f32>()) as u64;
+        let storage = |label, data: &[f32]| {
+            self.device
+                .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                    label: Some(label),
+                    contents: bytemuck::cast_slice(data),
+                    usage: wgpu::BufferUsages::STORAGE,
+                })
+        };
+        let a_buffer = storage("a", &a.data);
+        let b_buffer = storage("b", &b.data);
+        let c_buffer = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("c"),
+            size: output_size,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+            mapped_at_creation: false,
+        });
+        let dims = [a.row as u32, a.col as u32, b.col as u32, 0];
+        let dims_buffer = self
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("dims"),
+                contents: bytemuck::cast_slice(&dims),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let readback = self.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("readback"),
+            size: output_size,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let bind_group = self.device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("matmul"),
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: a_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: b_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: c_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: dims_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
+        let mut encoder = self
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            pass.dispatch_workgroups(
+                (b.col as u32).div_ceil(WORKGROUP_SIZE),
+                (a.row as u32).div_ceil(WORKGROUP_SIZE),
+                1,
+            );
+        }
+        encoder.copy_buffer_to_buffer(&c_buffer, 0, &readback, 0, output_size);
+        self.queue.submit([encoder.finish()]);
+
+        // 映射回读缓冲区并阻塞等待 GPU 完成
+        let (tx, rx) = mpsc::channel();
+        readback.map_async(wgpu::MapMode::Read, .., move |result| {
+            let _ = tx.send(result);
+        });
+        self.device
+            .poll(wgpu::PollType::wait_indefinitely())
+            .map_err(|e| anyhow!("GPU poll error: {}", e))?;
+        rx.recv()?
+            .map_err(|e| anyhow!("GPU buffer map error: {}", e))?;
+
+        let data = {
+            let view = readback
+                .get_mapped_range(..)
+                .map_err(|e| anyhow!("GPU buffer map error: {}", e))?;
+            bytemuck::cast_slice::<u8, f32>(&view).to_vec()
+        };
+        readback.unmap();
+
+        Ok(Matrix {
+            data,
+            row: a.row,
+            col: b.col,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_multiply_gpu() -> Result<()> {
+        // 没有 GPU 时回退到 CPU，结果应当一致
+        let a = Matrix::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let b = Matrix::new([7.0, 8.0, 9.0, 10.0, 11.0, 12.0], 3, 2);
+        let c = multiply_gpu(&a, &b)?;
+        assert_eq!(c, Matrix::new([58.0, 64.0, 139.0, 154.0], 2, 2));
+        Ok(())
+    }
+}
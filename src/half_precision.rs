@@ -0,0 +1,118 @@
+use std::fmt;
+
+use half::{bf16, f16};
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, multiply_kernel_into};
+use crate::options::MultiplyOptions;
+
+/// 半精度浮点元素类型
+///
+/// 半精度的有效位数很少，逐项累加时舍入误差会迅速放大，
+/// 因此乘加在 `f32` 中进行，只在写回结果时舍入一次
+pub trait HalfElement: fmt::Debug + Default + Copy + Send + Sync + 'static {
+    fn to_f32(self) -> f32;
+    fn from_f32(value: f32) -> Self;
+}
+
+impl HalfElement for f16 {
+    fn to_f32(self) -> f32 {
+        f16::to_f32(self)
+    }
+
+    fn from_f32(value: f32) -> Self {
+        f16::from_f32(value)
+    }
+}
+
+impl HalfElement for bf16 {
+    fn to_f32(self) -> f32 {
+        bf16::to_f32(self)
+    }
+
+    fn from_f32(value: f32) -> Self {
+        bf16::from_f32(value)
+    }
+}
+
+/// 半精度矩阵乘法，点积使用 `f32` 累加
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，包含乘积结果或错误信息
+pub fn multiply_half<T: HalfElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+) -> Result<Matrix<T>, MatrixError> {
+    multiply_half_with(a, b, MultiplyOptions::new())
+}
+
+/// 按配置执行半精度矩阵乘法，点积使用 `f32` 累加
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `options`: 乘法配置
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，包含乘积结果或错误信息
+pub fn multiply_half_with<T: HalfElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    options: MultiplyOptions<'_>,
+) -> Result<Matrix<T>, MatrixError> {
+    let mut out = Matrix {
+        data: vec![T::default(); a.row * b.col],
+        row: a.row,
+        col: b.col,
+    };
+    multiply_kernel_into(a, b, &mut out, options, widening_kernel)?;
+    Ok(out)
+}
+
+fn widening_kernel<T: HalfElement>(row: &[T], col: &[T]) -> Option<T> {
+    let sum = row
+        .iter()
+        .zip(col)
+        .fold(0.0f32, |sum, (&x, &y)| x.to_f32().mul_add(y.to_f32(), sum));
+    Some(T::from_f32(sum))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_multiply_half() -> Result<()> {
+        let a = Matrix::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].map(f16::from_f32), 2, 3);
+        let b = Matrix::new([7.0, 8.0, 9.0, 10.0, 11.0, 12.0].map(f16::from_f32), 3, 2);
+        let c = multiply_half(&a, &b)?;
+        assert_eq!(
+            c,
+            Matrix::new([58.0, 64.0, 139.0, 154.0].map(f16::from_f32), 2, 2)
+        );
+
+        let a = Matrix::new([1.0, 2.0].map(bf16::from_f32), 1, 2);
+        let b = Matrix::new([3.0, 4.0].map(bf16::from_f32), 2, 1);
+        assert_eq!(
+            multiply_half(&a, &b)?,
+            Matrix::new([bf16::from_f32(11.0)], 1, 1)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_half_accumulates_in_f32() -> Result<()> {
+        // 2048 在 f16 中加 1 会被舍入掉，f32 累加则能得到精确结果
+        let len = 4096;
+        let a = Matrix::new(vec![f16::ONE; len], 1, len);
+        let b = Matrix::new(vec![f16::ONE; len], len, 1);
+        let c = multiply_half(&a, &b)?;
+        assert_eq!(c, Matrix::new([f16::from_f32(4096.0)], 1, 1));
+        Ok(())
+    }
+}
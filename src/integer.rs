@@ -0,0 +1,185 @@
+use std::fmt;
+
+use crate::error::MatrixError;
+use crate::matrix::{Kernel, Matrix, multiply_kernel_into};
+use crate::options::MultiplyOptions;
+
+/// 支持显式溢出语义的整数元素类型
+pub trait IntegerElement: fmt::Debug + Default + Copy + Send + Sync + 'static {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+    fn saturating_add(self, rhs: Self) -> Self;
+    fn saturating_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_integer_element {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl IntegerElement for $ty {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, rhs)
+                }
+
+                fn wrapping_add(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_add(self, rhs)
+                }
+
+                fn wrapping_mul(self, rhs: Self) -> Self {
+                    <$ty>::wrapping_mul(self, rhs)
+                }
+
+                fn saturating_add(self, rhs: Self) -> Self {
+                    <$ty>::saturating_add(self, rhs)
+                }
+
+                fn saturating_mul(self, rhs: Self) -> Self {
+                    <$ty>::saturating_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer_element!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// 检查溢出的整数矩阵乘法
+///
+/// release 构建中整数乘加默认回绕，该函数在点积内核中使用 `checked_mul`/`checked_add`，
+/// 一旦溢出立即停止并指出出错的单元
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，溢出时返回 `MatrixError::Overflow { idx }`
+pub fn multiply_checked<T: IntegerElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+) -> Result<Matrix<T>, MatrixError> {
+    multiply_integer(a, b, checked_kernel)
+}
+
+/// 回绕语义的整数矩阵乘法
+///
+/// 乘加均按 `wrapping_*` 计算，debug 与 release 构建结果一致，不会 panic
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，只在维度不匹配等情况下出错
+pub fn multiply_wrapping<T: IntegerElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+) -> Result<Matrix<T>, MatrixError> {
+    multiply_integer(a, b, wrapping_kernel)
+}
+
+/// 饱和语义的整数矩阵乘法
+///
+/// 乘加均按 `saturating_*` 计算，溢出时结果停留在类型的最大或最小值，
+/// 注意饱和后的累加与求和顺序有关
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，只在维度不匹配等情况下出错
+pub fn multiply_saturating<T: IntegerElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+) -> Result<Matrix<T>, MatrixError> {
+    multiply_integer(a, b, saturating_kernel)
+}
+
+/// 使用指定内核计算整数矩阵乘法
+fn multiply_integer<T: IntegerElement>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    kernel: Kernel<T>,
+) -> Result<Matrix<T>, MatrixError> {
+    let mut out = Matrix {
+        data: vec![T::default(); a.row * b.col],
+        row: a.row,
+        col: b.col,
+    };
+    multiply_kernel_into(a, b, &mut out, MultiplyOptions::new(), kernel)?;
+    Ok(out)
+}
+
+fn checked_kernel<T: IntegerElement>(row: &[T], col: &[T]) -> Option<T> {
+    row.iter().zip(col).try_fold(T::default(), |sum, (&x, &y)| {
+        sum.checked_add(x.checked_mul(y)?)
+    })
+}
+
+fn wrapping_kernel<T: IntegerElement>(row: &[T], col: &[T]) -> Option<T> {
+    Some(row.iter().zip(col).fold(T::default(), |sum, (&x, &y)| {
+        sum.wrapping_add(x.wrapping_mul(y))
+    }))
+}
+
+fn saturating_kernel<T: IntegerElement>(row: &[T], col: &[T]) -> Option<T> {
+    Some(row.iter().zip(col).fold(T::default(), |sum, (&x, &y)| {
+        sum.saturating_add(x.saturating_mul(y))
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_multiply_checked() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4], 2, 2);
+        let b = Matrix::new([5, 6, 7, 8], 2, 2);
+        assert_eq!(
+            multiply_checked(&a, &b)?,
+            Matrix::new([19, 22, 43, 50], 2, 2)
+        );
+
+        let a = Matrix::new([1i32, 1, i32::MAX, 1], 2, 2);
+        let b = Matrix::new([1i32, 0, 2, 0], 2, 2);
+        assert_eq!(
+            multiply_checked(&a, &b).unwrap_err(),
+            MatrixError::Overflow { idx: 2 }
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_wrapping_and_saturating() -> Result<()> {
+        let a = Matrix::new([100i8, 100, -100, 1], 2, 2);
+        let b = Matrix::new([2i8, 0, 1, 1], 2, 2);
+        assert_eq!(
+            multiply_wrapping(&a, &b)?,
+            Matrix::new(
+                [
+                    100i8.wrapping_mul(2).wrapping_add(100),
+                    100,
+                    (-100i8).wrapping_mul(2).wrapping_add(1),
+                    1
+                ],
+                2,
+                2
+            )
+        );
+        assert_eq!(
+            multiply_saturating(&a, &b)?,
+            Matrix::new([i8::MAX, 100, i8::MIN + 1, 1], 2, 2)
+        );
+        Ok(())
+    }
+}
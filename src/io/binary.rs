@@ -0,0 +1,101 @@
+use super::FormatError;
+use crate::element::WireElement;
+use crate::matrix::Matrix;
+
+/// 二进制格式魔数
+const MAGIC: &[u8; 4] = b"CMAT";
+
+/// 二进制格式版本
+const VERSION: u8 = 1;
+
+/// 头部字节数：魔数、版本、类型标记以及 u64 的行数和列数
+const HEADER_LEN: usize = 4 + 1 + 1 + 8 + 8;
+
+impl<T: WireElement> Matrix<T> {
+    /// 编码为紧凑的二进制格式
+    ///
+    /// 头部依次为魔数 `CMAT`、版本号、元素类型标记和小端 u64 的行数、列数，
+    /// 之后是按行主序排列的小端元素
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(HEADER_LEN + self.data.len() * T::SIZE);
+        buf.extend_from_slice(MAGIC);
+        buf.push(VERSION);
+        buf.push(T::TAG);
+        buf.extend_from_slice(&(self.row as u64).to_le_bytes());
+        buf.extend_from_slice(&(self.col as u64).to_le_bytes());
+        for value in &self.data {
+            value.write_le(&mut buf);
+        }
+        buf
+    }
+
+    /// 从 `to_bytes` 的输出解码
+    ///
+    /// # 参数
+    /// * `bytes`: 编码后的数据
+    ///
+    /// # 返回值
+    /// 头部不合法、元素类型与 `T` 不一致或数据长度不正确时返回错误
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FormatError> {
+        if bytes.len() < HEADER_LEN || &bytes[..4] != MAGIC {
+            return Err(FormatError::Shape("not a binary matrix".to_string()));
+        }
+        if bytes[4] != VERSION {
+            return Err(FormatError::Shape(format!(
+                "unsupported binary matrix version {}",
+                bytes[4]
+            )));
+        }
+        if bytes[5] != T::TAG {
+            return Err(FormatError::Shape(format!(
+                "element tag {} does not match {}",
+                bytes[5],
+                T::TAG
+            )));
+        }
+        let row = read_dim(&bytes[6..14])?;
+        let col = read_dim(&bytes[14..22])?;
+        let body = &bytes[HEADER_LEN..];
+        let expected = row
+            .checked_mul(col)
+            .and_then(|n| n.checked_mul(T::SIZE))
+            .ok_or_else(|| FormatError::Shape(format!("{}x{} is too large", row, col)))?;
+        if body.len() != expected {
+            return Err(FormatError::Shape(format!(
+                "expected {} data bytes for {}x{}, got {}",
+                expected,
+                row,
+                col,
+                body.len()
+            )));
+        }
+        let data = body.chunks_exact(T::SIZE).map(T::read_le).collect();
+        Ok(Matrix { data, row, col })
+    }
+}
+
+fn read_dim(raw: &[u8]) -> Result<usize, FormatError> {
+    let mut dim = [0u8; 8];
+    dim.copy_from_slice(raw);
+    let dim = u64::from_le_bytes(dim);
+    usize::try_from(dim).map_err(|_| FormatError::Shape(format!("dimension {} is too large", dim)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_bytes_round_trip() -> Result<()> {
+        let m = Matrix::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let bytes = m.to_bytes();
+        assert_eq!(bytes.len(), HEADER_LEN + 6 * 8);
+        assert_eq!(Matrix::<f64>::from_bytes(&bytes)?, m);
+
+        assert!(Matrix::<i64>::from_bytes(&bytes).is_err());
+        assert!(Matrix::<f64>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+        assert!(Matrix::<f64>::from_bytes(&bytes[1..]).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,186 @@
+use std::fmt;
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+use super::FormatError;
+use crate::matrix::Matrix;
+
+/// CSV 读写选项
+///
+/// # 字段
+/// * `delimiter`: 字段分隔符，默认为 `,`
+/// * `has_header`: 首行是否为表头，为 true 时读取跳过首行
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    pub delimiter: char,
+    pub has_header: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: ',',
+            has_header: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// 设置字段分隔符
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// 设置首行是否为表头
+    pub fn has_header(mut self, has_header: bool) -> Self {
+        self.has_header = has_header;
+        self
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    /// 从逗号分隔的 CSV 读取矩阵，每行一条记录
+    ///
+    /// # 参数
+    /// * `reader`: 输入
+    ///
+    /// # 返回值
+    /// 解析失败时返回的错误包含出错的行号和列号（从 1 开始）
+    pub fn from_csv(reader: impl BufRead) -> Result<Self, FormatError> {
+        Self::from_csv_with(reader, CsvOptions::default())
+    }
+
+    /// 按指定选项从 CSV 读取矩阵，空行会被跳过
+    ///
+    /// # 参数
+    /// * `reader`: 输入
+    /// * `options`: 读写选项
+    ///
+    /// # 返回值
+    /// 解析失败或各行长度不一致时返回错误
+    pub fn from_csv_with(reader: impl BufRead, options: CsvOptions) -> Result<Self, FormatError> {
+        let mut data = Vec::new();
+        let mut row = 0;
+        let mut col = None;
+        for (i, line) in reader.lines().enumerate() {
+            let line = line?;
+            let line = line.trim_end_matches('\r');
+            if (i == 0 && options.has_header) || line.trim().is_empty() {
+                continue;
+            }
+            let mut fields = 0;
+            for (j, field) in line.split(options.delimiter).enumerate() {
+                let field = field.trim();
+                let value = field.parse::<T>().map_err(|e| FormatError::Parse {
+                    row: i + 1,
+                    col: j + 1,
+                    value: field.to_string(),
+                    message: e.to_string(),
+                })?;
+                data.push(value);
+                fields += 1;
+            }
+            match col {
+                None => col = Some(fields),
+                Some(expected) if expected != fields => {
+                    return Err(FormatError::Shape(format!(
+                        "row {} has {} fields, expected {}",
+                        i + 1,
+                        fields,
+                        expected
+                    )));
+                }
+                _ => {}
+            }
+            row += 1;
+        }
+        Ok(Matrix {
+            data,
+            row,
+            col: col.unwrap_or(0),
+        })
+    }
+}
+
+impl<T: fmt::Display> Matrix<T> {
+    /// 以逗号分隔的 CSV 写出矩阵
+    ///
+    /// # 参数
+    /// * `writer`: 输出
+    pub fn to_csv(&self, writer: impl Write) -> Result<(), FormatError> {
+        self.to_csv_with(writer, CsvOptions::default())
+    }
+
+    /// 按指定选项以 CSV 写出矩阵，`has_header` 为 true 时先写出 `c0,c1,...` 表头
+    ///
+    /// # 参数
+    /// * `writer`: 输出
+    /// * `options`: 读写选项
+    pub fn to_csv_with(
+        &self,
+        mut writer: impl Write,
+        options: CsvOptions,
+    ) -> Result<(), FormatError> {
+        let mut delimiter = [0u8; 4];
+        let delimiter = options.delimiter.encode_utf8(&mut delimiter).as_bytes();
+        if options.has_header {
+            for j in 0..self.col {
+                if j > 0 {
+                    writer.write_all(delimiter)?;
+                }
+                write!(writer, "c{}", j)?;
+            }
+            writeln!(writer)?;
+        }
+        for i in 0..self.row {
+            for j in 0..self.col {
+                if j > 0 {
+                    writer.write_all(delimiter)?;
+                }
+                write!(writer, "{}", self.data[i * self.col + j])?;
+            }
+            writeln!(writer)?;
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_csv_round_trip() -> Result<()> {
+        let m = Matrix::new([1.5, 2.0, 3.0, 4.0, 5.0, 6.25], 2, 3);
+        let mut buf = Vec::new();
+        m.to_csv(&mut buf)?;
+        assert_eq!(String::from_utf8(buf.clone())?, "1.5,2,3\n4,5,6.25\n");
+        assert_eq!(Matrix::<f64>::from_csv(buf.as_slice())?, m);
+
+        let options = CsvOptions::default().delimiter(';').has_header(true);
+        let mut buf = Vec::new();
+        m.to_csv_with(&mut buf, options)?;
+        assert_eq!(
+            String::from_utf8(buf.clone())?,
+            "c0;c1;c2\n1.5;2;3\n4;5;6.25\n"
+        );
+        assert_eq!(Matrix::<f64>::from_csv_with(buf.as_slice(), options)?, m);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_parse_error_position() {
+        let err = Matrix::<i32>::from_csv("1,2\n3,x\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, FormatError::Parse { row: 2, col: 2, .. }));
+
+        let err = Matrix::<i32>::from_csv("1,2\n3\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, FormatError::Shape(_)));
+    }
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use image::GrayImage;
+
+use super::FormatError;
+use crate::matrix::Matrix;
+
+impl Matrix<u8> {
+    /// 读取图像并转换为灰度矩阵
+    ///
+    /// 图像高度为行数、宽度为列数，彩色图像按亮度转换为灰度
+    ///
+    /// # 参数
+    /// * `path`: 图像路径，格式由扩展名和文件内容推断
+    pub fn from_image(path: impl AsRef<Path>) -> Result<Self, FormatError> {
+        Ok(Self::from(image::open(path)?.into_luma8()))
+    }
+
+    /// 将矩阵作为灰度图像写出
+    ///
+    /// # 参数
+    /// * `path`: 图像路径，格式由扩展名推断
+    pub fn to_image(&self, path: impl AsRef<Path>) -> Result<(), FormatError> {
+        GrayImage::try_from(self)?.save(path)?;
+        Ok(())
+    }
+}
+
+impl From<GrayImage> for Matrix<u8> {
+    fn from(image: GrayImage) -> Self {
+        let (width, height) = image.dimensions();
+        Matrix {
+            data: image.into_raw(),
+            row: height as usize,
+            col: width as usize,
+        }
+    }
+}
+
+impl TryFrom<&Matrix<u8>> for GrayImage {
+    type Error = FormatError;
+
+    /// 矩阵的行数或列数超出 `u32` 范围时返回错误
+    fn try_from(m: &Matrix<u8>) -> Result<Self, Self::Error> {
+        let too_large =
+            || FormatError::Shape(format!("{}x{} is too large for an image", m.row, m.col));
+        let width = u32::try_from(m.col).map_err(|_| too_large())?;
+        let height = u32::try_from(m.row).map_err(|_| too_large())?;
+        GrayImage::from_raw(width, height, m.data.clone()).ok_or_else(|| {
+            FormatError::Shape(format!(
+                "{} elements do not fill a {}x{} image",
+                m.data.len(),
+                m.row,
+                m.col
+            ))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_image_round_trip() -> Result<()> {
+        let m = Matrix::new([0u8, 64, 128, 192, 255, 32], 2, 3);
+        let path = std::env::temp_dir().join(format!("concurrency-{}.png", std::process::id()));
+        m.to_image(&path)?;
+        let loaded = Matrix::<u8>::from_image(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!(loaded, m);
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use std::fmt;
+
+use crate::matrix::Matrix;
+
+impl<T: fmt::Display> Matrix<T> {
+    /// 渲染为 GitHub 风格的 Markdown 表格
+    ///
+    /// 表头为 `c0`、`c1` 等列名，与 CSV 表头一致，各列右对齐并按最宽的单元格补齐空格
+    pub fn to_markdown(&self) -> String {
+        let header: Vec<String> = (0..self.col).map(|j| format!("c{}", j)).collect();
+        let cells: Vec<String> = self.data.iter().map(ToString::to_string).collect();
+        // 分隔行至少需要 `--:` 三个字符
+        let widths: Vec<usize> = (0..self.col)
+            .map(|j| {
+                cells[j..]
+                    .iter()
+                    .step_by(self.col)
+                    .chain([&header[j]])
+                    .map(|cell| cell.chars().count())
+                    .fold(3, usize::max)
+            })
+            .collect();
+
+        let mut out = String::new();
+        push_row(&mut out, header.iter(), &widths);
+        let separator: Vec<String> = widths
+            .iter()
+            .map(|w| format!("{}:", "-".repeat(w - 1)))
+            .collect();
+        push_row(&mut out, separator.iter(), &widths);
+        for row in cells.chunks(self.col.max(1)) {
+            push_row(&mut out, row.iter(), &widths);
+        }
+        out
+    }
+}
+
+/// 追加一行表格，单元格右对齐到对应列宽
+fn push_row<'a>(out: &mut String, cells: impl Iterator<Item = &'a String>, widths: &[usize]) {
+    out.push('|');
+    for (cell, width) in cells.zip(widths) {
+        out.push_str(&format!(" {:>width$} |", cell));
+    }
+    out.push('\n');
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_markdown() {
+        let m = Matrix::new([1.5, 20.0, 3.0, 4.0, 5.0, 600.25], 2, 3);
+        assert_eq!(
+            m.to_markdown(),
+            "|  c0 |  c1 |     c2 |\n\
+             | --: | --: | -----: |\n\
+             | 1.5 |  20 |      3 |\n\
+             |   4 |   5 | 600.25 |\n"
+        );
+    }
+}
@@ -0,0 +1,36 @@
+mod binary;
+mod csv;
+#[cfg(feature = "image")]
+mod image;
+mod markdown;
+mod mtx;
+mod npy;
+
+use thiserror::Error;
+
+pub use csv::CsvOptions;
+pub use mtx::{DEFAULT_MTX_MAX_ELEMENTS, MtxElement};
+pub use npy::NpyElement;
+
+/// 矩阵导入导出错误
+#[derive(Debug, Error)]
+pub enum FormatError {
+    /// 底层读写失败
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+    /// 元素解析失败，`row`、`col` 为从 1 开始的行号和列号
+    #[error("Parse error at row {row}, column {col}: {value:?}: {message}")]
+    Parse {
+        row: usize,
+        col: usize,
+        value: String,
+        message: String,
+    },
+    /// 数据形状不合法
+    #[error("Shape error: {0}")]
+    Shape(String),
+    /// 图像编解码失败
+    #[cfg(feature = "image")]
+    #[error("Image error: {0}")]
+    Image(#[from] ::image::ImageError),
+}
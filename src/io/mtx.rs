@@ -0,0 +1,389 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use super::FormatError;
+use crate::matrix::Matrix;
+
+/// Matrix Market 文件头标识
+const BANNER: &str = "%%MatrixMarket";
+
+/// `read_mtx` 允许的最大元素个数，文件头声明的形状超过时返回错误而不分配内存
+pub const DEFAULT_MTX_MAX_ELEMENTS: usize = 1 << 28;
+
+/// 可以写出为 Matrix Market 文件的元素类型
+///
+/// `FIELD` 为文件头中的数据域，整数为 `integer`，浮点数为 `real`
+pub trait MtxElement {
+    const FIELD: &'static str;
+}
+
+impl MtxElement for i32 {
+    const FIELD: &'static str = "integer";
+}
+
+impl MtxElement for i64 {
+    const FIELD: &'static str = "integer";
+}
+
+impl MtxElement for u32 {
+    const FIELD: &'static str = "integer";
+}
+
+impl MtxElement for u64 {
+    const FIELD: &'static str = "integer";
+}
+
+impl MtxElement for f32 {
+    const FIELD: &'static str = "real";
+}
+
+impl MtxElement for f64 {
+    const FIELD: &'static str = "real";
+}
+
+impl<T> Matrix<T>
+where
+    T: FromStr + Default + Clone,
+    T::Err: fmt::Display,
+{
+    /// 读取 Matrix Market `.mtx` 文件
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    pub fn from_mtx(path: impl AsRef<Path>) -> Result<Self, FormatError> {
+        Self::read_mtx(BufReader::new(File::open(path)?))
+    }
+
+    /// 从任意输入读取 Matrix Market 数据
+    ///
+    /// 支持 `array`（按列主序的稠密格式）和 `coordinate`（从 1 开始的三元组）两种格式，
+    /// 对称矩阵只存储下三角部分，读取时会补全上三角，未出现的元素为 `T::default()`
+    ///
+    /// # 参数
+    /// * `reader`: 输入
+    ///
+    /// # 返回值
+    /// 文件头不合法、元素个数超过 `DEFAULT_MTX_MAX_ELEMENTS`、元素解析失败
+    /// 或条目数量与声明不一致时返回错误
+    pub fn read_mtx(reader: impl BufRead) -> Result<Self, FormatError> {
+        Self::read_mtx_with_limit(reader, DEFAULT_MTX_MAX_ELEMENTS)
+    }
+
+    /// 从任意输入读取 Matrix Market 数据，元素个数不超过 `max_elements`
+    ///
+    /// 文件头中的形状在分配稠密矩阵之前先与 `max_elements` 比较
+    ///
+    /// # 参数
+    /// * `reader`: 输入
+    /// * `max_elements`: 允许的最大元素个数
+    pub fn read_mtx_with_limit(
+        reader: impl BufRead,
+        max_elements: usize,
+    ) -> Result<Self, FormatError> {
+        let mut lines = reader.lines().enumerate();
+        let banner = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Err(FormatError::Shape("empty Matrix Market file".to_string())),
+        };
+        let header: Vec<String> = banner
+            .split_whitespace()
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let [banner, object, format, field, symmetry] = &header[..] else {
+            return Err(FormatError::Shape(format!(
+                "invalid Matrix Market header {:?}",
+                banner
+            )));
+        };
+        if banner != &BANNER.to_ascii_lowercase() || object != "matrix" {
+            return Err(FormatError::Shape(
+                "not a Matrix Market matrix file".to_string(),
+            ));
+        }
+        let coordinate = match format.as_str() {
+            "array" => false,
+            "coordinate" => true,
+            f => return Err(FormatError::Shape(format!("unsupported format {}", f))),
+        };
+        if field != "real" && field != "integer" {
+            return Err(FormatError::Shape(format!("unsupported field {}", field)));
+        }
+        let symmetric = match symmetry.as_str() {
+            "general" => false,
+            "symmetric" => true,
+            s => return Err(FormatError::Shape(format!("unsupported symmetry {}", s))),
+        };
+
+        // 跳过注释和空行，余下每行按空白切分，并记录从 1 开始的行号
+        let mut records = lines.filter_map(|(i, line)| match line {
+            Ok(line) if line.starts_with('%') || line.trim().is_empty() => None,
+            Ok(line) => Some(Ok((i + 1, line))),
+            Err(e) => Some(Err(FormatError::Io(e))),
+        });
+
+        let (size_line, size) = records
+            .next()
+            .ok_or_else(|| FormatError::Shape("missing size line".to_string()))??;
+        let size = size
+            .split_whitespace()
+            .enumerate()
+            .map(|(j, s)| parse_field::<usize>(s, size_line, j))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (row, col, entries) = match (coordinate, &size[..]) {
+            (false, &[row, col]) => (row, col, None),
+            (true, &[row, col, nnz]) => (row, col, Some(nnz)),
+            _ => {
+                return Err(FormatError::Shape(format!("invalid size line {:?}", size)));
+            }
+        };
+        if symmetric && row != col {
+            return Err(FormatError::Shape(format!(
+                "symmetric matrix must be square, got {}x{}",
+                row, col
+            )));
+        }
+
+        let len = row
+            .checked_mul(col)
+            .filter(|&len| len <= max_elements)
+            .ok_or_else(|| {
+                FormatError::Shape(format!(
+                    "{}x{} exceeds the limit of {} elements",
+                    row, col, max_elements
+                ))
+            })?;
+        // 对称矩阵只列出下三角，条目数不会超过下三角的单元数
+        let cells = if symmetric { row * (row + 1) / 2 } else { len };
+        if let Some(nnz) = entries.filter(|&nnz| nnz > cells) {
+            return Err(FormatError::Shape(format!(
+                "{} entries do not fit in {}x{}",
+                nnz, row, col
+            )));
+        }
+        let mut data = vec![T::default(); len];
+        let mut count = 0;
+        match entries {
+            None => {
+                // array 格式按列主序存储，对称矩阵只列出下三角，(i, j) 为下一个条目的位置
+                let (mut i, mut j) = (0, 0);
+                for record in records {
+                    let (line, text) = record?;
+                    for (k, field) in text.split_whitespace().enumerate() {
+                        if count == cells {
+                            return Err(FormatError::Shape(format!(
+                                "too many entries at line {}",
+                                line
+                            )));
+                        }
+                        let value = parse_field::<T>(field, line, k)?;
+                        if symmetric {
+                            data[j * col + i] = value.clone();
+                        }
+                        data[i * col + j] = value;
+                        count += 1;
+                        i += 1;
+                        if i == row {
+                            j += 1;
+                            i = if symmetric { j } else { 0 };
+                        }
+                    }
+                }
+                if count != cells {
+                    return Err(FormatError::Shape(format!(
+                        "expected {} entries, got {}",
+                        cells, count
+                    )));
+                }
+            }
+            Some(nnz) => {
+                for record in records {
+                    let (line, text) = record?;
+                    let fields: Vec<&str> = text.split_whitespace().collect();
+                    let [i, j, value] = fields[..] else {
+                        return Err(FormatError::Shape(format!(
+                            "line {} has {} fields, expected 3",
+                            line,
+                            fields.len()
+                        )));
+                    };
+                    let i = parse_field::<usize>(i, line, 0)?;
+                    let j = parse_field::<usize>(j, line, 1)?;
+                    if i == 0 || j == 0 || i > row || j > col {
+                        return Err(FormatError::Shape(format!(
+                            "entry ({}, {}) at line {} is outside {}x{}",
+                            i, j, line, row, col
+                        )));
+                    }
+                    let value = parse_field::<T>(value, line, 2)?;
+                    if symmetric {
+                        data[(j - 1) * col + (i - 1)] = value.clone();
+                    }
+                    data[(i - 1) * col + (j - 1)] = value;
+                    count += 1;
+                }
+                if count != nnz {
+                    return Err(FormatError::Shape(format!(
+                        "expected {} entries, got {}",
+                        nnz, count
+                    )));
+                }
+            }
+        }
+        Ok(Matrix { data, row, col })
+    }
+}
+
+impl<T: MtxElement + fmt::Display> Matrix<T> {
+    /// 以 Matrix Market `array` 格式写出矩阵
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    pub fn to_mtx(&self, path: impl AsRef<Path>) -> Result<(), FormatError> {
+        self.write_mtx(BufWriter::new(File::create(path)?))
+    }
+
+    /// 以 Matrix Market `array` 格式写出到任意输出，元素按列主序每行一个
+    pub fn write_mtx(&self, mut writer: impl Write) -> Result<(), FormatError> {
+        writeln!(writer, "{} matrix array {} general", BANNER, T::FIELD)?;
+        writeln!(writer, "{} {}", self.row, self.col)?;
+        for j in 0..self.col {
+            for i in 0..self.row {
+                writeln!(writer, "{}", self.data[i * self.col + j])?;
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<T: MtxElement + fmt::Display + Default + PartialEq> Matrix<T> {
+    /// 以 Matrix Market `coordinate` 格式写出矩阵，只写出非零元素
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    pub fn to_mtx_coordinate(&self, path: impl AsRef<Path>) -> Result<(), FormatError> {
+        self.write_mtx_coordinate(BufWriter::new(File::create(path)?))
+    }
+
+    /// 以 Matrix Market `coordinate` 格式写出到任意输出，值等于 `T::default()` 的元素被省略
+    pub fn write_mtx_coordinate(&self, mut writer: impl Write) -> Result<(), FormatError> {
+        let zero = T::default();
+        let nnz = self.data.iter().filter(|v| **v != zero).count();
+        writeln!(writer, "{} matrix coordinate {} general", BANNER, T::FIELD)?;
+        writeln!(writer, "{} {} {}", self.row, self.col, nnz)?;
+        for i in 0..self.row {
+            for j in 0..self.col {
+                let value = &self.data[i * self.col + j];
+                if *value != zero {
+                    writeln!(writer, "{} {} {}", i + 1, j + 1, value)?;
+                }
+            }
+        }
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// 解析单个字段，`line` 为从 1 开始的行号，`idx` 为从 0 开始的字段序号
+fn parse_field<V>(field: &str, line: usize, idx: usize) -> Result<V, FormatError>
+where
+    V: FromStr,
+    V::Err: fmt::Display,
+{
+    field.parse::<V>().map_err(|e| FormatError::Parse {
+        row: line,
+        col: idx + 1,
+        value: field.to_string(),
+        message: e.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_mtx_round_trip() -> Result<()> {
+        let m = Matrix::new([1.5, 0.0, 3.0, 0.0, 5.0, 0.0], 2, 3);
+        let mut buf = Vec::new();
+        m.write_mtx(&mut buf)?;
+        assert_eq!(
+            String::from_utf8(buf.clone())?,
+            "%%MatrixMarket matrix array real general\n2 3\n1.5\n0\n0\n5\n3\n0\n"
+        );
+        assert_eq!(Matrix::<f64>::read_mtx(buf.as_slice())?, m);
+
+        let mut buf = Vec::new();
+        m.write_mtx_coordinate(&mut buf)?;
+        assert_eq!(
+            String::from_utf8(buf.clone())?,
+            "%%MatrixMarket matrix coordinate real general\n2 3 3\n1 1 1.5\n1 3 3\n2 2 5\n"
+        );
+        assert_eq!(Matrix::<f64>::read_mtx(buf.as_slice())?, m);
+
+        let path = std::env::temp_dir().join(format!("concurrency-{}.mtx", std::process::id()));
+        m.to_mtx_coordinate(&path)?;
+        assert_eq!(Matrix::<f64>::from_mtx(&path)?, m);
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_mtx_symmetric() -> Result<()> {
+        let input = "%%MatrixMarket matrix coordinate integer symmetric\n\
+                     % lower triangle only\n\
+                     3 3 4\n1 1 1\n2 1 2\n3 2 3\n3 3 4\n";
+        let m = Matrix::<i32>::read_mtx(input.as_bytes())?;
+        assert_eq!(m, Matrix::new([1, 2, 0, 2, 0, 3, 0, 3, 4], 3, 3));
+
+        let input = "%%MatrixMarket matrix array integer symmetric\n2 2\n1\n2\n3\n";
+        let m = Matrix::<i32>::read_mtx(input.as_bytes())?;
+        assert_eq!(m, Matrix::new([1, 2, 2, 3], 2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mtx_errors() {
+        let err = Matrix::<i32>::read_mtx(
+            "%%MatrixMarket matrix coordinate integer general\n2 2 1\n1 x 1\n".as_bytes(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, FormatError::Parse { row: 3, col: 2, .. }));
+
+        let err = Matrix::<i32>::read_mtx(
+            "%%MatrixMarket matrix coordinate integer general\n2 2 2\n1 1 1\n".as_bytes(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, FormatError::Shape(_)));
+
+        let err = Matrix::<i32>::read_mtx(
+            "%%MatrixMarket matrix coordinate complex general\n1 1 0\n".as_bytes(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, FormatError::Shape(_)));
+
+        let err = Matrix::<i32>::read_mtx(
+            "%%MatrixMarket matrix coordinate integer general\n18446744073709551615 2 0\n"
+                .as_bytes(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, FormatError::Shape(_)));
+
+        // 形状在分配之前与上限比较，条目数不能超过单元数
+        let err = Matrix::<f64>::read_mtx(
+            "%%MatrixMarket matrix coordinate real general\n100000 100000 1\n1 1 1\n".as_bytes(),
+        )
+        .unwrap_err();
+        assert!(matches!(err, FormatError::Shape(_)));
+        let err = Matrix::<i32>::read_mtx_with_limit(
+            "%%MatrixMarket matrix coordinate integer general\n2 2 5\n".as_bytes(),
+            4,
+        )
+        .unwrap_err();
+        assert!(matches!(err, FormatError::Shape(_)));
+    }
+}
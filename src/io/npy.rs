@@ -0,0 +1,254 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+use super::FormatError;
+use crate::element::WireElement;
+use crate::matrix::Matrix;
+
+/// npy 文件魔数
+const MAGIC: &[u8; 6] = b"\x93NUMPY";
+
+/// 头部的最大字节数，与 NumPy 默认的 `max_header_size` 一致
+const MAX_HEADER_LEN: usize = 10_000;
+
+/// 可以读写 npy 文件的元素类型
+///
+/// `DESCR` 为 NumPy 的小端类型描述符，元素编码复用 `WireElement`
+pub trait NpyElement: WireElement {
+    const DESCR: &'static str;
+}
+
+impl NpyElement for i32 {
+    const DESCR: &'static str = "<i4";
+}
+
+impl NpyElement for i64 {
+    const DESCR: &'static str = "<i8";
+}
+
+impl NpyElement for u32 {
+    const DESCR: &'static str = "<u4";
+}
+
+impl NpyElement for u64 {
+    const DESCR: &'static str = "<u8";
+}
+
+impl NpyElement for f32 {
+    const DESCR: &'static str = "<f4";
+}
+
+impl NpyElement for f64 {
+    const DESCR: &'static str = "<f8";
+}
+
+impl<T: NpyElement> Matrix<T> {
+    /// 读取 NumPy `.npy` 文件
+    ///
+    /// 只支持 C 连续（`fortran_order: False`）的二维数组，元素类型必须与 `T` 一致
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    pub fn from_npy(path: impl AsRef<Path>) -> Result<Self, FormatError> {
+        Self::read_npy(BufReader::new(File::open(path)?))
+    }
+
+    /// 以 NumPy `.npy` 1.0 格式写出矩阵
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    pub fn to_npy(&self, path: impl AsRef<Path>) -> Result<(), FormatError> {
+        self.write_npy(BufWriter::new(File::create(path)?))
+    }
+
+    /// 从任意输入读取 npy 数据
+    pub fn read_npy(mut reader: impl Read) -> Result<Self, FormatError> {
+        let mut magic = [0u8; 8];
+        reader.read_exact(&mut magic)?;
+        if &magic[..6] != MAGIC {
+            return Err(FormatError::Shape("not an npy file".to_string()));
+        }
+        // 1.0 版本头长度为 u16，2.0 及以上为 u32
+        let header_len = match magic[6] {
+            1 => {
+                let mut len = [0u8; 2];
+                reader.read_exact(&mut len)?;
+                u16::from_le_bytes(len) as usize
+            }
+            2 | 3 => {
+                let mut len = [0u8; 4];
+                reader.read_exact(&mut len)?;
+                u32::from_le_bytes(len) as usize
+            }
+            v => return Err(FormatError::Shape(format!("unsupported npy version {}", v))),
+        };
+        if header_len > MAX_HEADER_LEN {
+            return Err(FormatError::Shape(format!(
+                "npy header of {} bytes exceeds the limit of {} bytes",
+                header_len, MAX_HEADER_LEN
+            )));
+        }
+        let mut header = vec![0u8; header_len];
+        reader.read_exact(&mut header)?;
+        let header = String::from_utf8_lossy(&header);
+
+        let descr = header_value(&header, "descr")
+            .map(|v| v.trim_matches(|c| c == '\'' || c == '"'))
+            .ok_or_else(|| FormatError::Shape("npy header has no descr".to_string()))?;
+        if descr != T::DESCR {
+            return Err(FormatError::Shape(format!(
+                "npy dtype {} does not match {}",
+                descr,
+                T::DESCR
+            )));
+        }
+        if header_value(&header, "fortran_order") != Some("False") {
+            return Err(FormatError::Shape(
+                "only C-contiguous npy arrays are supported".to_string(),
+            ));
+        }
+        let shape = header_value(&header, "shape")
+            .ok_or_else(|| FormatError::Shape("npy header has no shape".to_string()))?
+            .trim_matches(|c| c == '(' || c == ')')
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                s.parse::<usize>()
+                    .map_err(|_| FormatError::Shape(format!("invalid npy shape entry {:?}", s)))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let [row, col] = shape[..] else {
+            return Err(FormatError::Shape(format!(
+                "expected a 2-D npy array, got shape {:?}",
+                shape
+            )));
+        };
+
+        let len = row
+            .checked_mul(col)
+            .and_then(|n| n.checked_mul(T::SIZE))
+            .ok_or_else(|| FormatError::Shape(format!("{}x{} is too large", row, col)))?;
+        // 按实际读到的数据增长缓冲区，头部声明的形状再大也不会预先分配
+        let mut bytes = Vec::new();
+        reader.take(len as u64).read_to_end(&mut bytes)?;
+        if bytes.len() != len {
+            return Err(FormatError::Shape(format!(
+                "expected {} data bytes for {}x{}, got {}",
+                len,
+                row,
+                col,
+                bytes.len()
+            )));
+        }
+        let data = bytes.chunks_exact(T::SIZE).map(T::read_le).collect();
+        Ok(Matrix { data, row, col })
+    }
+
+    /// 以 npy 1.0 格式写出到任意输出
+    pub fn write_npy(&self, mut writer: impl Write) -> Result<(), FormatError> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({}, {}), }}",
+            T::DESCR,
+            self.row,
+            self.col
+        );
+        // 魔数、版本和长度共 10 字节，头部以换行结尾并用空格填充到 64 字节对齐
+        let total = (10 + header.len() + 1).div_ceil(64) * 64;
+        header.push_str(&" ".repeat(total - 10 - header.len() - 1));
+        header.push('\n');
+
+        writer.write_all(MAGIC)?;
+        writer.write_all(&[1, 0])?;
+        writer.write_all(&(header.len() as u16).to_le_bytes())?;
+        writer.write_all(header.as_bytes())?;
+        let mut buf = Vec::with_capacity(self.data.len() * T::SIZE);
+        for value in &self.data {
+            value.write_le(&mut buf);
+        }
+        writer.write_all(&buf)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// 从 npy 头部字典中取出某个键的原始值
+fn header_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}'", key))? + key.len() + 2;
+    let rest = header[start..].trim_start().strip_prefix(':')?.trim_start();
+    let end = if rest.starts_with('(') {
+        rest.find(')')? + 1
+    } else {
+        rest.find(',').unwrap_or(rest.len())
+    };
+    Some(rest[..end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_npy_round_trip() -> Result<()> {
+        let m = Matrix::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3);
+        let mut buf = Vec::new();
+        m.write_npy(&mut buf)?;
+        assert_eq!(&buf[..6], MAGIC);
+        assert_eq!(buf.len(), 128 + 6 * 8);
+        assert_eq!(Matrix::<f64>::read_npy(buf.as_slice())?, m);
+        assert!(Matrix::<i32>::read_npy(buf.as_slice()).is_err());
+
+        let path = std::env::temp_dir().join(format!("concurrency-{}.npy", std::process::id()));
+        m.to_npy(&path)?;
+        assert_eq!(Matrix::<f64>::from_npy(&path)?, m);
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_npy_rejects_fortran_order() {
+        let header = "{'descr': '<f8', 'fortran_order': True, 'shape': (1, 1), }";
+        let mut buf = MAGIC.to_vec();
+        buf.extend_from_slice(&[1, 0]);
+        buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        buf.extend_from_slice(header.as_bytes());
+        buf.extend_from_slice(&1.0f64.to_le_bytes());
+        assert!(Matrix::<f64>::read_npy(buf.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_npy_rejects_oversized_header() {
+        let mut buf = MAGIC.to_vec();
+        buf.extend_from_slice(&[2, 0]);
+        buf.extend_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Matrix::<f64>::read_npy(buf.as_slice()),
+            Err(FormatError::Shape(_))
+        ));
+
+        let header = "{'descr': '', 'fortran_order': False, 'shape': (1, 1), }";
+        let mut buf = MAGIC.to_vec();
+        buf.extend_from_slice(&[1, 0]);
+        buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        buf.extend_from_slice(header.as_bytes());
+        assert!(Matrix::<f64>::read_npy(buf.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_npy_rejects_oversized_shape() {
+        for shape in ["(18446744073709551615, 2)", "(1099511627776, 1)"] {
+            let header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': {shape}, }}");
+            let mut buf = MAGIC.to_vec();
+            buf.extend_from_slice(&[1, 0]);
+            buf.extend_from_slice(&(header.len() as u16).to_le_bytes());
+            buf.extend_from_slice(header.as_bytes());
+            buf.extend_from_slice(&1.0f64.to_le_bytes());
+            assert!(matches!(
+                Matrix::<f64>::read_npy(buf.as_slice()),
+                Err(FormatError::Shape(_))
+            ));
+        }
+    }
+}
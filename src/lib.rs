@@ -1,5 +1,172 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "std")]
+pub mod actor;
+#[cfg(feature = "affinity")]
+pub mod affinity;
+#[cfg(feature = "std")]
+pub mod analysis;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+pub mod banded;
+#[cfg(feature = "std")]
+pub mod bridge;
+#[cfg(feature = "std")]
+pub mod cancel;
+#[cfg(feature = "std")]
+pub mod channel;
+#[cfg(feature = "complex")]
+mod complex_impl;
+#[cfg(feature = "std")]
+pub mod conv;
+#[cfg(feature = "std")]
+pub mod distance;
+#[cfg(feature = "std")]
+pub mod distributed;
+pub mod element;
+pub mod error;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+#[cfg(feature = "half")]
+pub mod half_precision;
+#[cfg(feature = "std")]
+pub mod integer;
+#[cfg(feature = "std")]
+pub mod io;
+#[cfg(feature = "std")]
+pub mod linalg;
+#[cfg(feature = "std")]
+pub mod lockfree;
 pub mod matrix;
+#[cfg(feature = "std")]
+pub mod metrics;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+#[cfg(feature = "std")]
+pub mod nn;
+#[cfg(feature = "affinity")]
+pub mod numa;
+#[cfg(feature = "std")]
+pub mod options;
+#[cfg(feature = "std")]
+pub mod par;
+pub mod permutation;
+#[cfg(feature = "std")]
+pub mod pipeline;
+#[cfg(feature = "std")]
+pub mod pool;
+#[cfg(feature = "std")]
+pub mod preprocess;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+#[cfg(feature = "std")]
+pub mod scheduler;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "std")]
+pub mod shared_matrix;
+pub mod sparse;
+pub mod static_matrix;
+#[cfg(feature = "std")]
+pub mod stats;
+#[cfg(feature = "proptest")]
+pub mod strategy;
+#[cfg(feature = "std")]
+pub mod streaming;
+#[cfg(feature = "std")]
+pub mod sync;
+#[cfg(feature = "std")]
+pub mod task_group;
+#[cfg(feature = "std")]
+pub mod trace;
 pub mod vector;
+#[cfg(feature = "std")]
+pub mod work_queue;
 
-pub use matrix::{Matrix, multiply};
+#[cfg(feature = "std")]
+pub use actor::{Actor, ActorAddr, ActorError, ActorHandle, spawn_actor};
+#[cfg(feature = "std")]
+pub use analysis::{
+    LinearRegression, Pca, covariance, linreg, pca, random_projection, random_projection_with_rng,
+};
+#[cfg(feature = "arbitrary")]
+pub use arbitrary_impl::{ARBITRARY_MAX_DIM, Multiplicable};
+pub use banded::BandedMatrix;
+#[cfg(feature = "std")]
+pub use bridge::multiply_async;
+#[cfg(feature = "tokio")]
+pub use bridge::multiply_blocking_on;
+#[cfg(feature = "std")]
+pub use cancel::CancelToken;
+#[cfg(feature = "std")]
+pub use conv::{Padding, convolve2d, convolve2d_on};
+#[cfg(feature = "std")]
+pub use distance::{Metric, pairwise_distances, pairwise_distances_on};
+#[cfg(feature = "std")]
+pub use distributed::{
+    DEFAULT_IO_TIMEOUT, DEFAULT_MAX_CONNECTIONS, DEFAULT_MAX_PAYLOAD_BYTES, DistributedOptions,
+    ErrorHandler, multiply_distributed, multiply_distributed_with, run_worker, run_worker_with,
+    serve_worker, serve_worker_with,
+};
+pub use element::WireElement;
+pub use error::{MatrixError, WorkerError, WorkerErrorKind};
+#[cfg(feature = "half")]
+pub use half_precision::{HalfElement, multiply_half, multiply_half_with};
+#[cfg(feature = "std")]
+pub use integer::{IntegerElement, multiply_checked, multiply_saturating, multiply_wrapping};
+#[cfg(feature = "std")]
+pub use io::{CsvOptions, DEFAULT_MTX_MAX_ELEMENTS, FormatError, MtxElement, NpyElement};
+#[cfg(feature = "std")]
+pub use linalg::{Lu, SymmetricEigen, lu_decompose, symmetric_eigen};
+#[cfg(feature = "std")]
+pub use matrix::{
+    Cells, arbitrary_matrix, arbitrary_multiplicable, multiply, multiply_batch, multiply_batch_on,
+    multiply_cells, multiply_into, multiply_into_with, multiply_views, multiply_with,
+    multiply_with_cancel, multiply_with_timeout,
+};
+pub use matrix::{
+    DEFAULT_DISPLAY_EDGE_ITEMS, DEFAULT_DISPLAY_THRESHOLD, Layout, Matrix, MatrixDisplay,
+    MatrixView, multiply_seq, multiply_sequential, multiply_views_sequential,
+};
+#[cfg(feature = "std")]
+pub use metrics::{CmapMetrics, DEFAULT_METRICS_SHARDS};
+#[cfg(feature = "mmap")]
+pub use mmap::MmapMatrix;
+#[cfg(feature = "std")]
+pub use options::{ChunkStrategy, DEFAULT_SEQUENTIAL_THRESHOLD, MultiplyOptions};
+pub use permutation::Permutation;
+#[cfg(feature = "std")]
+pub use pool::{DEFAULT_QUEUE_CAPACITY, PoolStats, PoolWorkerStats, Priority, ThreadPool};
+#[cfg(feature = "std")]
+pub use preprocess::{MinMaxScaler, StandardScaler};
+#[cfg(feature = "std")]
+pub use scheduler::{LeastLoaded, RandomScheduler, RoundRobin, Scheduler};
+#[cfg(feature = "std")]
+pub use shared_matrix::SharedMatrix;
+pub use sparse::{CooMatrix, CsrMatrix, SparseVector};
+#[cfg(feature = "std")]
+pub use sparse::{spmm, spmv, spmv_sparse};
+pub use static_matrix::StaticMatrix;
+#[cfg(feature = "std")]
+pub use stats::{MultiplyStats, WorkerStats};
+#[cfg(feature = "mmap")]
+pub use streaming::FileSink;
+#[cfg(feature = "std")]
+pub use streaming::{BlockSink, BlockSource, multiply_streaming};
+#[cfg(feature = "std")]
+pub use sync::{CountdownLatch, RateLimiter, Semaphore, SemaphorePermit};
+#[cfg(feature = "std")]
+pub use task_group::{TaskGroup, TaskResult};
+#[cfg(feature = "std")]
+pub use trace::{MultiplyTrace, TraceEvent};
+#[cfg(feature = "std")]
+pub use vector::DEFAULT_PARALLEL_THRESHOLD;
 pub use vector::{Vector, dot_product};
+#[cfg(feature = "std")]
+pub use work_queue::{DeadLetter, WorkQueue};
@@ -0,0 +1,259 @@
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+use crate::permutation::Permutation;
+
+/// Jacobi 迭代的最大轮数，每轮依次消去所有非对角元
+const MAX_JACOBI_SWEEPS: usize = 100;
+
+/// 对称矩阵的特征分解结果
+///
+/// # 字段
+/// * `values`: 特征值，从大到小排列
+/// * `vectors`: 单位特征向量按列排列，第 `i` 列对应 `values[i]`，
+///   每个向量绝对值最大的分量为正，使结果与计算顺序无关
+#[derive(Debug, PartialEq)]
+pub struct SymmetricEigen {
+    pub values: Vec<f64>,
+    pub vectors: Matrix<f64>,
+}
+
+/// 用循环 Jacobi 方法计算实对称矩阵的全部特征值和特征向量
+///
+/// 只读取矩阵本身，不检查是否对称；非对称的输入结果没有意义。
+/// 面向协方差矩阵等特征数不多的小矩阵，在当前线程上计算
+///
+/// # 参数
+/// * `m`: 实对称矩阵
+///
+/// # 返回值
+/// 矩阵不是方阵时返回 `MatrixError::ShapeMismatch`
+pub fn symmetric_eigen(m: &Matrix<f64>) -> Result<SymmetricEigen, MatrixError> {
+    let n = m.row;
+    if m.col != n {
+        return Err(MatrixError::ShapeMismatch {
+            expected: (n, n),
+            actual: (m.row, m.col),
+        });
+    }
+    let mut a = m.data.clone();
+    let mut v = Matrix::<f64>::identity(n).data;
+    let scale = a.iter().map(|x| x * x).sum::<f64>();
+
+    for _ in 0..MAX_JACOBI_SWEEPS {
+        let off = (0..n)
+            .flat_map(|p| (0..n).filter(move |&q| q != p).map(move |q| (p, q)))
+            .map(|(p, q)| a[p * n + q] * a[p * n + q])
+            .sum::<f64>();
+        if off <= f64::EPSILON * f64::EPSILON * scale {
+            break;
+        }
+        for p in 0..n {
+            for q in p + 1..n {
+                let apq = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+                // 选择旋转角使 a[p][q] 变为零
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    let mut order = (0..n).collect::<Vec<_>>();
+    order.sort_by(|&i, &j| a[j * n + j].total_cmp(&a[i * n + i]));
+    let values = order.iter().map(|&i| a[i * n + i]).collect();
+    let mut vectors = vec![0.0; n * n];
+    for (dst, &src) in order.iter().enumerate() {
+        let column = (0..n).map(|k| v[k * n + src]);
+        let sign = column
+            .clone()
+            .fold(0.0f64, |max, x| if x.abs() > max.abs() { x } else { max })
+            .signum();
+        for (k, x) in column.enumerate() {
+            vectors[k * n + dst] = x * sign;
+        }
+    }
+    Ok(SymmetricEigen {
+        values,
+        vectors: Matrix {
+            data: vectors,
+            row: n,
+            col: n,
+        },
+    })
+}
+
+/// 带部分选主元的 LU 分解 `P·A = L·U`，由 `lu_decompose` 返回
+///
+/// `L` 的单位对角线不存储，与 `U` 合并保存在同一个矩阵中
+#[derive(Debug, PartialEq)]
+pub struct Lu {
+    lu: Matrix<f64>,
+    perm: Vec<usize>,
+}
+
+/// 对方阵做带部分选主元的 LU 分解
+///
+/// # 参数
+/// * `m`: 方阵
+///
+/// # 返回值
+/// 矩阵不是方阵时返回 `MatrixError::ShapeMismatch`，
+/// 主元相对矩阵最大元素小到数值上不可靠时返回 `MatrixError::Singular`
+pub fn lu_decompose(m: &Matrix<f64>) -> Result<Lu, MatrixError> {
+    let n = m.row;
+    if m.col != n {
+        return Err(MatrixError::ShapeMismatch {
+            expected: (n, n),
+            actual: (m.row, m.col),
+        });
+    }
+    let mut a = m.data.clone();
+    let mut perm = (0..n).collect::<Vec<_>>();
+    let max = a.iter().fold(0.0f64, |max, x| max.max(x.abs()));
+    let tolerance = max * n as f64 * f64::EPSILON;
+
+    for k in 0..n {
+        // 选当前列中绝对值最大的元素作为主元
+        let pivot = (k..n)
+            .max_by(|&i, &j| a[i * n + k].abs().total_cmp(&a[j * n + k].abs()))
+            .unwrap_or(k);
+        if a[pivot * n + k].abs() <= tolerance {
+            return Err(MatrixError::Singular);
+        }
+        if pivot != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot * n + j);
+            }
+            perm.swap(k, pivot);
+        }
+        for i in k + 1..n {
+            let factor = a[i * n + k] / a[k * n + k];
+            a[i * n + k] = factor;
+            for j in k + 1..n {
+                a[i * n + j] -= factor * a[k * n + j];
+            }
+        }
+    }
+    Ok(Lu {
+        lu: Matrix {
+            data: a,
+            row: n,
+            col: n,
+        },
+        perm,
+    })
+}
+
+impl Lu {
+    /// 选主元产生的行置换 `P`
+    pub fn permutation(&self) -> Permutation {
+        Permutation::new(self.perm.clone()).expect("pivoting produces a valid permutation")
+    }
+
+    /// 求解 `A·x = b`
+    ///
+    /// # 返回值
+    /// `b` 的长度与矩阵阶数不一致时返回 `MatrixError::LengthMismatch`
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let n = self.lu.row;
+        if b.len() != n {
+            return Err(MatrixError::LengthMismatch { a: n, b: b.len() });
+        }
+        let a = &self.lu.data;
+        // 前代求解 L·y = P·b
+        let mut x = self.perm.iter().map(|&i| b[i]).collect::<Vec<_>>();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= a[i * n + j] * x[j];
+            }
+        }
+        // 回代求解 U·x = y
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= a[i * n + j] * x[j];
+            }
+            x[i] /= a[i * n + i];
+        }
+        Ok(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_symmetric_eigen() -> Result<()> {
+        let m = Matrix::new([4.0, 1.0, 2.0, 1.0, 3.0, 0.0, 2.0, 0.0, 5.0], 3, 3);
+        let eigen = symmetric_eigen(&m)?;
+        assert!(eigen.values.windows(2).all(|w| w[0] >= w[1]));
+        assert!((eigen.values.iter().sum::<f64>() - 12.0).abs() < 1e-10);
+        // 逐个验证 A·v = λ·v
+        for (i, &lambda) in eigen.values.iter().enumerate() {
+            let v = (0..3)
+                .map(|k| eigen.vectors.data[k * 3 + i])
+                .collect::<Vec<_>>();
+            for r in 0..3 {
+                let av = (0..3).map(|k| m.data[r * 3 + k] * v[k]).sum::<f64>();
+                assert!((av - lambda * v[r]).abs() < 1e-10);
+            }
+        }
+
+        let diagonal = symmetric_eigen(&Matrix::new([1.0, 0.0, 0.0, 3.0], 2, 2))?;
+        assert_eq!(diagonal.values, [3.0, 1.0]);
+        assert_eq!(diagonal.vectors, Matrix::new([0.0, 1.0, 1.0, 0.0], 2, 2));
+
+        assert!(matches!(
+            symmetric_eigen(&Matrix::new([1.0, 2.0], 1, 2)),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_lu_solve() -> Result<()> {
+        // 第一列首元素为零，必须选主元
+        let m = Matrix::new([0.0, 2.0, 1.0, 1.0, 1.0, 1.0, 2.0, 1.0, 3.0], 3, 3);
+        let lu = lu_decompose(&m)?;
+        // 第一步把第三行换到最前
+        assert_eq!(lu.permutation().as_slice()[0], 2);
+        let b = Matrix::new([7.0, 6.0, 13.0], 3, 1);
+        assert_eq!(lu.permutation().apply_rows(&b)?.data[0], 13.0);
+        let x = lu.solve(&[7.0, 6.0, 13.0])?;
+        for (x, e) in x.iter().zip([1.0, 2.0, 3.0]) {
+            assert!((x - e).abs() < 1e-12);
+        }
+        assert!(matches!(
+            lu.solve(&[1.0]),
+            Err(MatrixError::LengthMismatch { a: 3, b: 1 })
+        ));
+
+        let singular = Matrix::new([1.0, 2.0, 2.0, 4.0], 2, 2);
+        assert_eq!(lu_decompose(&singular), Err(MatrixError::Singular));
+        assert!(matches!(
+            lu_decompose(&Matrix::new([1.0, 2.0], 1, 2)),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,5 @@
+mod queue;
+mod stack;
+
+pub use queue::MsQueue;
+pub use stack::TreiberStack;
@@ -0,0 +1,241 @@
+use crossbeam_epoch::{self as epoch, Atomic, Owned, Shared};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::sync::atomic::Ordering;
+
+/// Michael-Scott 无锁队列
+///
+/// 队头始终是一个不含值的哨兵节点，出队时把队头移到下一个节点并取走其中的值，
+/// 该节点成为新的哨兵。入队时若发现 tail 落后（其 next 非空），先帮忙把 tail 推进再重试。
+/// 出队的节点交给 epoch 回收器延迟释放
+pub struct MsQueue<T> {
+    head: Atomic<Node<T>>,
+    tail: Atomic<Node<T>>,
+}
+
+/// 队列节点，哨兵节点的 `value` 未初始化
+struct Node<T> {
+    value: MaybeUninit<T>,
+    next: Atomic<Node<T>>,
+}
+
+// SAFETY: 值只会被出队的那一个线程取走，因此 `T: Send` 即可在线程间共享
+unsafe impl<T: Send> Send for MsQueue<T> {}
+unsafe impl<T: Send> Sync for MsQueue<T> {}
+
+impl<T> MsQueue<T> {
+    /// 创建空队列
+    pub fn new() -> Self {
+        let queue = Self {
+            head: Atomic::null(),
+            tail: Atomic::null(),
+        };
+        let sentinel = Owned::new(Node {
+            value: MaybeUninit::uninit(),
+            next: Atomic::null(),
+        });
+        // SAFETY: 队列尚未共享给其他线程
+        let sentinel = sentinel.into_shared(unsafe { epoch::unprotected() });
+        queue.head.store(sentinel, Ordering::Relaxed);
+        queue.tail.store(sentinel, Ordering::Relaxed);
+        queue
+    }
+
+    /// 在队尾加入一个元素
+    pub fn push(&self, value: T) {
+        let guard = &epoch::pin();
+        let node = Owned::new(Node {
+            value: MaybeUninit::new(value),
+            next: Atomic::null(),
+        })
+        .into_shared(guard);
+        loop {
+            let tail = self.tail.load(Ordering::Acquire, guard);
+            // SAFETY: tail 永远不为空，且在 guard 存续期间不会被释放
+            let tail_node = unsafe { tail.deref() };
+            let next = tail_node.next.load(Ordering::Acquire, guard);
+            if !next.is_null() {
+                // tail 落后，帮其他线程推进后重试
+                let _ = self.tail.compare_exchange(
+                    tail,
+                    next,
+                    Ordering::Release,
+                    Ordering::Relaxed,
+                    guard,
+                );
+                continue;
+            }
+            if tail_node
+                .next
+                .compare_exchange(
+                    Shared::null(),
+                    node,
+                    Ordering::Release,
+                    Ordering::Relaxed,
+                    guard,
+                )
+                .is_ok()
+            {
+                // 推进失败说明已有其他线程代为推进
+                let _ = self.tail.compare_exchange(
+                    tail,
+                    node,
+                    Ordering::Release,
+                    Ordering::Relaxed,
+                    guard,
+                );
+                return;
+            }
+        }
+    }
+
+    /// 取出队头元素，队列为空时返回 `None`
+    pub fn pop(&self) -> Option<T> {
+        let guard = &epoch::pin();
+        loop {
+            let head = self.head.load(Ordering::Acquire, guard);
+            // SAFETY: head 永远不为空，且在 guard 存续期间不会被释放
+            let next = unsafe { head.deref() }.next.load(Ordering::Acquire, guard);
+            // SAFETY: 同上
+            let next_node = unsafe { next.as_ref() }?;
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::Release, Ordering::Relaxed, guard)
+                .is_ok()
+            {
+                // tail 不能落在即将释放的旧哨兵上
+                let tail = self.tail.load(Ordering::Relaxed, guard);
+                if tail == head {
+                    let _ = self.tail.compare_exchange(
+                        tail,
+                        next,
+                        Ordering::Release,
+                        Ordering::Relaxed,
+                        guard,
+                    );
+                }
+                // SAFETY: CAS 成功说明只有当前线程取得了 next 中的值，
+                // 它成为新的哨兵后值不会再被读取；旧哨兵延迟释放
+                unsafe {
+                    guard.defer_destroy(head);
+                    return Some(next_node.value.assume_init_read());
+                }
+            }
+        }
+    }
+
+    /// 队列是否为空，并发修改时结果只是一个瞬时值
+    pub fn is_empty(&self) -> bool {
+        let guard = &epoch::pin();
+        let head = self.head.load(Ordering::Acquire, guard);
+        // SAFETY: head 永远不为空，且在 guard 存续期间不会被释放
+        unsafe { head.deref() }
+            .next
+            .load(Ordering::Acquire, guard)
+            .is_null()
+    }
+}
+
+impl<T> Default for MsQueue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for MsQueue<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+        // SAFETY: 持有 &mut self，没有其他线程访问队列，剩下的哨兵可以立即释放
+        unsafe {
+            let guard = epoch::unprotected();
+            drop(self.head.load(Ordering::Relaxed, guard).into_owned());
+        }
+    }
+}
+
+impl<T> fmt::Debug for MsQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MsQueue").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_queue_fifo() {
+        let queue = MsQueue::new();
+        assert!(queue.is_empty());
+        for i in 0..3 {
+            queue.push(i);
+        }
+        assert_eq!([queue.pop(), queue.pop()], [Some(0), Some(1)]);
+        queue.push(10);
+        assert_eq!(
+            [queue.pop(), queue.pop(), queue.pop()],
+            [Some(2), Some(10), None]
+        );
+    }
+
+    #[test]
+    fn test_queue_stress() {
+        const PER_PRODUCER: usize = 2000;
+        let queue = Arc::new(MsQueue::new());
+        let producers: Vec<_> = (0..2)
+            .map(|t| {
+                let queue = queue.clone();
+                thread::spawn(move || {
+                    for i in 0..PER_PRODUCER {
+                        queue.push((t, i));
+                    }
+                })
+            })
+            .collect();
+        let consumers: Vec<_> = (0..2)
+            .map(|_| {
+                let queue = queue.clone();
+                thread::spawn(move || {
+                    let mut received = Vec::new();
+                    while received.len() < PER_PRODUCER {
+                        match queue.pop() {
+                            Some(v) => received.push(v),
+                            None => thread::yield_now(),
+                        }
+                    }
+                    received
+                })
+            })
+            .collect();
+        for producer in producers {
+            producer.join().unwrap();
+        }
+        let received: Vec<_> = consumers.into_iter().map(|c| c.join().unwrap()).collect();
+        // 每个消费者看到的同一生产者的元素保持入队顺序
+        for values in &received {
+            for t in 0..2 {
+                let seq: Vec<_> = values.iter().filter(|v| v.0 == t).map(|v| v.1).collect();
+                assert!(seq.windows(2).all(|w| w[0] < w[1]));
+            }
+        }
+        assert_eq!(
+            received.iter().map(Vec::len).sum::<usize>(),
+            2 * PER_PRODUCER
+        );
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_queue_drops_remaining_values() {
+        let value = Arc::new(());
+        let queue = MsQueue::new();
+        for _ in 0..10 {
+            queue.push(value.clone());
+        }
+        drop(queue.pop());
+        drop(queue);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,164 @@
+use crossbeam_epoch::{self as epoch, Atomic, Owned};
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::Ordering;
+
+/// Treiber 无锁栈
+///
+/// 入栈和出栈都只对栈顶指针做一次 CAS，失败时重试。
+/// 出栈的节点交给 epoch 回收器延迟释放，保证其他线程仍在读取的节点不会被提前释放
+pub struct TreiberStack<T> {
+    head: Atomic<Node<T>>,
+}
+
+struct Node<T> {
+    value: ManuallyDrop<T>,
+    next: Atomic<Node<T>>,
+}
+
+// SAFETY: 值只会被出栈的那一个线程取走，因此 `T: Send` 即可在线程间共享
+unsafe impl<T: Send> Send for TreiberStack<T> {}
+unsafe impl<T: Send> Sync for TreiberStack<T> {}
+
+impl<T> TreiberStack<T> {
+    /// 创建空栈
+    pub fn new() -> Self {
+        Self {
+            head: Atomic::null(),
+        }
+    }
+
+    /// 压入一个元素
+    pub fn push(&self, value: T) {
+        let mut node = Owned::new(Node {
+            value: ManuallyDrop::new(value),
+            next: Atomic::null(),
+        });
+        let guard = &epoch::pin();
+        loop {
+            let head = self.head.load(Ordering::Acquire, guard);
+            node.next.store(head, Ordering::Relaxed);
+            match self.head.compare_exchange(
+                head,
+                node,
+                Ordering::Release,
+                Ordering::Relaxed,
+                guard,
+            ) {
+                Ok(_) => return,
+                Err(e) => node = e.new,
+            }
+        }
+    }
+
+    /// 弹出栈顶元素，栈为空时返回 `None`
+    pub fn pop(&self) -> Option<T> {
+        let guard = &epoch::pin();
+        loop {
+            let head = self.head.load(Ordering::Acquire, guard);
+            // SAFETY: head 在 guard 存续期间不会被释放
+            let node = unsafe { head.as_ref() }?;
+            let next = node.next.load(Ordering::Relaxed, guard);
+            if self
+                .head
+                .compare_exchange(head, next, Ordering::Relaxed, Ordering::Relaxed, guard)
+                .is_ok()
+            {
+                // SAFETY: CAS 成功说明只有当前线程取得了该节点，值只会被读出一次，
+                // 节点延迟到所有可能读取它的线程离开 epoch 后再释放
+                unsafe {
+                    guard.defer_destroy(head);
+                    return Some(ManuallyDrop::into_inner(ptr::read(&node.value)));
+                }
+            }
+        }
+    }
+
+    /// 栈是否为空，并发修改时结果只是一个瞬时值
+    pub fn is_empty(&self) -> bool {
+        let guard = &epoch::pin();
+        self.head.load(Ordering::Acquire, guard).is_null()
+    }
+}
+
+impl<T> Default for TreiberStack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for TreiberStack<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+impl<T> fmt::Debug for TreiberStack<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreiberStack").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_stack_lifo() {
+        let stack = TreiberStack::new();
+        assert!(stack.is_empty());
+        for i in 0..3 {
+            stack.push(i);
+        }
+        assert_eq!([stack.pop(), stack.pop()], [Some(2), Some(1)]);
+        stack.push(10);
+        assert_eq!(
+            [stack.pop(), stack.pop(), stack.pop()],
+            [Some(10), Some(0), None]
+        );
+    }
+
+    #[test]
+    fn test_stack_stress() {
+        let stack = Arc::new(TreiberStack::new());
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                let stack = stack.clone();
+                thread::spawn(move || {
+                    let mut popped = Vec::new();
+                    for i in 0..2000 {
+                        stack.push(t * 2000 + i);
+                        if i % 2 == 0 {
+                            popped.extend(stack.pop());
+                        }
+                    }
+                    popped
+                })
+            })
+            .collect();
+        let mut values: Vec<_> = handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect();
+        while let Some(v) = stack.pop() {
+            values.push(v);
+        }
+        values.sort();
+        assert_eq!(values, (0..8000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_stack_drops_remaining_values() {
+        let value = Arc::new(());
+        let stack = TreiberStack::new();
+        for _ in 0..10 {
+            stack.push(value.clone());
+        }
+        drop(stack.pop());
+        drop(stack);
+        assert_eq!(Arc::strong_count(&value), 1);
+    }
+}
@@ -1,12 +1,47 @@
-use anyhow::{Result, anyhow};
-use std::fmt::Formatter;
-use std::ops::{Add, AddAssign, Mul};
-use std::sync::mpsc;
-use std::{fmt, thread};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Formatter;
+use core::ops::{AddAssign, Mul};
+use num_traits::{One, Zero};
 
-use crate::vector::{Vector, dot_product};
+use crate::error::MatrixError;
+use crate::vector::dot;
 
-const NUM_THREADS: usize = 4; // 线程数
+#[cfg(feature = "std")]
+mod arbitrary;
+#[cfg(feature = "std")]
+mod batch;
+mod block;
+#[cfg(feature = "std")]
+mod cells;
+mod display;
+mod edit;
+mod layout;
+#[cfg(feature = "std")]
+mod parallel;
+mod slice;
+mod structured;
+mod transform;
+mod transpose;
+
+#[cfg(feature = "std")]
+pub use arbitrary::{arbitrary_matrix, arbitrary_multiplicable};
+#[cfg(feature = "std")]
+pub use batch::{multiply_batch, multiply_batch_on};
+#[cfg(feature = "std")]
+pub use cells::{Cells, multiply_cells};
+pub use display::{DEFAULT_DISPLAY_EDGE_ITEMS, DEFAULT_DISPLAY_THRESHOLD, MatrixDisplay};
+#[cfg(feature = "std")]
+pub use layout::multiply_views;
+pub use layout::{Layout, MatrixView, multiply_views_sequential};
+#[cfg(feature = "std")]
+pub(crate) use parallel::{Kernel, NUM_THREADS, multiply_kernel_into};
+#[cfg(feature = "std")]
+pub use parallel::{
+    Msg, MsgInput, MsgOutput, multiply, multiply_into, multiply_into_with, multiply_traced,
+    multiply_with, multiply_with_cancel, multiply_with_timeout,
+};
 
 /// 矩阵结构体
 ///
@@ -19,9 +54,9 @@ const NUM_THREADS: usize = 4; // 线程数
 /// * `col`: 矩阵列数
 #[derive(PartialEq)]
 pub struct Matrix<T> {
-    data: Vec<T>,
-    row: usize,
-    col: usize,
+    pub(crate) data: Vec<T>,
+    pub(crate) row: usize,
+    pub(crate) col: usize,
 }
 
 impl<T: fmt::Debug> Matrix<T> {
@@ -43,13 +78,46 @@ impl<T: fmt::Debug> Matrix<T> {
     }
 }
 
+impl<T: Clone + Zero> Matrix<T> {
+    /// 创建全零矩阵
+    ///
+    /// # 参数
+    /// * `row`: 行数
+    /// * `col`: 列数
+    pub fn zeros(row: usize, col: usize) -> Self {
+        Self {
+            data: vec![T::zero(); row * col],
+            row,
+            col,
+        }
+    }
+}
+
+impl<T: Clone + Zero + One> Matrix<T> {
+    /// 创建 `n×n` 单位矩阵
+    ///
+    /// # 参数
+    /// * `n`: 行数和列数
+    pub fn identity(n: usize) -> Self {
+        let mut m = Self::zeros(n, n);
+        for i in 0..n {
+            m.data[i * n + i] = T::one();
+        }
+        m
+    }
+}
+
 impl<T> fmt::Display for Matrix<T>
 where
     T: fmt::Display,
 {
     // display a 2x3 as {1 2 3, 4 5 6},3x2 as {1 2, 3 4, 5 6}
     /// 实现格式化输出的 trait 方法
-    /// 该方法用于将矩阵以字符串的形式输出
+    /// 该方法用于将矩阵以字符串的形式输出，
+    /// 使用 `{:#}` 时每行单独输出一行，各列按最宽元素右对齐。
+    /// 宽度和精度（如 `{:8.3}`）作用于每个元素，
+    /// 元素个数超过 `DEFAULT_DISPLAY_THRESHOLD` 时只输出四角的元素，
+    /// 需要其他截断配置时使用 `Matrix::display`
     ///
     /// # 参数
     ///
@@ -59,30 +127,7 @@ where
     ///
     /// 返回 `fmt::Result`，表示格式化操作成功或失败
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        // 写入左花括号，开始格式化输出矩阵
-        write!(f, "{{")?;
-
-        // 遍历矩阵的行
-        for i in 0..self.row {
-            // 遍历矩阵的列
-            for j in 0..self.col {
-                // 格式化输出当前元素
-                write!(f, "{}", self.data[i * self.col + j])?;
-                // 如果当前元素不是当前行的最后一个元素，写入一个空格
-                if j != self.col - 1 {
-                    write!(f, " ")?;
-                }
-            }
-            // 如果当前行不是矩阵的最后一行，写入一个逗号和一个空格
-            if i != self.row - 1 {
-                write!(f, ", ")?;
-            }
-        }
-        // 写入右花括号，完成矩阵的格式化输出
-        write!(f, "}}")?;
-
-        // 返回 Ok 表示格式化操作成功
-        Ok(())
+        fmt::Display::fmt(&self.display(), f)
     }
 }
 
@@ -98,89 +143,35 @@ where
     }
 }
 
-/// 并发矩阵乘法运算
+/// 在当前线程上串行计算矩阵乘法
 ///
-/// # 类型参数
-/// * `T`: 元素类型，需满足多个trait约束
+/// 不依赖线程池，也不需要 `std`，在 `no_std` 环境下同样可用
 ///
 /// # 参数
 /// * `a`: 左操作数矩阵
 /// * `b`: 右操作数矩阵
 ///
 /// # 返回值
-/// 返回Result<Matrix<T>>，包含乘积结果或错误信息
-///
-/// # 并发策略
-/// 使用固定大小线程池（NUM_THREADS）进行并行计算
-pub fn multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>>
+/// 返回Result<Matrix<T>, MatrixError>，维度不匹配时返回 `MatrixError::DimensionMismatch`
+pub fn multiply_sequential<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
 where
-    T: fmt::Debug + Default + Copy + Add<Output = T> + AddAssign + Mul<Output = T> + Send + 'static,
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
 {
-    // 检查矩阵维度是否匹配
     if a.col != b.row {
-        return Err(anyhow!("Matrix multiply error: a.col != b.row"));
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
     }
 
-    // 创建线程池和通信通道
-    let senders = (0..NUM_THREADS)
-        .map(|_| {
-            let (tx, rx) = mpsc::channel::<Msg<T>>();
-            thread::spawn(move || {
-                // 线程工作循环：接收消息并计算点积
-                for msg in rx {
-                    let value = dot_product(msg.input.row, msg.input.col)?;
-                    // 通过一次性通道返回计算结果
-                    if let Err(e) = msg.sender.send(MsgOutput {
-                        idx: msg.input.idx,
-                        value,
-                    }) {
-                        eprintln!("Send error: {:?}", e);
-                    }
-                }
-                Ok::<_, anyhow::Error>(())
-            });
-            tx
-        })
-        .collect::<Vec<_>>();
-
-    // 初始化结果矩阵数据
-    let matrix_len = a.row * b.col;
-    let mut data = vec![T::default(); matrix_len];
-    let mut receivers = Vec::with_capacity(matrix_len);
-
-    // 分发计算任务
+    let bt = columns(b);
+    let mut data = Vec::with_capacity(a.row * b.col);
     for i in 0..a.row {
+        let row = &a.data[i * a.col..(i + 1) * a.col];
         for j in 0..b.col {
-            // 提取当前行和列的数据
-            let row = Vector::new(&a.data[i * a.col..(i + 1) * a.col]);
-            let col_data = b.data[j..]
-                .iter()
-                .step_by(b.col)
-                .copied()
-                .collect::<Vec<_>>();
-            let col = Vector::new(col_data);
-
-            // 创建任务索引和通信通道
-            let idx = i * b.col + j;
-            let input = MsgInput::new(idx, row, col);
-            let (tx, rx) = oneshot::channel();
-            let msg = Msg::new(input, tx);
-
-            // 轮询分配任务到线程池
-            if let Err(e) = senders[idx % NUM_THREADS].send(msg) {
-                eprintln!("Send error: {:?}", e)
-            }
-            receivers.push(rx)
+            data.push(dot(row, &bt[j * b.row..(j + 1) * b.row]));
         }
     }
-
-    // 收集计算结果
-    for rx in receivers {
-        let msg = rx.recv()?;
-        data[msg.idx] = msg.value;
-    }
-
-    // 返回最终计算结果
     Ok(Matrix {
         data,
         row: a.row,
@@ -188,110 +179,124 @@ where
     })
 }
 
-/// 消息输入结构体
-/// 用于封装单个点积计算任务的参数
+/// 朴素三重循环矩阵乘法，作为验证其它实现的参考
 ///
-/// # 字段
-/// * `idx`: 结果矩阵中的位置索引
-/// * `row`: 当前行向量
-/// * `col`: 当前列向量
-pub struct MsgInput<T> {
-    idx: usize,
-    row: Vector<T>,
-    col: Vector<T>,
-}
-
-/// 消息输出结构体
-/// 用于封装单个点积计算结果
+/// 按定义 `c[i][j] = Σ a[i][k] * b[k][j]` 逐个累加，不转置、不分块、不使用线程，
+/// 适合在测试中与并发实现比对结果，或作为测量并行加速比的基准
 ///
-/// # 字段
-/// * `idx`: 结果矩阵中的位置索引
-/// * `value`: 计算结果值
-pub struct MsgOutput<T> {
-    idx: usize,
-    value: T,
-}
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，维度不匹配时返回 `MatrixError::DimensionMismatch`
+pub fn multiply_seq<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
 
-impl<T> MsgInput<T> {
-    /// 创建消息输入实例
-    ///
-    /// # 参数
-    /// * `idx`: 结果矩阵中的位置索引
-    /// * `row`: 当前行向量
-    /// * `col`: 当前列向量
-    ///
-    /// # 返回值
-    /// 返回MsgInput<T>实例
-    pub fn new(idx: usize, row: Vector<T>, col: Vector<T>) -> Self {
-        Self { idx, row, col }
+    let mut data = Vec::with_capacity(a.row * b.col);
+    for i in 0..a.row {
+        for j in 0..b.col {
+            let mut sum = T::zero();
+            for k in 0..a.col {
+                sum += a.data[i * a.col + k].clone() * b.data[k * b.col + j].clone();
+            }
+            data.push(sum);
+        }
     }
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: b.col,
+    })
 }
 
-pub struct Msg<T> {
-    input: MsgInput<T>,
-    sender: oneshot::Sender<MsgOutput<T>>, // 一次性channel
-}
-impl<T> Msg<T> {
-    /// 创建消息实例
-    ///
-    /// # 参数
-    /// * `input`: 计算任务参数
-    /// * `sender`: 一次性发送通道
-    ///
-    /// # 返回值
-    /// 返回Msg<T>实例
-    pub fn new(input: MsgInput<T>, sender: oneshot::Sender<MsgOutput<T>>) -> Self {
-        Self { input, sender }
+/// 按列优先顺序复制矩阵数据（即转置后的行优先数据），使每一列在内存中连续
+pub(crate) fn columns<T: Clone>(m: &Matrix<T>) -> Vec<T> {
+    let mut data = Vec::with_capacity(m.data.len());
+    for j in 0..m.col {
+        data.extend(m.data.iter().skip(j).step_by(m.col).cloned());
     }
+    data
 }
 
-impl<T> Mul for Matrix<T>
-where
-    T: fmt::Debug + Default + Copy + Add<Output = T> + AddAssign + Mul<Output = T> + Send + 'static,
-{
-    type Output = Self;
+/// 可失败的乘法运算
+///
+/// 与 `core::ops::Mul` 对应，但在操作数不兼容时返回错误而不是 panic
+pub trait TryMul<Rhs = Self> {
+    type Output;
+    type Error;
 
-    fn mul(self, rhs: Self) -> Self::Output {
-        multiply(&self, &rhs).unwrap_or_else(|e| panic!("Matrix multiply error: {}", e))
-    }
+    fn try_mul(self, rhs: Rhs) -> Result<Self::Output, Self::Error>;
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::format;
 
     #[test]
-    fn test_matrix_multiply() -> Result<()> {
-        let a = Matrix::new(vec![1, 2, 3, 4, 5, 6], 2, 3);
-        let b = Matrix::new(vec![7, 8, 9, 10, 11, 12], 3, 2);
-        let c = multiply(&a, &b)?;
-        assert_eq!(c, Matrix::new(vec![58, 64, 139, 154], 2, 2));
-        Ok(())
-    }
-
-    #[test]
-    fn test_matrix_display() -> Result<()> {
-        let a = Matrix::new(vec![1, 2, 3, 4, 5, 6], 2, 3);
-        let b = Matrix::new(vec![7, 8, 9, 10, 11, 12], 3, 2);
-        let c = multiply(&a, &b)?;
+    fn test_multiply_sequential() {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let Ok(c) = multiply_sequential(&a, &b) else {
+            panic!("multiply should succeed");
+        };
         assert_eq!(c.data, vec![58, 64, 139, 154]);
         assert_eq!(format!("{}", c), "{58 64, 139 154}");
-        Ok(())
+
+        let d = Matrix::new([1, 2, 3, 4], 2, 2);
+        assert!(multiply_sequential(&a, &d).is_err());
     }
 
     #[test]
-    fn test_a_can_not_multiply_b() {
+    fn test_multiply_seq() {
         let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
-        let b = Matrix::new([1, 2, 3, 4], 2, 2);
-        let c = multiply(&a, &b);
-        assert!(c.is_err());
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        assert_eq!(multiply_seq(&a, &b), multiply_sequential(&a, &b));
+        assert_eq!(
+            multiply_seq(&a, &a),
+            Err(MatrixError::DimensionMismatch {
+                a: (2, 3),
+                b: (2, 3)
+            })
+        );
+        let empty = Matrix::<i32>::zeros(2, 0);
+        assert_eq!(
+            multiply_seq(&empty, &Matrix::zeros(0, 3)),
+            Ok(Matrix::zeros(2, 3))
+        );
     }
 
     #[test]
-    #[should_panic]
-    fn test_a_can_not_multiply_b_panic() {
+    fn test_identity() {
         let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
-        let b = Matrix::new([1, 2, 3, 4], 2, 2);
-        let _c = a * b;
+        let Ok(c) = multiply_sequential(&Matrix::identity(2), &a) else {
+            panic!("multiply should succeed");
+        };
+        assert_eq!(c, a);
+        assert_eq!(Matrix::<f64>::zeros(1, 2), Matrix::new([0.0, 0.0], 1, 2));
+    }
+
+    #[test]
+    fn test_matrix_display_aligned() {
+        let m = Matrix::new([1, -2, 3, 40, 5, 600], 2, 3);
+        assert_eq!(format!("{:#}", m), "[ 1 -2   3]\n[40  5 600]");
+        assert_eq!(format!("{:#}", Matrix::<i32>::zeros(0, 3)), "");
+    }
+
+    #[test]
+    fn test_matrix_display_precision() {
+        let m = Matrix::new([1.0, 2.5, 1.0 / 3.0, -40.125], 2, 2);
+        assert_eq!(format!("{:.2}", m), "{1.00 2.50, 0.33 -40.12}");
+        assert_eq!(format!("{:6.1}", m), "{   1.0    2.5,    0.3  -40.1}");
+        assert_eq!(format!("{:#.1}", m), "[1.0   2.5]\n[0.3 -40.1]");
     }
 }
@@ -0,0 +1,130 @@
+use rand::distr::Distribution;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use super::Matrix;
+
+/// 生成随机形状的矩阵，行数和列数均匀取自 `0..=max_dim`，元素取自 `dist`
+///
+/// 包含空矩阵和单行单列等边界形状，用于对组合了本库运算的代码做属性测试
+///
+/// # 参数
+/// * `rng`: 随机数生成器，使用固定种子的生成器可以复现失败的用例
+/// * `max_dim`: 行数和列数的上限
+/// * `dist`: 元素分布
+pub fn arbitrary_matrix<T, R>(rng: &mut R, max_dim: usize, dist: &impl Distribution<T>) -> Matrix<T>
+where
+    R: Rng + ?Sized,
+{
+    let row = rng.random_range(0..=max_dim);
+    let col = rng.random_range(0..=max_dim);
+    random_matrix(rng, row, col, dist)
+}
+
+/// 生成一对可以相乘的随机矩阵 `m×k` 和 `k×n`，三个维度均匀取自 `0..=max_dim`
+///
+/// # 参数
+/// * `rng`: 随机数生成器
+/// * `max_dim`: 各维度的上限
+/// * `dist`: 元素分布
+pub fn arbitrary_multiplicable<T, R>(
+    rng: &mut R,
+    max_dim: usize,
+    dist: &impl Distribution<T>,
+) -> (Matrix<T>, Matrix<T>)
+where
+    R: Rng + ?Sized,
+{
+    let m = rng.random_range(0..=max_dim);
+    let k = rng.random_range(0..=max_dim);
+    let n = rng.random_range(0..=max_dim);
+    (
+        random_matrix(rng, m, k, dist),
+        random_matrix(rng, k, n, dist),
+    )
+}
+
+impl<T> Matrix<T> {
+    /// 创建 `row×col` 的随机矩阵，元素依次取自 `dist`
+    ///
+    /// # 参数
+    /// * `row`: 行数
+    /// * `col`: 列数
+    /// * `dist`: 元素分布
+    /// * `rng`: 随机数生成器，由调用方决定是否使用固定种子
+    pub fn random<R: Rng + ?Sized>(
+        row: usize,
+        col: usize,
+        dist: &impl Distribution<T>,
+        rng: &mut R,
+    ) -> Self {
+        random_matrix(rng, row, col, dist)
+    }
+
+    /// 使用种子为 `seed` 的 `StdRng` 创建随机矩阵，相同参数在不同机器和运行之间得到相同的矩阵
+    pub fn random_seeded(row: usize, col: usize, dist: &impl Distribution<T>, seed: u64) -> Self {
+        random_matrix(&mut StdRng::seed_from_u64(seed), row, col, dist)
+    }
+}
+
+fn random_matrix<T, R>(
+    rng: &mut R,
+    row: usize,
+    col: usize,
+    dist: &impl Distribution<T>,
+) -> Matrix<T>
+where
+    R: Rng + ?Sized,
+{
+    Matrix {
+        data: dist.sample_iter(rng).take(row * col).collect(),
+        row,
+        col,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{multiply, multiply_seq};
+    use rand::distr::Uniform;
+
+    #[test]
+    fn test_arbitrary_shapes() -> anyhow::Result<()> {
+        let mut rng = StdRng::seed_from_u64(7);
+        let dist = Uniform::new_inclusive(-9i64, 9)?;
+        for _ in 0..50 {
+            let m = arbitrary_matrix(&mut rng, 5, &dist);
+            assert!(m.row <= 5 && m.col <= 5);
+            assert_eq!(m.data.len(), m.row * m.col);
+            assert!(m.data.iter().all(|v| (-9..=9).contains(v)));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_random_seeded() -> anyhow::Result<()> {
+        let dist = Uniform::new(0.0, 1.0)?;
+        let a = Matrix::<f64>::random_seeded(3, 4, &dist, 11);
+        assert_eq!(a, Matrix::random_seeded(3, 4, &dist, 11));
+        assert_ne!(a, Matrix::random_seeded(3, 4, &dist, 12));
+        assert_eq!(
+            a,
+            Matrix::random(3, 4, &dist, &mut StdRng::seed_from_u64(11))
+        );
+        assert!(a.data.iter().all(|v| (0.0..1.0).contains(v)));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_matches_reference_property() -> anyhow::Result<()> {
+        let mut rng = StdRng::seed_from_u64(42);
+        let dist = Uniform::new_inclusive(-9i64, 9)?;
+        for _ in 0..30 {
+            let (a, b) = arbitrary_multiplicable(&mut rng, 12, &dist);
+            assert_eq!(a.col, b.row);
+            assert_eq!(multiply(&a, &b)?, multiply_seq(&a, &b)?);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use num_traits::Zero;
+use std::ops::{AddAssign, Mul};
+use std::sync::Arc;
+
+use super::{Matrix, NUM_THREADS, columns};
+use crate::error::MatrixError;
+use crate::par::run_tasks;
+use crate::pool::ThreadPool;
+use crate::vector::dot;
+
+/// 批量矩阵乘法，计算 `a[i] × b[i]`
+///
+/// 所有批次在同一个私有线程池上一次性调度：批次数不少于线程数时每个批次一个任务，
+/// 否则把每个批次的行切分为多个任务，使许多小矩阵和少数大矩阵都能用满线程
+///
+/// # 参数
+/// * `a`: 左操作数序列
+/// * `b`: 右操作数序列，长度必须与 `a` 相同
+///
+/// # 返回值
+/// 返回Result<Vec<Matrix<T>>, MatrixError>，序列长度不同时返回 `MatrixError::LengthMismatch`，
+/// 任一批次维度不匹配时返回 `MatrixError::DimensionMismatch`，
+/// 计算中的 panic 以 `MatrixError::WorkerFailed` 返回，其 `idx` 为批次序号
+pub fn multiply_batch<T>(a: &[Matrix<T>], b: &[Matrix<T>]) -> Result<Vec<Matrix<T>>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_batch_on(a, b, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算批量矩阵乘法
+///
+/// # 参数
+/// * `a`: 左操作数序列
+/// * `b`: 右操作数序列，长度必须与 `a` 相同
+/// * `pool`: 执行计算的线程池
+pub fn multiply_batch_on<T>(
+    a: &[Matrix<T>],
+    b: &[Matrix<T>],
+    pool: &ThreadPool,
+) -> Result<Vec<Matrix<T>>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.len() != b.len() {
+        return Err(MatrixError::LengthMismatch {
+            a: a.len(),
+            b: b.len(),
+        });
+    }
+    if let Some((a, b)) = a.iter().zip(b).find(|(a, b)| a.col != b.row) {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+
+    // 每个批次切分的任务数，批次较少时按行切分以用满线程
+    let splits = pool.size().div_ceil(a.len().max(1));
+    let mut tasks = Vec::new();
+    let mut counts = Vec::with_capacity(a.len());
+    for (idx, (a, b)) in a.iter().zip(b).enumerate() {
+        // 右操作数的列只复制一次，同一批次的任务共享
+        let bt = Arc::new(columns(b));
+        let rows_per_task = a.row.div_ceil(splits).max(1);
+        let before = tasks.len();
+        for start in (0..a.row).step_by(rows_per_task) {
+            let end = (start + rows_per_task).min(a.row);
+            let (depth, width) = (a.col, b.col);
+            // 每个任务只复制自己负责的行
+            let rows = a.data[start * depth..end * depth].to_vec();
+            let bt = bt.clone();
+            tasks.push((idx, move || {
+                let mut out = Vec::with_capacity((end - start) * width);
+                for i in 0..end - start {
+                    let row = &rows[i * depth..(i + 1) * depth];
+                    for col in 0..width {
+                        out.push(dot(row, &bt[col * depth..(col + 1) * depth]));
+                    }
+                }
+                out
+            }));
+        }
+        counts.push(tasks.len() - before);
+    }
+    let mut blocks = run_tasks(pool, tasks)?.into_iter();
+
+    // 任务按批次和行的顺序提交，依次拼接回每个批次的结果
+    Ok(a.iter()
+        .zip(b)
+        .zip(counts)
+        .map(|((a, b), count)| {
+            let data = blocks.by_ref().take(count).flatten().collect();
+            Matrix {
+                data,
+                row: a.row,
+                col: b.col,
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WorkerErrorKind;
+    use crate::matrix::multiply_sequential;
+    use anyhow::Result;
+
+    #[test]
+    fn test_multiply_batch() -> Result<()> {
+        // 多个小矩阵与少量批次时按行切分两种情况
+        for count in [1, 2, 9] {
+            let a = (0..count)
+                .map(|i| Matrix::new((0..15).map(|x| x + i).collect::<Vec<i64>>(), 5, 3))
+                .collect::<Vec<_>>();
+            let b = (0..count)
+                .map(|i| Matrix::new((0..12).map(|x| x * i).collect::<Vec<i64>>(), 3, 4))
+                .collect::<Vec<_>>();
+            let c = multiply_batch(&a, &b)?;
+            assert_eq!(c.len(), count as usize);
+            for ((a, b), c) in a.iter().zip(&b).zip(&c) {
+                assert_eq!(c, &multiply_sequential(a, b)?);
+            }
+        }
+
+        // 零行、零深度的批次
+        let a = [Matrix::<i32>::new([], 0, 2), Matrix::new([], 2, 0)];
+        let b = [Matrix::new([1, 2], 2, 1), Matrix::new([], 0, 3)];
+        let c = multiply_batch(&a, &b)?;
+        assert_eq!(c[0], Matrix::new([], 0, 1));
+        assert_eq!(c[1], Matrix::new([0; 6], 2, 3));
+        assert!(multiply_batch::<i32>(&[], &[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_batch_errors() {
+        let a = [Matrix::new([1, 2], 1, 2)];
+        let b = [Matrix::new([1, 2], 2, 1), Matrix::new([1, 2], 2, 1)];
+        assert!(matches!(
+            multiply_batch(&a, &b),
+            Err(MatrixError::LengthMismatch { a: 1, b: 2 })
+        ));
+        assert!(matches!(
+            multiply_batch(&a, &a),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+
+        // 只有调试构建中整数溢出才会 panic
+        if !cfg!(debug_assertions) {
+            return;
+        }
+        let pool = ThreadPool::new(2);
+        let a = [Matrix::new([1u8, 2], 1, 2), Matrix::new([200u8, 2], 1, 2)];
+        let b = [Matrix::new([1u8, 1], 2, 1), Matrix::new([2u8, 1], 2, 1)];
+        let Err(MatrixError::WorkerFailed(err)) = multiply_batch_on(&a, &b, &pool) else {
+            panic!("overflow should fail the batch");
+        };
+        assert_eq!(err.idx, 1);
+        assert!(matches!(err.kind, WorkerErrorKind::Panicked(_)));
+    }
+}
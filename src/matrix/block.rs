@@ -0,0 +1,88 @@
+use alloc::vec::Vec;
+
+use super::Matrix;
+use crate::error::MatrixError;
+
+impl<T: Clone> Matrix<T> {
+    /// 由 `M × N` 个分块拼接成一个矩阵
+    ///
+    /// 同一行的分块行数必须相同，同一列的分块列数必须相同，
+    /// 即第 `i` 行的分块都与 `blocks[i][0]` 同高，第 `j` 列的分块都与 `blocks[0][j]` 同宽
+    ///
+    /// # 参数
+    /// * `blocks`: 按行排列的分块
+    ///
+    /// # 返回值
+    /// 返回Result<Matrix<T>, MatrixError>，分块形状不兼容时返回 `MatrixError::ShapeMismatch`，
+    /// 其中 `expected` 为该位置应有的形状
+    pub fn from_blocks<const M: usize, const N: usize>(
+        blocks: &[[&Matrix<T>; N]; M],
+    ) -> Result<Matrix<T>, MatrixError> {
+        if M == 0 || N == 0 {
+            return Ok(Matrix {
+                data: Vec::new(),
+                row: 0,
+                col: 0,
+            });
+        }
+        let heights = blocks.map(|blocks| blocks[0].row);
+        let widths = blocks[0].map(|block| block.col);
+        for (blocks, &height) in blocks.iter().zip(&heights) {
+            for (block, &width) in blocks.iter().zip(&widths) {
+                if block.row != height || block.col != width {
+                    return Err(MatrixError::ShapeMismatch {
+                        expected: (height, width),
+                        actual: (block.row, block.col),
+                    });
+                }
+            }
+        }
+
+        let row = heights.iter().sum();
+        let col = widths.iter().sum();
+        let mut data = Vec::with_capacity(row * col);
+        for (blocks, &height) in blocks.iter().zip(&heights) {
+            for i in 0..height {
+                for block in blocks {
+                    data.extend_from_slice(&block.data[i * block.col..(i + 1) * block.col]);
+                }
+            }
+        }
+        Ok(Matrix { data, row, col })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_from_blocks() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4], 2, 2);
+        let b = Matrix::new([5, 6], 2, 1);
+        let c = Matrix::new([7, 8], 1, 2);
+        let d = Matrix::new([9], 1, 1);
+        assert_eq!(
+            Matrix::from_blocks(&[[&a, &b], [&c, &d]])?,
+            Matrix::new([1, 2, 5, 3, 4, 6, 7, 8, 9], 3, 3)
+        );
+
+        // 增广矩阵 [A | b]
+        assert_eq!(
+            Matrix::from_blocks(&[[&a, &b]])?,
+            Matrix::new([1, 2, 5, 3, 4, 6], 2, 3)
+        );
+
+        assert_eq!(
+            Matrix::from_blocks(&[[&a, &b], [&d, &c]]),
+            Err(MatrixError::ShapeMismatch {
+                expected: (1, 2),
+                actual: (1, 1)
+            })
+        );
+        let empty: [[&Matrix<i32>; 0]; 2] = [[], []];
+        assert_eq!(Matrix::from_blocks(&empty)?, Matrix::new([], 0, 0));
+        Ok(())
+    }
+}
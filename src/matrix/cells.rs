@@ -0,0 +1,246 @@
+use num_traits::Zero;
+use std::fmt;
+use std::ops::{AddAssign, Mul};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use super::{Matrix, NUM_THREADS, columns};
+use crate::channel::{self, Receiver, Sender};
+use crate::error::{MatrixError, WorkerError};
+use crate::pool::{DEFAULT_QUEUE_CAPACITY, ThreadPool};
+use crate::vector::dot;
+
+/// 结果通道的容量，也是在途单元数的上限
+const RESULT_CAPACITY: usize = DEFAULT_QUEUE_CAPACITY;
+
+/// 逐个产出已完成结果单元的迭代器，由 `multiply_cells` 创建
+///
+/// 每一项为 `(idx, value)`，`idx` 为单元在结果矩阵中的行主序索引，
+/// 产出顺序取决于各工作线程完成的先后，不保证有序。
+/// 行任务在 `next` 中按需分发，在途单元数不超过结果通道的容量，
+/// 因此分发和工作线程发送都不会因调用方尚未消费而永久阻塞。
+/// 提前 drop 迭代器会中止剩余计算
+pub struct Cells<T> {
+    // 必须在 pool 之前声明：先 drop 接收端，使阻塞在发送上的工作线程立即返回，
+    // 随后 drop 线程池时才能顺利 join 所有工作线程
+    rx: Receiver<Result<(usize, T), MatrixError>>,
+    // 全部行分发完毕后置为 None，使工作线程全部退出时 `recv` 能返回断开错误
+    tx: Option<Sender<Result<(usize, T), MatrixError>>>,
+    a_data: Arc<Vec<T>>,
+    bt: Arc<Vec<T>>,
+    depth: usize,
+    width: usize,
+    rows: usize,
+    next_row: usize,
+    in_flight: usize,
+    remaining: usize,
+    pool: ThreadPool,
+}
+
+impl<T> Cells<T> {
+    /// 尚未产出的结果单元数
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<T> Cells<T>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    /// 在在途单元数不超过通道容量的前提下分发尽可能多的行，至少保证有一行在途
+    fn dispatch(&mut self) -> Result<(), MatrixError> {
+        while self.next_row < self.rows
+            && (self.in_flight == 0 || self.in_flight + self.width <= RESULT_CAPACITY)
+        {
+            let Some(tx) = self.tx.clone() else {
+                break;
+            };
+            let i = self.next_row;
+            let (a_data, bt) = (self.a_data.clone(), self.bt.clone());
+            let (depth, width) = (self.depth, self.width);
+            let job = move || {
+                let row = &a_data[i * depth..(i + 1) * depth];
+                for j in 0..width {
+                    let idx = i * width + j;
+                    let cell = panic::catch_unwind(AssertUnwindSafe(|| {
+                        dot(row, &bt[j * depth..(j + 1) * depth])
+                    }))
+                    .map(|value| (idx, value))
+                    .map_err(|payload| WorkerError::panicked(idx, payload).into());
+                    let failed = cell.is_err();
+                    // 迭代器已被 drop 或任务失败时放弃这一行剩余的单元
+                    if tx.send(cell).is_err() || failed {
+                        return;
+                    }
+                }
+            };
+            if self.pool.execute_on(i % self.pool.size(), job).is_err() {
+                return Err(WorkerError::disconnected(i * self.width).into());
+            }
+            self.next_row += 1;
+            self.in_flight += self.width;
+        }
+        if self.next_row == self.rows {
+            self.tx = None;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Iterator for Cells<T>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    type Item = Result<(usize, T), MatrixError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let item = match self.dispatch() {
+            Ok(()) => match self.rx.recv() {
+                Ok(item) => item,
+                // 所有工作线程都已退出但单元数不够，说明有任务没能完成
+                Err(_) => Err(WorkerError::disconnected(0).into()),
+            },
+            Err(e) => Err(e),
+        };
+        // 出错后停止产出
+        match item {
+            Ok(_) => {
+                self.remaining -= 1;
+                self.in_flight -= 1;
+            }
+            Err(_) => self.remaining = 0,
+        }
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.remaining))
+    }
+}
+
+impl<T> fmt::Debug for Cells<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Cells")
+            .field("remaining", &self.remaining)
+            .finish_non_exhaustive()
+    }
+}
+
+/// 并发计算矩阵乘积，每算完一个结果单元就立即交给调用方
+///
+/// 结果按行分配给私有线程池，工作线程通过有界通道逐个发送单元；
+/// 行任务随调用方消费逐步分发，调用方消费不及时时计算会暂停，
+/// 适合增量渲染或消费超大的结果
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 维度不匹配时返回 `MatrixError::DimensionMismatch`；
+/// 计算中的 panic 以迭代器中的 `MatrixError::WorkerFailed` 项返回，之后迭代结束
+pub fn multiply_cells<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Cells<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+
+    let (tx, rx) = channel::bounded(RESULT_CAPACITY);
+    Ok(Cells {
+        rx,
+        tx: Some(tx),
+        a_data: Arc::new(a.data.clone()),
+        bt: Arc::new(columns(b)),
+        depth: a.col,
+        width: b.col,
+        rows: a.row,
+        next_row: 0,
+        in_flight: 0,
+        remaining: a.row * b.col,
+        pool: ThreadPool::new(NUM_THREADS),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+
+    #[test]
+    fn test_multiply_cells() -> anyhow::Result<()> {
+        let a = Matrix::new((0..35).collect::<Vec<i64>>(), 7, 5);
+        let b = Matrix::new((0..30).collect::<Vec<i64>>(), 5, 6);
+        let expected = multiply_sequential(&a, &b)?;
+
+        let cells = multiply_cells(&a, &b)?;
+        assert_eq!(cells.remaining(), 42);
+        let mut data = vec![None; 42];
+        for cell in cells {
+            let (idx, value) = cell?;
+            assert!(data[idx].replace(value).is_none());
+        }
+        assert_eq!(
+            data.into_iter().collect::<Option<Vec<_>>>(),
+            Some(expected.data)
+        );
+
+        assert_eq!(
+            multiply_cells(&Matrix::<i64>::zeros(0, 3), &Matrix::zeros(3, 2))?.count(),
+            0
+        );
+        assert!(matches!(
+            multiply_cells(&a, &a),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_cells_larger_than_channel() -> anyhow::Result<()> {
+        // 行数和单元数都远超通道与线程池队列的容量，创建迭代器时不能阻塞
+        let rows = 6 * DEFAULT_QUEUE_CAPACITY;
+        let a = Matrix::new((0..rows as u64).collect::<Vec<_>>(), rows, 1);
+        let b = Matrix::new([2u64], 1, 1);
+        let mut sum = 0;
+        let mut count = 0;
+        for cell in multiply_cells(&a, &b)? {
+            sum += cell?.1;
+            count += 1;
+        }
+        assert_eq!(count, rows);
+        assert_eq!(sum, (rows as u64 - 1) * rows as u64);
+
+        // 单行宽度超过通道容量时每次只分发一行
+        let wide = Matrix::new(
+            vec![1u64; 2 * DEFAULT_QUEUE_CAPACITY],
+            1,
+            2 * DEFAULT_QUEUE_CAPACITY,
+        );
+        let a = Matrix::new([1u64, 2, 3], 3, 1);
+        assert_eq!(
+            multiply_cells(&a, &wide)?.count(),
+            6 * DEFAULT_QUEUE_CAPACITY
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_cells_dropped_early() -> anyhow::Result<()> {
+        // 结果远大于通道容量，提前 drop 时工作线程必须能退出
+        let a = Matrix::new(vec![1u64; 200 * 4], 200, 4);
+        let b = Matrix::new(vec![1u64; 4 * 200], 4, 200);
+        let mut cells = multiply_cells(&a, &b)?;
+        assert!(matches!(cells.next(), Some(Ok((_, 4)))));
+        drop(cells);
+        Ok(())
+    }
+}
@@ -0,0 +1,216 @@
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::fmt::Formatter;
+
+use super::Matrix;
+
+/// 默认的截断阈值：元素个数超过该值时只输出四角的元素
+pub const DEFAULT_DISPLAY_THRESHOLD: usize = 1000;
+
+/// 默认截断时每个方向保留的行数和列数
+pub const DEFAULT_DISPLAY_EDGE_ITEMS: usize = 3;
+
+/// 矩阵的可配置格式化输出
+///
+/// 由 `Matrix::display` 创建，元素个数超过 `threshold` 时，
+/// 只输出开头和末尾各 `edge_items` 行、列，中间以 `…` 代替
+///
+/// # 字段
+/// * `matrix`: 要输出的矩阵
+/// * `threshold`: 截断阈值
+/// * `edge_items`: 截断时每个方向保留的行数和列数
+pub struct MatrixDisplay<'a, T> {
+    matrix: &'a Matrix<T>,
+    threshold: usize,
+    edge_items: usize,
+}
+
+impl<T> Matrix<T> {
+    /// 以默认截断配置创建格式化输出，`{}` 输出矩阵时使用的就是该配置
+    pub fn display(&self) -> MatrixDisplay<'_, T> {
+        MatrixDisplay {
+            matrix: self,
+            threshold: DEFAULT_DISPLAY_THRESHOLD,
+            edge_items: DEFAULT_DISPLAY_EDGE_ITEMS,
+        }
+    }
+}
+
+impl<T> MatrixDisplay<'_, T> {
+    /// 设置截断阈值，`usize::MAX` 表示从不截断
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// 设置截断时每个方向保留的行数和列数
+    pub fn edge_items(mut self, edge_items: usize) -> Self {
+        self.edge_items = edge_items;
+        self
+    }
+
+    /// 要输出的行号或列号，None 表示省略号
+    fn visible(&self, len: usize) -> Vec<Option<usize>> {
+        let m = self.matrix;
+        if m.row * m.col > self.threshold && len > 2 * self.edge_items {
+            (0..self.edge_items)
+                .map(Some)
+                .chain([None])
+                .chain((len - self.edge_items..len).map(Some))
+                .collect()
+        } else {
+            (0..len).map(Some).collect()
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for MatrixDisplay<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            return self.fmt_aligned(f);
+        }
+        let m = self.matrix;
+        let (width, precision) = (f.width(), f.precision());
+        let cols = self.visible(m.col);
+
+        // 写入左花括号，开始格式化输出矩阵
+        write!(f, "{{")?;
+
+        // 遍历矩阵的行
+        for (ri, i) in self.visible(m.row).into_iter().enumerate() {
+            // 如果当前行不是矩阵的第一行，先写入一个逗号和一个空格
+            if ri > 0 {
+                write!(f, ", ")?;
+            }
+            let Some(i) = i else {
+                write!(f, "…")?;
+                continue;
+            };
+            // 遍历矩阵的列
+            for (ci, j) in cols.iter().enumerate() {
+                // 如果当前元素不是当前行的第一个元素，先写入一个空格
+                if ci > 0 {
+                    write!(f, " ")?;
+                }
+                // 格式化输出当前元素
+                match j {
+                    Some(j) => {
+                        write!(f, "{}", Cell::new(&m.data[i * m.col + j], width, precision))?
+                    }
+                    None => write!(f, "…")?,
+                }
+            }
+        }
+        // 写入右花括号，完成矩阵的格式化输出
+        write!(f, "}}")
+    }
+}
+
+impl<T: fmt::Display> MatrixDisplay<'_, T> {
+    /// 多行对齐输出，例如 2x3 矩阵输出为
+    ///
+    /// ```text
+    /// [ 1 -2   3]
+    /// [40  5 600]
+    /// ```
+    fn fmt_aligned(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let m = self.matrix;
+        let (width, precision) = (f.width(), f.precision());
+        let rows = self.visible(m.row);
+        let cols = self.visible(m.col);
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|i| {
+                cols.iter()
+                    .map(|j| match (i, j) {
+                        (Some(i), Some(j)) => {
+                            Cell::new(&m.data[i * m.col + j], width, precision).to_string()
+                        }
+                        _ => "…".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        // 每列的宽度取该列最宽元素的字符数
+        let mut widths = vec![0; cols.len()];
+        for row in &cells {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for (ri, row) in cells.iter().enumerate() {
+            if ri > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (ci, (cell, width)) in row.iter().zip(&widths).enumerate() {
+                if ci > 0 {
+                    write!(f, " ")?;
+                }
+                write!(f, "{:>width$}", cell)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+/// 按外层格式化参数中的宽度和精度输出单个元素
+struct Cell<'a, T> {
+    value: &'a T,
+    width: Option<usize>,
+    precision: Option<usize>,
+}
+
+impl<'a, T> Cell<'a, T> {
+    fn new(value: &'a T, width: Option<usize>, precision: Option<usize>) -> Self {
+        Self {
+            value,
+            width,
+            precision,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Cell<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match (self.width, self.precision) {
+            (Some(width), Some(precision)) => write!(f, "{:width$.precision$}", self.value),
+            (Some(width), None) => write!(f, "{:width$}", self.value),
+            (None, Some(precision)) => write!(f, "{:.precision$}", self.value),
+            (None, None) => write!(f, "{}", self.value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn test_display_truncated() {
+        let m = Matrix::new((0..100).collect::<Vec<i32>>(), 10, 10);
+        assert_eq!(m.display().threshold(usize::MAX).to_string().len(), 300);
+
+        let short = m.display().threshold(50).edge_items(2);
+        assert_eq!(
+            format!("{}", short),
+            "{0 1 … 8 9, 10 11 … 18 19, …, 80 81 … 88 89, 90 91 … 98 99}"
+        );
+        assert_eq!(
+            format!("{:#}", short),
+            "[ 0  1 …  8  9]\n[10 11 … 18 19]\n[ …  … …  …  …]\n[80 81 … 88 89]\n[90 91 … 98 99]"
+        );
+    }
+
+    #[test]
+    fn test_display_default_threshold() {
+        let m = Matrix::new(vec![0u8; 40 * 40], 40, 40);
+        assert_eq!(format!("{}", m).matches('0').count(), 36);
+        let m = Matrix::new(vec![0u8; 10 * 10], 10, 10);
+        assert_eq!(format!("{}", m).matches('0').count(), 100);
+    }
+}
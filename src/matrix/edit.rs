@@ -0,0 +1,200 @@
+use alloc::vec::Vec;
+use core::mem;
+
+use super::Matrix;
+use crate::error::MatrixError;
+use crate::vector::Vector;
+
+impl<T: Clone> Matrix<T> {
+    /// 在末尾追加一行
+    ///
+    /// `0×0` 矩阵追加第一行时列数取 `row.len()`
+    ///
+    /// # 返回值
+    /// 行长度与列数不同时返回 `MatrixError::LengthMismatch`，矩阵保持不变
+    pub fn push_row(&mut self, row: &[T]) -> Result<(), MatrixError> {
+        if self.row == 0 && self.col == 0 {
+            self.col = row.len();
+        }
+        if row.len() != self.col {
+            return Err(MatrixError::LengthMismatch {
+                a: row.len(),
+                b: self.col,
+            });
+        }
+        self.data.extend_from_slice(row);
+        self.row += 1;
+        Ok(())
+    }
+
+    /// 在末尾追加一列，一次遍历重建缓冲区
+    ///
+    /// `0×0` 矩阵追加第一列时行数取 `col.len()`
+    ///
+    /// # 返回值
+    /// 列长度与行数不同时返回 `MatrixError::LengthMismatch`，矩阵保持不变
+    pub fn push_col(&mut self, col: &[T]) -> Result<(), MatrixError> {
+        if self.row == 0 && self.col == 0 {
+            self.row = col.len();
+        }
+        if col.len() != self.row {
+            return Err(MatrixError::LengthMismatch {
+                a: col.len(),
+                b: self.row,
+            });
+        }
+        let mut old = mem::take(&mut self.data).into_iter();
+        let mut data = Vec::with_capacity(self.row * (self.col + 1));
+        for value in col {
+            data.extend(old.by_ref().take(self.col));
+            data.push(value.clone());
+        }
+        self.data = data;
+        self.col += 1;
+        Ok(())
+    }
+
+    /// 把所有元素设为 `value`
+    pub fn fill(&mut self, value: T) {
+        self.data.fill(value);
+    }
+
+    /// 把主对角线上的 `min(row, col)` 个元素设为 `value`，其余元素不变
+    pub fn fill_diagonal(&mut self, value: T) {
+        let n = self.row.min(self.col);
+        for i in 0..n {
+            self.data[i * self.col + i] = value.clone();
+        }
+    }
+
+    /// 用 `diag` 依次替换主对角线上的元素，其余元素不变
+    ///
+    /// # 返回值
+    /// `diag` 长度不等于 `min(row, col)` 时返回 `MatrixError::LengthMismatch`
+    pub fn set_diagonal(&mut self, diag: &Vector<T>) -> Result<(), MatrixError> {
+        let n = self.row.min(self.col);
+        if diag.len() != n {
+            return Err(MatrixError::LengthMismatch {
+                a: diag.len(),
+                b: n,
+            });
+        }
+        for (i, value) in diag.iter().enumerate() {
+            self.data[i * self.col + i] = value.clone();
+        }
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T> {
+    /// 删除第 `i` 行并返回该行的元素
+    ///
+    /// # 返回值
+    /// 行号越界时返回 `MatrixError::IndexOutOfBounds`
+    pub fn remove_row(&mut self, i: usize) -> Result<Vec<T>, MatrixError> {
+        if i >= self.row {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (i, 0),
+                shape: (self.row, self.col),
+            });
+        }
+        let removed = self.data.drain(i * self.col..(i + 1) * self.col).collect();
+        self.row -= 1;
+        Ok(removed)
+    }
+
+    /// 删除第 `j` 列并返回该列的元素
+    ///
+    /// 一次遍历原地压缩缓冲区，其余元素只移动一次
+    ///
+    /// # 返回值
+    /// 列号越界时返回 `MatrixError::IndexOutOfBounds`
+    pub fn remove_col(&mut self, j: usize) -> Result<Vec<T>, MatrixError> {
+        if j >= self.col {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (0, j),
+                shape: (self.row, self.col),
+            });
+        }
+        let col = self.col;
+        let mut idx = 0;
+        let removed = self
+            .data
+            .extract_if(.., |_| {
+                idx += 1;
+                (idx - 1) % col == j
+            })
+            .collect();
+        self.col -= 1;
+        Ok(removed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_push_row_col() -> anyhow::Result<()> {
+        let mut m = Matrix::new([], 0, 0);
+        m.push_row(&[1, 2])?;
+        m.push_row(&[3, 4])?;
+        m.push_col(&[5, 6])?;
+        assert_eq!(m, Matrix::new([1, 2, 5, 3, 4, 6], 2, 3));
+
+        assert_eq!(
+            m.push_row(&[1, 2]),
+            Err(MatrixError::LengthMismatch { a: 2, b: 3 })
+        );
+        assert_eq!(
+            m.push_col(&[1, 2, 3]),
+            Err(MatrixError::LengthMismatch { a: 3, b: 2 })
+        );
+        assert_eq!(m, Matrix::new([1, 2, 5, 3, 4, 6], 2, 3));
+
+        let mut c = Matrix::new([], 0, 0);
+        c.push_col(&[1, 2])?;
+        assert_eq!(c, Matrix::new([1, 2], 2, 1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_remove_row_col() -> anyhow::Result<()> {
+        let mut m = Matrix::new([1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+        assert_eq!(m.remove_row(1)?, vec![4, 5, 6]);
+        assert_eq!(m, Matrix::new([1, 2, 3, 7, 8, 9], 2, 3));
+        assert_eq!(m.remove_col(0)?, vec![1, 7]);
+        assert_eq!(m, Matrix::new([2, 3, 8, 9], 2, 2));
+
+        assert_eq!(
+            m.remove_row(2),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (2, 0),
+                shape: (2, 2)
+            })
+        );
+        assert!(m.remove_col(2).is_err());
+
+        assert_eq!(m.remove_col(1)?, vec![3, 9]);
+        assert_eq!(m.remove_col(0)?, vec![2, 8]);
+        assert_eq!(m, Matrix::new([], 2, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fill_diagonal() -> anyhow::Result<()> {
+        let mut m = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        m.fill_diagonal(0);
+        assert_eq!(m, Matrix::new([0, 2, 3, 4, 0, 6], 2, 3));
+        m.set_diagonal(&Vector::new([7, 8]))?;
+        assert_eq!(m, Matrix::new([7, 2, 3, 4, 8, 6], 2, 3));
+        assert_eq!(
+            m.set_diagonal(&Vector::new([1, 2, 3])),
+            Err(MatrixError::LengthMismatch { a: 3, b: 2 })
+        );
+        m.fill(1);
+        assert_eq!(m, Matrix::new([1; 6], 2, 3));
+        Ok(())
+    }
+}
@@ -0,0 +1,323 @@
+use alloc::vec::Vec;
+use core::ops::{AddAssign, Mul};
+use num_traits::Zero;
+#[cfg(feature = "std")]
+use std::thread;
+
+#[cfg(feature = "std")]
+use super::NUM_THREADS;
+use super::{Matrix, columns};
+use crate::error::MatrixError;
+#[cfg(feature = "std")]
+use crate::error::WorkerError;
+
+/// 矩阵元素在一维缓冲区中的排列顺序
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Layout {
+    /// 行优先（C 顺序），同一行的元素连续存放，`Matrix` 内部使用这种顺序
+    #[default]
+    RowMajor,
+    /// 列优先（Fortran/BLAS 顺序），同一列的元素连续存放
+    ColMajor,
+}
+
+/// 借用外部缓冲区的只读矩阵视图，按给定的 `Layout` 解释数据
+///
+/// 与 BLAS/Fortran 等列优先数据交互时无需先复制并重排成 `Matrix`
+///
+/// # 字段
+/// * `data`: 元素缓冲区，长度为 `row * col`
+/// * `row`: 行数
+/// * `col`: 列数
+/// * `layout`: 元素排列顺序
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixView<'a, T> {
+    data: &'a [T],
+    row: usize,
+    col: usize,
+    layout: Layout,
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// 创建矩阵视图
+    ///
+    /// # 参数
+    /// * `data`: 元素缓冲区
+    /// * `row`: 行数
+    /// * `col`: 列数
+    /// * `layout`: `data` 的排列顺序
+    ///
+    /// # 返回值
+    /// 缓冲区长度不等于 `row * col` 时返回 `MatrixError::LengthMismatch`
+    pub fn new(data: &'a [T], row: usize, col: usize, layout: Layout) -> Result<Self, MatrixError> {
+        if row.checked_mul(col) != Some(data.len()) {
+            return Err(MatrixError::LengthMismatch {
+                a: data.len(),
+                b: row.saturating_mul(col),
+            });
+        }
+        Ok(Self {
+            data,
+            row,
+            col,
+            layout,
+        })
+    }
+
+    /// 返回 `(行数, 列数)`
+    pub fn shape(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    /// 元素排列顺序
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// 底层缓冲区
+    pub fn as_slice(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// 读取 `(i, j)` 处的元素，越界时返回 `None`
+    pub fn get(&self, i: usize, j: usize) -> Option<&'a T> {
+        if i >= self.row || j >= self.col {
+            return None;
+        }
+        Some(&self.data[self.index(i, j)])
+    }
+
+    /// 按行优先顺序复制出 `Matrix`
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Clone,
+    {
+        let data = match self.layout {
+            Layout::RowMajor => self.data.to_vec(),
+            Layout::ColMajor => (0..self.row)
+                .flat_map(|i| self.row_iter(i).cloned())
+                .collect(),
+        };
+        Matrix {
+            data,
+            row: self.row,
+            col: self.col,
+        }
+    }
+
+    fn index(&self, i: usize, j: usize) -> usize {
+        match self.layout {
+            Layout::RowMajor => i * self.col + j,
+            Layout::ColMajor => j * self.row + i,
+        }
+    }
+
+    /// 第 `i` 行的元素，行优先时连续读取，列优先时按行数跨步读取
+    fn row_iter(&self, i: usize) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let stride = match self.layout {
+            Layout::RowMajor => 1,
+            Layout::ColMajor => self.row,
+        };
+        self.data[self.index(i, 0)..]
+            .iter()
+            .step_by(stride)
+            .take(self.col)
+    }
+
+    /// 第 `j` 列的元素，列优先时连续读取，行优先时按列数跨步读取
+    fn col_iter(&self, j: usize) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let stride = match self.layout {
+            Layout::RowMajor => self.col,
+            Layout::ColMajor => 1,
+        };
+        self.data[self.index(0, j)..]
+            .iter()
+            .step_by(stride)
+            .take(self.row)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// 以行优先视图借用矩阵，可与列优先视图一起参与 `multiply_views`
+    pub fn view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            data: &self.data,
+            row: self.row,
+            col: self.col,
+            layout: Layout::RowMajor,
+        }
+    }
+}
+
+impl<T: Clone> Matrix<T> {
+    /// 按指定顺序导出元素，`Layout::ColMajor` 可直接交给 BLAS/Fortran 接口
+    pub fn to_layout(&self, layout: Layout) -> Vec<T> {
+        match layout {
+            Layout::RowMajor => self.data.clone(),
+            Layout::ColMajor => columns(self),
+        }
+    }
+}
+
+/// 计算结果矩阵从 `first_row` 开始的若干行，写入行优先的 `out`
+fn multiply_rows<T>(a: &MatrixView<'_, T>, b: &MatrixView<'_, T>, first_row: usize, out: &mut [T])
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    for (k, cell) in out.iter_mut().enumerate() {
+        let (i, j) = (first_row + k / b.col, k % b.col);
+        let mut sum = T::zero();
+        for (x, y) in a.row_iter(i).zip(b.col_iter(j)) {
+            sum += x.clone() * y.clone();
+        }
+        *cell = sum;
+    }
+}
+
+fn check_views<T>(a: &MatrixView<'_, T>, b: &MatrixView<'_, T>) -> Result<(), MatrixError> {
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+    Ok(())
+}
+
+/// 在当前线程上串行计算两个视图的乘积，按各自的排列顺序直接读取，不重排输入
+///
+/// # 参数
+/// * `a`: 左操作数视图
+/// * `b`: 右操作数视图
+///
+/// # 返回值
+/// 返回行优先的结果矩阵，维度不匹配时返回 `MatrixError::DimensionMismatch`
+pub fn multiply_views_sequential<T>(
+    a: &MatrixView<'_, T>,
+    b: &MatrixView<'_, T>,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    check_views(a, b)?;
+    let mut data = alloc::vec![T::zero(); a.row * b.col];
+    multiply_rows(a, b, 0, &mut data);
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: b.col,
+    })
+}
+
+/// 并发计算两个视图的乘积，按各自的排列顺序直接读取，不重排输入
+///
+/// 结果按行分成 `NUM_THREADS` 段，每段在一个作用域线程中计算
+///
+/// # 参数
+/// * `a`: 左操作数视图
+/// * `b`: 右操作数视图
+///
+/// # 返回值
+/// 返回行优先的结果矩阵，维度不匹配时返回 `MatrixError::DimensionMismatch`，
+/// 工作线程 panic 时返回 `MatrixError::WorkerFailed`
+#[cfg(feature = "std")]
+pub fn multiply_views<T>(
+    a: &MatrixView<'_, T>,
+    b: &MatrixView<'_, T>,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync,
+{
+    check_views(a, b)?;
+    let mut data = alloc::vec![T::zero(); a.row * b.col];
+    if !data.is_empty() {
+        let band = a.row.div_ceil(NUM_THREADS);
+        thread::scope(|s| {
+            let handles = data
+                .chunks_mut(band * b.col)
+                .enumerate()
+                .map(|(idx, out)| (idx, s.spawn(move || multiply_rows(a, b, idx * band, out))))
+                .collect::<Vec<_>>();
+            handles.into_iter().try_for_each(|(idx, handle)| {
+                handle.join().map_err(|payload| {
+                    MatrixError::WorkerFailed(WorkerError::panicked(idx, payload))
+                })
+            })
+        })?;
+    }
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: b.col,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_view_layouts() -> anyhow::Result<()> {
+        let m = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let col_major = m.to_layout(Layout::ColMajor);
+        assert_eq!(col_major, vec![1, 4, 2, 5, 3, 6]);
+
+        let v = MatrixView::new(&col_major, 2, 3, Layout::ColMajor)?;
+        assert_eq!(v.shape(), (2, 3));
+        assert_eq!(v.get(0, 2), Some(&3));
+        assert_eq!(v.get(1, 0), Some(&4));
+        assert_eq!(v.get(2, 0), None);
+        assert_eq!(v.to_matrix(), m);
+        assert_eq!(m.view().to_matrix(), m);
+
+        assert_eq!(
+            MatrixView::new(&col_major, 4, 2, Layout::ColMajor),
+            Err(MatrixError::LengthMismatch { a: 6, b: 8 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_views_sequential() -> anyhow::Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let expected = Matrix::new([58, 64, 139, 154], 2, 2);
+
+        let a_cm = a.to_layout(Layout::ColMajor);
+        let b_cm = b.to_layout(Layout::ColMajor);
+        let a_cm = MatrixView::new(&a_cm, 2, 3, Layout::ColMajor)?;
+        let b_cm = MatrixView::new(&b_cm, 3, 2, Layout::ColMajor)?;
+        for (x, y) in [
+            (a.view(), b.view()),
+            (a_cm, b.view()),
+            (a.view(), b_cm),
+            (a_cm, b_cm),
+        ] {
+            assert_eq!(multiply_views_sequential(&x, &y)?, expected);
+        }
+
+        assert!(matches!(
+            multiply_views_sequential(&a.view(), &a.view()),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_multiply_views() -> anyhow::Result<()> {
+        let a = Matrix::new((0..35).collect::<Vec<i64>>(), 7, 5);
+        let b = Matrix::new((0..15).rev().collect::<Vec<i64>>(), 5, 3);
+        let b_cm = b.to_layout(Layout::ColMajor);
+        let b_cm = MatrixView::new(&b_cm, 5, 3, Layout::ColMajor)?;
+        assert_eq!(
+            multiply_views(&a.view(), &b_cm)?,
+            crate::matrix::multiply_sequential(&a, &b)?
+        );
+
+        let empty = Matrix::<i64>::new([], 0, 5);
+        assert_eq!(multiply_views(&empty.view(), &b_cm)?, Matrix::new([], 0, 3));
+        Ok(())
+    }
+}
@@ -0,0 +1,1244 @@
+use num_traits::Zero;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{AddAssign, Mul, Range};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use super::{Matrix, TryMul, columns};
+use crate::cancel::CancelToken;
+use crate::channel::oneshot;
+use crate::error::{MatrixError, WorkerError};
+use crate::options::{ChunkStrategy, MultiplyOptions, ProgressFn};
+use crate::pool::{SubmitError, ThreadPool};
+use crate::trace::{MultiplyTrace, TraceEvent};
+use crate::vector::{Vector, dot};
+
+pub(crate) const NUM_THREADS: usize = 4; // 线程数
+
+/// 并发矩阵乘法运算
+///
+/// # 类型参数
+/// * `T`: 元素类型，需满足多个trait约束
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，包含乘积结果或错误信息
+///
+/// # 并发策略
+/// 使用固定大小线程池（NUM_THREADS）进行并行计算，
+/// 计算量低于 `DEFAULT_SEQUENTIAL_THRESHOLD` 时在当前线程串行计算
+pub fn multiply<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_with_cancel(a, b, &CancelToken::new())
+}
+
+/// 可取消的并发矩阵乘法运算
+///
+/// 工作线程在每个任务之间检查取消令牌，调用方在分发和收集结果时同样检查，
+/// 一旦令牌被取消，立即停止并返回 `MatrixError::Cancelled`
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `token`: 取消令牌
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，被取消时返回 `MatrixError::Cancelled`
+pub fn multiply_with_cancel<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    token: &CancelToken,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_with(a, b, MultiplyOptions::new().cancel_token(token.clone()))
+}
+
+/// 带超时的并发矩阵乘法运算
+///
+/// 如果在 `timeout` 内没有收集完全部结果，返回 `MatrixError::Timeout`，
+/// 尚未执行的任务会被工作线程直接丢弃，不会继续占用 CPU
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `timeout`: 最长等待时间
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，超时时返回 `MatrixError::Timeout`
+pub fn multiply_with_timeout<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    timeout: Duration,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_with(a, b, MultiplyOptions::new().timeout(timeout))
+}
+
+/// 按配置执行并发矩阵乘法运算
+///
+/// 取消、超时与进度回调等行为由 `MultiplyOptions` 控制，
+/// 其余 `multiply_with_*` 函数都是它的简单包装
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `options`: 乘法配置
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，包含乘积结果或错误信息
+pub fn multiply_with<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    options: MultiplyOptions<'_>,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    // 检查矩阵维度是否匹配
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+
+    // 初始化结果矩阵数据
+    let mut out = Matrix {
+        data: vec![T::zero(); a.row * b.col],
+        row: a.row,
+        col: b.col,
+    };
+    multiply_into_with(a, b, &mut out, options)?;
+    Ok(out)
+}
+
+/// 按给定配置计算乘积，同时返回每个任务的调度记录
+///
+/// 相当于在 `options` 上开启 `MultiplyOptions::trace`，
+/// 返回的事件按开始时间排序，可用于绘制各工作线程的甘特图
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `options`: 乘法配置
+pub fn multiply_traced<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    options: MultiplyOptions<'_>,
+) -> Result<(Matrix<T>, Vec<TraceEvent>), MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    let trace = MultiplyTrace::new();
+    let c = multiply_with(a, b, options.trace(trace.clone()))?;
+    Ok((c, trace.events()))
+}
+
+/// 将矩阵乘积写入预先分配的矩阵
+///
+/// 复用 `out` 的存储空间，避免在循环中反复相乘时每次都重新分配结果矩阵
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `out`: 结果矩阵，形状必须为 `a.row × b.col`
+///
+/// # 返回值
+/// 返回Result<(), MatrixError>，出错时 `out` 中可能只写入了部分结果
+pub fn multiply_into<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    out: &mut Matrix<T>,
+) -> Result<(), MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_into_with(a, b, out, MultiplyOptions::new())
+}
+
+/// 按配置将矩阵乘积写入预先分配的矩阵
+///
+/// # 参数
+/// * `a`: 左操作数矩阵
+/// * `b`: 右操作数矩阵
+/// * `out`: 结果矩阵，形状必须为 `a.row × b.col`
+/// * `options`: 乘法配置
+///
+/// # 返回值
+/// 返回Result<(), MatrixError>，出错时 `out` 中可能只写入了部分结果
+pub fn multiply_into_with<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    out: &mut Matrix<T>,
+    options: MultiplyOptions<'_>,
+) -> Result<(), MatrixError>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_kernel_into(a, b, out, options, dot_kernel)
+}
+
+/// 默认单元内核：普通点积，溢出行为与元素类型的 `+`、`*` 一致
+fn dot_kernel<T>(row: &[T], col: &[T]) -> Option<T>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    Some(dot(row, col))
+}
+
+/// 单元计算内核：由行和列计算一个结果单元，返回 None 表示算术溢出
+pub(crate) type Kernel<T> = fn(&[T], &[T]) -> Option<T>;
+
+/// 按配置、使用指定的单元内核执行并发矩阵乘法
+///
+/// 调度、取消、超时、进度与统计都在这里实现，
+/// 不同的算术语义（普通、溢出检查、回绕、饱和）只需提供不同的内核
+pub(crate) fn multiply_kernel_into<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    out: &mut Matrix<T>,
+    options: MultiplyOptions<'_>,
+    kernel: Kernel<T>,
+) -> Result<(), MatrixError>
+where
+    T: fmt::Debug + Clone + Send + Sync + 'static,
+{
+    // 检查矩阵维度是否匹配
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+    if out.row != a.row || out.col != b.col || out.data.len() != a.row * b.col {
+        return Err(MatrixError::OutputShapeMismatch {
+            expected: (a.row, b.col),
+            actual: (out.row, out.col),
+        });
+    }
+
+    let MultiplyOptions {
+        cancel,
+        timeout,
+        mut progress,
+        sequential_threshold,
+        deterministic,
+        channel_capacity,
+        pool: shared_pool,
+        priority,
+        stats,
+        trace,
+        pin_workers,
+        scheduler,
+        chunk_strategy,
+    } = options;
+    let started = Instant::now();
+    if let Some(trace) = &trace {
+        trace.reset(started);
+    }
+    let token = &cancel.unwrap_or_default();
+    // 超出 Instant 表示范围的超时等价于不限时
+    let deadline = timeout.and_then(|timeout| Instant::now().checked_add(timeout));
+
+    // 小矩阵的线程和通道开销超过计算本身，直接串行计算
+    if a.row * a.col * b.col < sequential_threshold {
+        sequential_into(a, b, &mut out.data, token, deadline, progress, kernel)?;
+        if let Some(stats) = &stats {
+            // 串行计算只有调用方一个线程执行一个任务，全部时间都在计算
+            let elapsed = started.elapsed();
+            stats.reset(1);
+            stats.record(0, Duration::ZERO, elapsed);
+            stats.finish(elapsed);
+        }
+        if let Some(trace) = &trace {
+            trace.record(0, 0, started, Instant::now());
+        }
+        return Ok(());
+    }
+
+    // 未指定共享线程池时创建私有线程池，函数返回时私有线程池被 drop，所有工作线程都会被 join
+    let owned_pool;
+    let pool = match shared_pool {
+        Some(pool) if !deterministic => pool,
+        _ => {
+            owned_pool = match pin_workers {
+                #[cfg(feature = "affinity")]
+                true => ThreadPool::pinned_with_capacity(NUM_THREADS, channel_capacity),
+                _ => ThreadPool::with_capacity(NUM_THREADS, channel_capacity),
+            };
+            &owned_pool
+        }
+    };
+    let num_threads = pool.size();
+    if let Some(stats) = &stats {
+        stats.reset(num_threads);
+    }
+
+    // 内部中止信号：函数提前返回时通知工作线程丢弃剩余任务，
+    // 与调用方的令牌分开，避免超时等内部原因取消了调用方的令牌。
+    // 必须在 pool 之后声明，保证先于 pool 被 drop
+    let abort = CancelToken::new();
+    let _guard = AbortOnDrop(abort.clone());
+
+    // 确定性模式下所有任务按索引顺序轮流执行
+    let turnstile = deterministic.then(|| Arc::new(Turnstile::default()));
+    let scheduler = scheduler.filter(|_| !deterministic);
+
+    let matrix_len = a.row * b.col;
+    // 输入只复制一次，所有任务共享，任务本身只携带行列范围
+    let a_data: Arc<[T]> = Arc::from(a.data.as_slice());
+    let bt: Arc<[T]> = Arc::from(columns(b));
+    // 在途任务窗口：超过窗口时先按顺序收集最早的结果，使内存占用保持平稳
+    let window = channel_capacity.max(1) * num_threads;
+    let mut pending = VecDeque::with_capacity(window.min(matrix_len));
+    let mut done = 0;
+    let mut collect =
+        |idx: usize, rx: oneshot::Receiver<MsgOutput<T>>| -> Result<(), MatrixError> {
+            let msg = receive(idx, rx, token, deadline)?;
+            done += msg.store(&mut out.data)?;
+            if let Some(progress) = progress.as_mut() {
+                progress(done, matrix_len);
+            }
+            Ok(())
+        };
+
+    // 按分块策略分发计算任务
+    for (task, (rows, cols)) in chunks(chunk_strategy, a.row, b.col).enumerate() {
+        if token.is_cancelled() {
+            return Err(MatrixError::Cancelled);
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Err(MatrixError::Timeout);
+        }
+
+        // 以分块左上角的单元索引标识任务，并创建通信通道
+        let idx = rows.start * b.col + cols.start;
+        let input = MsgInput::block(idx, a_data.clone(), bt.clone(), rows, cols, a.col, b.col);
+        let (tx, rx) = oneshot::channel();
+        let msg = Msg::new(input, tx);
+
+        // 按调度策略分配任务到线程池，默认轮询
+        let task_token = token.clone();
+        let abort = abort.clone();
+        let turnstile = turnstile.clone();
+        let stats = stats.clone();
+        let trace = trace.clone();
+        let scheduler = scheduler.clone();
+        let worker = match &scheduler {
+            Some(scheduler) => scheduler.assign(task, num_threads) % num_threads,
+            None => task % num_threads,
+        };
+        let submitted = Instant::now();
+        let job = move || {
+            let _turn = turnstile.as_ref().map(|turnstile| turnstile.enter(task));
+            // 已取消则直接丢弃任务
+            if !task_token.is_cancelled() && !abort.is_cancelled() {
+                let start = Instant::now();
+                let Msg { input, sender } = msg;
+                let output = input.process(kernel);
+                // 先记录统计和跟踪再发送结果：调用方收到最后一个结果后可能立即返回，
+                // 使用共享线程池时不会等待工作线程
+                let end = Instant::now();
+                if let Some(stats) = stats {
+                    stats.record(worker, start - submitted, end - start);
+                }
+                if let Some(trace) = trace {
+                    trace.record(task, worker, start, end);
+                }
+                // 调用方已放弃等待（超时或出错）时发送失败是正常情况
+                let _ = sender.send(output);
+            }
+            if let Some(scheduler) = scheduler {
+                scheduler.on_complete(task, worker);
+            }
+        };
+        // 队列已满时等待空位不能越过截止时间，也要响应取消
+        pool.execute_on_until(worker, priority, deadline, token, job)
+            .map_err(|err| match err {
+                SubmitError::Closed => WorkerError::disconnected(idx).into(),
+                SubmitError::Timeout => MatrixError::Timeout,
+                SubmitError::Cancelled => MatrixError::Cancelled,
+            })?;
+        pending.push_back((idx, rx));
+        while pending.len() > window {
+            if let Some((idx, rx)) = pending.pop_front() {
+                collect(idx, rx)?;
+            }
+        }
+    }
+
+    // 收集剩余的计算结果
+    for (idx, rx) in pending {
+        collect(idx, rx)?;
+    }
+
+    if token.is_cancelled() {
+        return Err(MatrixError::Cancelled);
+    }
+
+    if let Some(stats) = &stats {
+        stats.finish(started.elapsed());
+    }
+
+    Ok(())
+}
+
+/// 按分块策略把 `rows × cols` 的结果矩阵切分为任务
+///
+/// 按行主序依次返回每个任务覆盖的行范围和列范围，边缘分块可能小于设定的大小
+fn chunks(
+    strategy: ChunkStrategy,
+    rows: usize,
+    cols: usize,
+) -> impl Iterator<Item = (Range<usize>, Range<usize>)> {
+    let (height, width) = match strategy {
+        ChunkStrategy::PerCell => (1, 1),
+        ChunkStrategy::PerRow => (1, cols.max(1)),
+        ChunkStrategy::Blocked { tile } => (tile.max(1), tile.max(1)),
+    };
+    (0..rows).step_by(height).flat_map(move |i| {
+        (0..cols)
+            .step_by(width)
+            .map(move |j| (i..(i + height).min(rows), j..(j + width).min(cols)))
+    })
+}
+
+/// 等待单个任务的结果
+///
+/// # 参数
+/// * `idx`: 任务在结果矩阵中的位置索引
+/// * `rx`: 任务的一次性接收端
+/// * `token`: 调用方的取消令牌
+/// * `deadline`: 截止时间，None 表示不限时
+fn receive<T>(
+    idx: usize,
+    rx: oneshot::Receiver<MsgOutput<T>>,
+    token: &CancelToken,
+    deadline: Option<Instant>,
+) -> Result<MsgOutput<T>, MatrixError> {
+    let received = match deadline {
+        Some(deadline) => rx.recv_deadline(deadline).map_err(|e| match e {
+            oneshot::RecvTimeoutError::Timeout => MatrixError::Timeout,
+            oneshot::RecvTimeoutError::Disconnected => WorkerError::disconnected(idx).into(),
+        }),
+        None => rx.recv().map_err(|_| WorkerError::disconnected(idx).into()),
+    };
+    match received {
+        Ok(msg) => Ok(msg),
+        // 工作线程因取消而丢弃了任务
+        Err(_) if token.is_cancelled() => Err(MatrixError::Cancelled),
+        Err(e) => Err(e),
+    }
+}
+
+/// 串行矩阵乘法内核
+///
+/// 在当前线程上按三重循环计算，取消与超时按行检查，进度按单元报告，
+/// 单元计算中的 panic 与并行路径一样以 `MatrixError::WorkerFailed` 返回，
+/// 内核报告的溢出以 `MatrixError::Overflow` 返回
+fn sequential_into<T>(
+    a: &Matrix<T>,
+    b: &Matrix<T>,
+    out: &mut [T],
+    token: &CancelToken,
+    deadline: Option<Instant>,
+    mut progress: Option<ProgressFn<'_>>,
+    kernel: Kernel<T>,
+) -> Result<(), MatrixError>
+where
+    T: Clone,
+{
+    let matrix_len = a.row * b.col;
+    let bt = columns(b);
+    for i in 0..a.row {
+        if token.is_cancelled() {
+            return Err(MatrixError::Cancelled);
+        }
+        if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+            return Err(MatrixError::Timeout);
+        }
+        let row = &a.data[i * a.col..(i + 1) * a.col];
+        for j in 0..b.col {
+            let idx = i * b.col + j;
+            let col = &bt[j * b.row..(j + 1) * b.row];
+            let value = panic::catch_unwind(AssertUnwindSafe(|| kernel(row, col)))
+                .map_err(|payload| WorkerError::panicked(idx, payload))?
+                .ok_or(MatrixError::Overflow { idx })?;
+            out[idx] = value;
+            if let Some(progress) = progress.as_mut() {
+                progress(idx + 1, matrix_len);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// 确定性模式使用的轮转门
+///
+/// 任务按索引顺序依次通过：索引为 `idx` 的任务必须等到前一个任务离开后才能进入。
+/// 每个工作线程的队列本身按索引递增，因此不会死锁
+#[derive(Default)]
+struct Turnstile {
+    next: Mutex<usize>,
+    cond: Condvar,
+}
+
+/// 轮转门通行凭证，drop 时放行下一个任务
+struct Turn<'a> {
+    turnstile: &'a Turnstile,
+}
+
+impl Turnstile {
+    /// 等待轮到索引为 `idx` 的任务
+    fn enter(&self, idx: usize) -> Turn<'_> {
+        let mut next = self.next.lock().unwrap_or_else(PoisonError::into_inner);
+        while *next != idx {
+            next = self.cond.wait(next).unwrap_or_else(PoisonError::into_inner);
+        }
+        Turn { turnstile: self }
+    }
+}
+
+impl Drop for Turn<'_> {
+    fn drop(&mut self) {
+        let mut next = self
+            .turnstile
+            .next
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        *next += 1;
+        self.turnstile.cond.notify_all();
+    }
+}
+
+/// 离开作用域时发出中止信号
+struct AbortOnDrop(CancelToken);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.cancel();
+    }
+}
+
+/// 消息输入结构体
+/// 用于封装一个结果分块的计算任务参数，单个点积是只有一个单元的分块
+///
+/// # 字段
+/// * `idx`: 分块左上角在结果矩阵中的位置索引
+/// * `row`: 共享的行向量，按行首尾相接
+/// * `col`: 共享的列向量，按列首尾相接
+/// * `rows`: 分块涉及的行在 `row` 中的编号范围
+/// * `cols`: 分块涉及的列在 `col` 中的编号范围
+/// * `depth`: 每个行（列）向量的长度
+/// * `stride`: 结果矩阵的列数，用于由分块内的位置换算索引
+pub struct MsgInput<T> {
+    idx: usize,
+    row: Arc<[T]>,
+    col: Arc<[T]>,
+    rows: Range<usize>,
+    cols: Range<usize>,
+    depth: usize,
+    stride: usize,
+}
+
+/// 消息输出结构体
+/// 用于封装单个点积计算结果
+///
+/// # 字段
+/// * `idx`: 分块左上角在结果矩阵中的位置索引
+/// * `width`: 分块的列数
+/// * `stride`: 结果矩阵的列数
+/// * `value`: 按行主序排列的分块结果，工作线程 panic 或溢出时为对应的错误
+pub struct MsgOutput<T> {
+    idx: usize,
+    width: usize,
+    stride: usize,
+    value: Result<Vec<T>, MatrixError>,
+}
+
+impl<T> MsgInput<T> {
+    /// 创建消息输入实例
+    ///
+    /// # 参数
+    /// * `idx`: 结果矩阵中的位置索引
+    /// * `row`: 当前行向量
+    /// * `col`: 当前列向量
+    ///
+    /// # 返回值
+    /// 返回MsgInput<T>实例
+    pub fn new(idx: usize, row: Vector<T>, col: Vector<T>) -> Self {
+        let depth = row.len();
+        Self {
+            idx,
+            row: row.into_inner().into(),
+            col: col.into_inner().into(),
+            rows: 0..1,
+            cols: 0..1,
+            depth,
+            stride: 1,
+        }
+    }
+
+    /// 创建分块计算任务
+    ///
+    /// # 参数
+    /// * `idx`: 分块左上角在结果矩阵中的位置索引
+    /// * `row`: 共享的行向量，按行首尾相接
+    /// * `col`: 共享的列向量，按列首尾相接
+    /// * `rows`: 分块涉及的行编号范围
+    /// * `cols`: 分块涉及的列编号范围
+    /// * `depth`: 每个行（列）向量的长度
+    /// * `stride`: 结果矩阵的列数
+    fn block(
+        idx: usize,
+        row: Arc<[T]>,
+        col: Arc<[T]>,
+        rows: Range<usize>,
+        cols: Range<usize>,
+        depth: usize,
+        stride: usize,
+    ) -> Self {
+        Self {
+            idx,
+            row,
+            col,
+            rows,
+            cols,
+            depth,
+            stride,
+        }
+    }
+}
+
+impl<T> MsgOutput<T> {
+    /// 把分块结果写入结果矩阵的数据，返回写入的单元数
+    fn store(self, out: &mut [T]) -> Result<usize, MatrixError> {
+        let MsgOutput {
+            idx,
+            width,
+            stride,
+            value,
+        } = self;
+        let values = value?;
+        let cells = values.len();
+        for (k, value) in values.into_iter().enumerate() {
+            out[idx + k / width * stride + k % width] = value;
+        }
+        Ok(cells)
+    }
+}
+
+pub struct Msg<T> {
+    input: MsgInput<T>,
+    sender: oneshot::Sender<MsgOutput<T>>, // 一次性channel
+}
+impl<T> Msg<T> {
+    /// 创建消息实例
+    ///
+    /// # 参数
+    /// * `input`: 计算任务参数
+    /// * `sender`: 一次性发送通道
+    ///
+    /// # 返回值
+    /// 返回Msg<T>实例
+    pub fn new(input: MsgInput<T>, sender: oneshot::Sender<MsgOutput<T>>) -> Self {
+        Self { input, sender }
+    }
+}
+
+impl<T> MsgInput<T> {
+    /// 用单元内核按行主序计算分块内的每个单元
+    ///
+    /// panic 会被捕获并作为 `WorkerError` 返回，避免单个任务拖垮整个工作线程；
+    /// 内核报告的溢出作为 `MatrixError::Overflow` 返回。出错时分块内余下的单元不再计算
+    fn process(self, kernel: Kernel<T>) -> MsgOutput<T> {
+        let MsgInput {
+            idx,
+            row,
+            col,
+            rows,
+            cols,
+            depth,
+            stride,
+        } = self;
+        let width = cols.len();
+        let value = (0..rows.len() * width)
+            .map(|k| {
+                let (i, j) = (k / width, k % width);
+                let cell = idx + i * stride + j;
+                let (r, c) = (rows.start + i, cols.start + j);
+                let row = &row[r * depth..(r + 1) * depth];
+                let col = &col[c * depth..(c + 1) * depth];
+                panic::catch_unwind(AssertUnwindSafe(|| kernel(row, col)))
+                    .map_err(|payload| MatrixError::from(WorkerError::panicked(cell, payload)))
+                    .and_then(|value| value.ok_or(MatrixError::Overflow { idx: cell }))
+            })
+            .collect();
+        MsgOutput {
+            idx,
+            width,
+            stride,
+            value,
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    /// 检查维度的矩阵乘法
+    ///
+    /// # 参数
+    /// * `rhs`: 右操作数矩阵
+    ///
+    /// # 返回值
+    /// 返回Result<Matrix<T>, MatrixError>，维度不匹配时返回 `MatrixError::DimensionMismatch`
+    pub fn checked_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        multiply(self, rhs)
+    }
+}
+
+impl<T> TryMul for Matrix<T>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    type Output = Self;
+    type Error = MatrixError;
+
+    fn try_mul(self, rhs: Self) -> Result<Self::Output, Self::Error> {
+        multiply(&self, &rhs)
+    }
+}
+
+impl<'a, T> TryMul<&'a Matrix<T>> for &'a Matrix<T>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    type Output = Matrix<T>;
+    type Error = MatrixError;
+
+    fn try_mul(self, rhs: &'a Matrix<T>) -> Result<Self::Output, Self::Error> {
+        multiply(self, rhs)
+    }
+}
+
+/// `*` 运算符是 `TryMul::try_mul` 的便捷写法
+///
+/// # Panics
+/// 维度不匹配或计算失败时 panic，库代码中应优先使用 `try_mul` 或 `checked_mul`
+impl<T> Mul for Matrix<T>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self::Output {
+        self.try_mul(rhs).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WorkerErrorKind;
+    use crate::matrix::{multiply_seq, multiply_sequential};
+    use crate::pool::Priority;
+    use crate::stats::MultiplyStats;
+    use anyhow::Result;
+    use std::ops::Add;
+
+    #[test]
+    fn test_matrix_multiply() -> Result<()> {
+        let a = Matrix::new(vec![1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new(vec![7, 8, 9, 10, 11, 12], 3, 2);
+        let c = multiply(&a, &b)?;
+        assert_eq!(c, Matrix::new(vec![58, 64, 139, 154], 2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_display() -> Result<()> {
+        let a = Matrix::new(vec![1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new(vec![7, 8, 9, 10, 11, 12], 3, 2);
+        let c = multiply(&a, &b)?;
+        assert_eq!(c.data, vec![58, 64, 139, 154]);
+        assert_eq!(format!("{}", c), "{58 64, 139 154}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_a_can_not_multiply_b() {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([1, 2, 3, 4], 2, 2);
+        let c = multiply(&a, &b);
+        assert_eq!(
+            c.unwrap_err(),
+            MatrixError::DimensionMismatch {
+                a: (2, 3),
+                b: (2, 2)
+            }
+        );
+    }
+
+    #[test]
+    fn test_try_mul_and_checked_mul() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let expected = Matrix::new([58, 64, 139, 154], 2, 2);
+        assert_eq!(a.checked_mul(&b)?, expected);
+        assert_eq!((&a).try_mul(&b)?, expected);
+        assert_eq!(a.try_mul(b)?, expected);
+
+        let c = Matrix::new([1, 2, 3, 4], 2, 2);
+        let d = Matrix::new([1, 2, 3, 4, 5, 6], 3, 2);
+        assert!(matches!(
+            c.try_mul(d),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_a_can_not_multiply_b_panic() {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([1, 2, 3, 4], 2, 2);
+        let _c = a * b;
+    }
+
+    #[test]
+    fn test_multiply_cancelled() {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([1, 2, 3, 4, 5, 6], 3, 2);
+        let token = CancelToken::new();
+        token.cancel();
+        let err = multiply_with_cancel(&a, &b, &token).unwrap_err();
+        assert_eq!(err, MatrixError::Cancelled);
+    }
+
+    #[test]
+    fn test_multiply_with_uncancelled_token() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let c = multiply_with_cancel(&a, &b, &CancelToken::new())?;
+        assert_eq!(c, Matrix::new([58, 64, 139, 154], 2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_with_timeout() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let c = multiply_with_timeout(&a, &b, Duration::from_secs(10))?;
+        assert_eq!(c, Matrix::new([58, 64, 139, 154], 2, 2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_timeout_expired() {
+        let a = Matrix::new(vec![1u64; 200 * 200], 200, 200);
+        let b = Matrix::new(vec![1u64; 200 * 200], 200, 200);
+        let err = multiply_with_timeout(&a, &b, Duration::ZERO).unwrap_err();
+        assert_eq!(err, MatrixError::Timeout);
+    }
+
+    #[test]
+    fn test_multiply_into_reuses_output() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let mut out = Matrix::new([0; 4], 2, 2);
+        let ptr = out.data.as_ptr();
+        multiply_into(&a, &b, &mut out)?;
+        assert_eq!(out, Matrix::new([58, 64, 139, 154], 2, 2));
+        assert_eq!(out.data.as_ptr(), ptr);
+
+        let mut wrong = Matrix::new([0; 6], 3, 2);
+        assert!(multiply_into(&a, &b, &mut wrong).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_deterministic_execution_order() -> Result<()> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // 通过 Mul 记录全局执行顺序，确定性模式下应严格按单元索引递增
+        static CLOCK: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Debug, Default, Clone, Copy, PartialEq)]
+        struct Stamp(usize);
+
+        impl Add for Stamp {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Stamp(self.0.max(rhs.0))
+            }
+        }
+
+        impl Zero for Stamp {
+            fn zero() -> Self {
+                Stamp(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl AddAssign for Stamp {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl Mul for Stamp {
+            type Output = Self;
+            fn mul(self, _rhs: Self) -> Self {
+                Stamp(CLOCK.fetch_add(1, Ordering::SeqCst))
+            }
+        }
+
+        let a = Matrix::new(vec![Stamp(0); 8 * 3], 8, 3);
+        let b = Matrix::new(vec![Stamp(0); 3 * 8], 3, 8);
+        let options = MultiplyOptions::new()
+            .sequential_threshold(0)
+            .deterministic(true);
+        let Ok(c) = multiply_with(&a, &b, options) else {
+            panic!("multiply should succeed");
+        };
+        // 每个单元做 3 次乘法，单元 idx 的最后一次乘法时间戳为 idx * 3 + 2
+        let expected = (0..64).map(|idx| Stamp(idx * 3 + 2)).collect::<Vec<_>>();
+        assert_eq!(c.data, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_with_small_channel_capacity() -> Result<()> {
+        let a = Matrix::new((0..48).collect::<Vec<i64>>(), 6, 8);
+        let b = Matrix::new((0..56).collect::<Vec<i64>>(), 8, 7);
+        let expected = multiply_with(
+            &a,
+            &b,
+            MultiplyOptions::new().sequential_threshold(usize::MAX),
+        )?;
+        for capacity in [0, 1, 3] {
+            let options = MultiplyOptions::new()
+                .sequential_threshold(0)
+                .channel_capacity(capacity);
+            assert_eq!(multiply_with(&a, &b, options)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_on_shared_pool() -> Result<()> {
+        let pool = ThreadPool::new(3);
+        let a = Matrix::new((0..48).collect::<Vec<i64>>(), 6, 8);
+        let b = Matrix::new((0..56).collect::<Vec<i64>>(), 8, 7);
+        let expected = multiply_with(
+            &a,
+            &b,
+            MultiplyOptions::new().sequential_threshold(usize::MAX),
+        )?;
+        for priority in [Priority::Normal, Priority::High] {
+            let options = MultiplyOptions::new()
+                .sequential_threshold(0)
+                .pool(&pool)
+                .priority(priority);
+            assert_eq!(multiply_with(&a, &b, options)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_timeout_on_saturated_pool() -> Result<()> {
+        let pool = ThreadPool::with_capacity(1, 1);
+        // 占住唯一的工作线程并填满队列，使后续提交必须等待空位
+        let (release, blocked) = std::sync::mpsc::channel::<()>();
+        pool.execute_on(0, move || {
+            let _ = blocked.recv();
+        })?;
+        pool.execute_on(0, || {})?;
+
+        let a = Matrix::new(vec![1u64; 16], 4, 4);
+        let b = Matrix::new(vec![1u64; 16], 4, 4);
+        let options = MultiplyOptions::new()
+            .sequential_threshold(0)
+            .pool(&pool)
+            .timeout(Duration::from_millis(50));
+        let start = Instant::now();
+        let err = multiply_with(&a, &b, options).unwrap_err();
+        assert_eq!(err, MatrixError::Timeout);
+        assert!(start.elapsed() < Duration::from_secs(5));
+        release.send(())?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_stats() -> Result<()> {
+        let a = Matrix::new((0..48).collect::<Vec<i64>>(), 6, 8);
+        let b = Matrix::new((0..56).collect::<Vec<i64>>(), 8, 7);
+        let stats = MultiplyStats::new();
+        let options = MultiplyOptions::new()
+            .sequential_threshold(0)
+            .stats(stats.clone());
+        multiply_with(&a, &b, options)?;
+        let workers = stats.workers();
+        assert_eq!(workers.len(), NUM_THREADS);
+        assert_eq!(stats.total_tasks(), 6);
+        // 默认每行一个任务，6 行轮询分配到 4 个线程
+        let tasks = workers.iter().map(|w| w.tasks).collect::<Vec<_>>();
+        assert_eq!(tasks, vec![2, 2, 1, 1]);
+
+        let options = MultiplyOptions::new()
+            .sequential_threshold(0)
+            .chunk_strategy(ChunkStrategy::PerCell)
+            .stats(stats.clone());
+        multiply_with(&a, &b, options)?;
+        // 42 个单元任务轮询分配到 4 个线程
+        let tasks = stats.workers().iter().map(|w| w.tasks).collect::<Vec<_>>();
+        assert_eq!(tasks, vec![11, 11, 10, 10]);
+
+        let options = MultiplyOptions::new()
+            .sequential_threshold(usize::MAX)
+            .stats(stats.clone());
+        multiply_with(&a, &b, options)?;
+        assert_eq!(stats.workers().len(), 1);
+        assert_eq!(stats.total_tasks(), 1);
+
+        // 共享线程池不会在返回前被 join，统计必须在结果发送前记录完整
+        let pool = ThreadPool::new(NUM_THREADS);
+        for _ in 0..20 {
+            let options = MultiplyOptions::new()
+                .sequential_threshold(0)
+                .pool(&pool)
+                .stats(stats.clone());
+            multiply_with(&a, &b, options)?;
+            assert_eq!(stats.total_tasks(), 6);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_clone_only_elements() -> Result<()> {
+        // 不实现 Copy 的元素类型，模拟任意精度整数
+        #[derive(Debug, Default, Clone, PartialEq)]
+        struct Big(Box<i64>);
+
+        impl Add for Big {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Big(Box::new(*self.0 + *rhs.0))
+            }
+        }
+
+        impl Zero for Big {
+            fn zero() -> Self {
+                Big(Box::new(0))
+            }
+
+            fn is_zero(&self) -> bool {
+                *self.0 == 0
+            }
+        }
+
+        impl AddAssign for Big {
+            fn add_assign(&mut self, rhs: Self) {
+                *self.0 += *rhs.0;
+            }
+        }
+
+        impl Mul for Big {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                Big(Box::new(*self.0 * *rhs.0))
+            }
+        }
+
+        let big = |values: &[i64]| values.iter().map(|&v| Big(Box::new(v))).collect::<Vec<_>>();
+        let a = Matrix::new(big(&[1, 2, 3, 4, 5, 6]), 2, 3);
+        let b = Matrix::new(big(&[7, 8, 9, 10, 11, 12]), 3, 2);
+        let expected = big(&[58, 64, 139, 154]);
+        for threshold in [0, usize::MAX] {
+            let options = MultiplyOptions::new().sequential_threshold(threshold);
+            let Ok(c) = multiply_with(&a, &b, options) else {
+                panic!("multiply should succeed");
+            };
+            assert_eq!(c.data, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_sequential_and_parallel_agree() -> Result<()> {
+        let a = Matrix::new((0..12).collect::<Vec<i64>>(), 3, 4);
+        let b = Matrix::new((0..20).collect::<Vec<i64>>(), 4, 5);
+        let sequential = multiply_with(
+            &a,
+            &b,
+            MultiplyOptions::new().sequential_threshold(usize::MAX),
+        )?;
+        let parallel = multiply_with(&a, &b, MultiplyOptions::new().sequential_threshold(0))?;
+        assert_eq!(sequential, parallel);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_traced() -> Result<()> {
+        let a = Matrix::new((0..35).collect::<Vec<i64>>(), 7, 5);
+        let b = Matrix::new((0..30).collect::<Vec<i64>>(), 5, 6);
+        let (c, events) = multiply_traced(&a, &b, MultiplyOptions::new().sequential_threshold(0))?;
+        assert_eq!(c, multiply_sequential(&a, &b)?);
+        // 默认每行一个任务，轮询分配到 4 个工作线程
+        let mut tasks = events
+            .iter()
+            .map(|e| (e.task, e.worker))
+            .collect::<Vec<_>>();
+        tasks.sort();
+        assert_eq!(
+            tasks,
+            (0..7).map(|t| (t, t % NUM_THREADS)).collect::<Vec<_>>()
+        );
+        assert!(events.iter().all(|e| e.start <= e.end));
+
+        let (_, events) = multiply_traced(&a, &b, MultiplyOptions::new())?;
+        assert_eq!(events.len(), 1);
+
+        // 共享线程池不会在返回前被 join，跟踪必须在结果发送前记录完整
+        let pool = ThreadPool::new(NUM_THREADS);
+        for _ in 0..20 {
+            let options = MultiplyOptions::new().sequential_threshold(0).pool(&pool);
+            let (_, events) = multiply_traced(&a, &b, options)?;
+            assert_eq!(events.len(), 7);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_matches_reference() -> Result<()> {
+        // 覆盖空矩阵、向量和行列数不整除线程数的形状
+        for (m, k, n) in [(0, 3, 2), (1, 1, 1), (1, 7, 1), (5, 3, 9), (13, 6, 11)] {
+            let a = Matrix::new(
+                (0..m * k).map(|v| v as i64 % 7 - 3).collect::<Vec<_>>(),
+                m,
+                k,
+            );
+            let b = Matrix::new(
+                (0..k * n).map(|v| v as i64 % 5 - 2).collect::<Vec<_>>(),
+                k,
+                n,
+            );
+            let expected = multiply_seq(&a, &b)?;
+            let options = MultiplyOptions::new().sequential_threshold(0);
+            assert_eq!(multiply_with(&a, &b, options)?, expected);
+            assert_eq!(multiply_sequential(&a, &b)?, expected);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_chunk_strategies_agree() -> Result<()> {
+        let a = Matrix::new((0..35).collect::<Vec<i64>>(), 7, 5);
+        let b = Matrix::new((0..30).collect::<Vec<i64>>(), 5, 6);
+        let expected = multiply_with(
+            &a,
+            &b,
+            MultiplyOptions::new().sequential_threshold(usize::MAX),
+        )?;
+        for strategy in [
+            ChunkStrategy::PerCell,
+            ChunkStrategy::PerRow,
+            ChunkStrategy::Blocked { tile: 0 },
+            ChunkStrategy::Blocked { tile: 3 },
+            ChunkStrategy::Blocked { tile: 16 },
+        ] {
+            let mut reports = Vec::new();
+            let options = MultiplyOptions::new()
+                .sequential_threshold(0)
+                .chunk_strategy(strategy)
+                .on_progress(|done, total| reports.push((done, total)));
+            assert_eq!(multiply_with(&a, &b, options)?, expected);
+            assert_eq!(reports.last(), Some(&(42, 42)));
+        }
+
+        // 3×3 分块切分 7×6 的结果：3 个行块 × 2 个列块，最后一行块只有 1 行
+        let blocks = chunks(ChunkStrategy::Blocked { tile: 3 }, 7, 6).collect::<Vec<_>>();
+        assert_eq!(blocks.len(), 6);
+        assert_eq!(blocks[5], (6..7, 3..6));
+        assert_eq!(chunks(ChunkStrategy::PerRow, 2, 0).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_progress() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([7, 8, 9, 10, 11, 12], 3, 2);
+        let mut reports = Vec::new();
+        let options = MultiplyOptions::new().on_progress(|done, total| reports.push((done, total)));
+        multiply_with(&a, &b, options)?;
+        assert_eq!(reports, vec![(1, 4), (2, 4), (3, 4), (4, 4)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_worker_panic() {
+        #[derive(Debug, Default, Clone, Copy)]
+        struct Poison(i32);
+
+        impl Add for Poison {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Poison(self.0 + rhs.0)
+            }
+        }
+
+        impl Zero for Poison {
+            fn zero() -> Self {
+                Poison(0)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl AddAssign for Poison {
+            fn add_assign(&mut self, rhs: Self) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl Mul for Poison {
+            type Output = Self;
+            fn mul(self, rhs: Self) -> Self {
+                if self.0 == 0 || rhs.0 == 0 {
+                    panic!("poisoned value");
+                }
+                Poison(self.0 * rhs.0)
+            }
+        }
+
+        let a = Matrix::new([Poison(1), Poison(2), Poison(0), Poison(4)], 2, 2);
+        let b = Matrix::new([Poison(1), Poison(2), Poison(3), Poison(4)], 2, 2);
+        let Err(MatrixError::WorkerFailed(err)) = multiply(&a, &b) else {
+            panic!("multiply should fail with a worker error");
+        };
+        assert_eq!(err.idx, 2);
+        assert_eq!(
+            err.kind,
+            WorkerErrorKind::Panicked("poisoned value".to_string())
+        );
+    }
+}
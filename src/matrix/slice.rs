@@ -0,0 +1,58 @@
+use core::ops::Range;
+
+use super::Matrix;
+use crate::error::MatrixError;
+
+impl<T: Clone> Matrix<T> {
+    /// 复制出 `rows × cols` 区域组成的新矩阵
+    ///
+    /// # 参数
+    /// * `rows`: 行区间
+    /// * `cols`: 列区间
+    ///
+    /// # 返回值
+    /// 区间反向或超出矩阵范围时返回 `MatrixError::IndexOutOfBounds`
+    pub fn slice(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>, MatrixError> {
+        if rows.start > rows.end
+            || rows.end > self.row
+            || cols.start > cols.end
+            || cols.end > self.col
+        {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (rows.end, cols.end),
+                shape: (self.row, self.col),
+            });
+        }
+        let (row, col) = (rows.len(), cols.len());
+        let data = rows
+            .flat_map(|i| self.data[i * self.col + cols.start..i * self.col + cols.end].iter())
+            .cloned()
+            .collect();
+        Ok(Matrix { data, row, col })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slice() -> anyhow::Result<()> {
+        let m = Matrix::new([1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12], 3, 4);
+        assert_eq!(m.slice(1..3, 1..3)?, Matrix::new([6, 7, 10, 11], 2, 2));
+        assert_eq!(m.slice(0..3, 0..4)?, m);
+        assert_eq!(m.slice(2..2, 0..4)?, Matrix::new([], 0, 4));
+
+        assert_eq!(
+            m.slice(0..4, 0..1),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (4, 1),
+                shape: (3, 4)
+            })
+        );
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = m.slice(0..1, 3..2);
+        assert!(reversed.is_err());
+        Ok(())
+    }
+}
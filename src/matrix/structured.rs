@@ -0,0 +1,79 @@
+use alloc::vec::Vec;
+
+use super::Matrix;
+
+/// 结构化矩阵的构造函数
+impl<T: Clone> Matrix<T> {
+    /// 创建 Toeplitz 矩阵，每条对角线上的元素相同
+    ///
+    /// 结果为 `first_col.len() × first_row.len()`，第 `i` 行第 `j` 列为
+    /// `i >= j` 时的 `first_col[i - j]` 或 `i < j` 时的 `first_row[j - i]`，
+    /// 因此左上角取 `first_col[0]`，`first_row[0]` 不会被使用
+    ///
+    /// # 参数
+    /// * `first_col`: 第一列
+    /// * `first_row`: 第一行
+    pub fn toeplitz(first_col: &[T], first_row: &[T]) -> Self {
+        let (row, col) = (first_col.len(), first_row.len());
+        let mut data = Vec::with_capacity(row * col);
+        for i in 0..row {
+            for j in 0..col {
+                data.push(if i >= j {
+                    first_col[i - j].clone()
+                } else {
+                    first_row[j - i].clone()
+                });
+            }
+        }
+        Matrix { data, row, col }
+    }
+
+    /// 创建循环矩阵，每一行是上一行循环右移一位
+    ///
+    /// 结果为 `n × n`，第 `i` 行第 `j` 列为 `first_row[(j - i) mod n]`
+    ///
+    /// # 参数
+    /// * `first_row`: 第一行
+    pub fn circulant(first_row: &[T]) -> Self {
+        let n = first_row.len();
+        let mut data = Vec::with_capacity(n * n);
+        for i in 0..n {
+            data.extend_from_slice(&first_row[n - i..]);
+            data.extend_from_slice(&first_row[..n - i]);
+        }
+        Matrix {
+            data,
+            row: n,
+            col: n,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toeplitz() {
+        assert_eq!(
+            Matrix::toeplitz(&[1, 2, 3], &[0, 4, 5, 6]),
+            Matrix::new([1, 4, 5, 6, 2, 1, 4, 5, 3, 2, 1, 4], 3, 4)
+        );
+        assert_eq!(Matrix::toeplitz(&[1, 2], &[9]), Matrix::new([1, 2], 2, 1));
+        assert_eq!(Matrix::<i32>::toeplitz(&[], &[1, 2]), Matrix::new([], 0, 2));
+    }
+
+    #[test]
+    fn test_circulant() {
+        assert_eq!(
+            Matrix::circulant(&[1, 2, 3]),
+            Matrix::new([1, 2, 3, 3, 1, 2, 2, 3, 1], 3, 3)
+        );
+        // 循环矩阵是第一列与第一行首尾相接的 Toeplitz 矩阵
+        assert_eq!(
+            Matrix::circulant(&[1, 2, 3, 4]),
+            Matrix::toeplitz(&[1, 4, 3, 2], &[1, 2, 3, 4])
+        );
+        assert_eq!(Matrix::<i32>::circulant(&[]), Matrix::new([], 0, 0));
+    }
+}
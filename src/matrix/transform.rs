@@ -0,0 +1,166 @@
+use alloc::vec;
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::{One, Zero};
+
+use super::Matrix;
+
+/// 图形学常用的变换矩阵，变换作用于列向量，组合时右侧的变换先生效
+impl<T: Clone + Zero + One> Matrix<T> {
+    /// 创建缩放矩阵，对角线依次为 `factors`
+    ///
+    /// # 参数
+    /// * `factors`: 每个坐标轴的缩放倍数，长度即矩阵的阶数
+    pub fn scaling(factors: &[T]) -> Self {
+        let n = factors.len();
+        let mut m = Self::zeros(n, n);
+        for (i, factor) in factors.iter().enumerate() {
+            m.data[i * n + i] = factor.clone();
+        }
+        m
+    }
+
+    /// 创建齐次坐标下的平移矩阵
+    ///
+    /// 结果为 `(n + 1) × (n + 1)`，左上角是单位矩阵，最后一列前 `n` 个元素为 `offsets`
+    ///
+    /// # 参数
+    /// * `offsets`: 每个坐标轴上的平移量
+    pub fn translation(offsets: &[T]) -> Self {
+        let n = offsets.len() + 1;
+        let mut m = Self::identity(n);
+        for (i, offset) in offsets.iter().enumerate() {
+            m.data[i * n + n - 1] = offset.clone();
+        }
+        m
+    }
+
+    /// 转换为齐次坐标下的变换矩阵
+    ///
+    /// 在右侧和下方各补一行一列零，右下角为 1，
+    /// 使旋转、缩放等线性变换可以与 `translation` 相乘组合
+    pub fn homogeneous(&self) -> Self {
+        let (row, col) = (self.row + 1, self.col + 1);
+        let mut data = vec![T::zero(); row * col];
+        for (i, src) in self.data.chunks(self.col.max(1)).take(self.row).enumerate() {
+            data[i * col..i * col + self.col].clone_from_slice(&src[..self.col]);
+        }
+        data[row * col - 1] = T::one();
+        Matrix { data, row, col }
+    }
+}
+
+/// 旋转矩阵，角度为弧度，从旋转轴正方向看去按逆时针旋转
+#[cfg(feature = "std")]
+impl<T: Float> Matrix<T> {
+    /// 创建二维旋转矩阵
+    pub fn rotation2d(theta: T) -> Self {
+        let (s, c) = theta.sin_cos();
+        Matrix {
+            data: vec![c, -s, s, c],
+            row: 2,
+            col: 2,
+        }
+    }
+
+    /// 创建绕 x 轴旋转的三维旋转矩阵
+    pub fn rotation3d_x(theta: T) -> Self {
+        let (s, c) = theta.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::rotation3d([l, o, o, o, c, -s, o, s, c])
+    }
+
+    /// 创建绕 y 轴旋转的三维旋转矩阵
+    pub fn rotation3d_y(theta: T) -> Self {
+        let (s, c) = theta.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::rotation3d([c, o, s, o, l, o, -s, o, c])
+    }
+
+    /// 创建绕 z 轴旋转的三维旋转矩阵
+    pub fn rotation3d_z(theta: T) -> Self {
+        let (s, c) = theta.sin_cos();
+        let (o, l) = (T::zero(), T::one());
+        Self::rotation3d([c, -s, o, s, c, o, o, o, l])
+    }
+
+    fn rotation3d(data: [T; 9]) -> Self {
+        Matrix {
+            data: data.into(),
+            row: 3,
+            col: 3,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "std")]
+    use crate::matrix::multiply_sequential;
+    #[cfg(feature = "std")]
+    use anyhow::Result;
+    #[cfg(feature = "std")]
+    use core::f64::consts::FRAC_PI_2;
+
+    #[cfg(feature = "std")]
+    fn assert_close(actual: &Matrix<f64>, expected: &[f64]) {
+        assert_eq!(actual.data.len(), expected.len());
+        for (a, e) in actual.data.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-12, "{:?} != {:?}", actual.data, expected);
+        }
+    }
+
+    #[test]
+    fn test_scaling_and_translation() {
+        assert_eq!(Matrix::scaling(&[2, 3]), Matrix::new([2, 0, 0, 3], 2, 2));
+        assert_eq!(
+            Matrix::translation(&[5, -1]),
+            Matrix::new([1, 0, 5, 0, 1, -1, 0, 0, 1], 3, 3)
+        );
+        assert_eq!(
+            Matrix::new([1, 2, 3, 4, 5, 6], 2, 3).homogeneous(),
+            Matrix::new([1, 2, 3, 0, 4, 5, 6, 0, 0, 0, 0, 1], 3, 4)
+        );
+        assert_eq!(
+            Matrix::<i32>::new([], 0, 0).homogeneous(),
+            Matrix::new([1], 1, 1)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_rotations() -> Result<()> {
+        let x = Matrix::new([1.0, 0.0], 2, 1);
+        assert_close(
+            &multiply_sequential(&Matrix::rotation2d(FRAC_PI_2), &x)?,
+            &[0.0, 1.0],
+        );
+
+        // 绕 x、y、z 轴各转 90°，依次把 y 转到 z、z 转到 x、x 转到 y
+        let e = |i: usize| {
+            let mut data = [0.0; 3];
+            data[i] = 1.0;
+            Matrix::new(data, 3, 1)
+        };
+        let rx = Matrix::rotation3d_x(FRAC_PI_2);
+        assert_close(&multiply_sequential(&rx, &e(1))?, &[0.0, 0.0, 1.0]);
+        let ry = Matrix::rotation3d_y(FRAC_PI_2);
+        assert_close(&multiply_sequential(&ry, &e(2))?, &[1.0, 0.0, 0.0]);
+        let rz = Matrix::rotation3d_z(FRAC_PI_2);
+        assert_close(&multiply_sequential(&rz, &e(0))?, &[0.0, 1.0, 0.0]);
+        Ok(())
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_compose_homogeneous_transforms() -> Result<()> {
+        // 先放大 2 倍、再旋转 90°、最后平移 (1, 1)
+        let transform = Matrix::translation(&[1.0, 1.0])
+            * (Matrix::rotation2d(FRAC_PI_2).homogeneous()
+                * Matrix::scaling(&[2.0, 2.0]).homogeneous());
+        let p = Matrix::new([1.0, 0.0, 1.0], 3, 1);
+        assert_close(&multiply_sequential(&transform, &p)?, &[1.0, 3.0, 1.0]);
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::thread;
+
+#[cfg(feature = "std")]
+use super::NUM_THREADS;
+use super::{Matrix, columns};
+#[cfg(feature = "std")]
+use crate::error::MatrixError;
+
+/// 原地转置时每个分块的边长
+#[cfg(feature = "std")]
+const TRANSPOSE_BLOCK: usize = 32;
+
+impl<T: Clone> Matrix<T> {
+    /// 转置，返回新矩阵
+    pub fn transpose(&self) -> Matrix<T> {
+        Matrix {
+            data: columns(self),
+            row: self.col,
+            col: self.row,
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Send> Matrix<T> {
+    /// 原地转置方阵，不分配新的缓冲区
+    ///
+    /// 矩阵按 `TRANSPOSE_BLOCK × TRANSPOSE_BLOCK` 分块，对角线上方的每个分块与其对称分块互换，
+    /// 对角线上的分块在块内互换；所有分块对平均分配给多个线程并行处理
+    ///
+    /// # 返回值
+    /// 矩阵不是方阵时返回 `MatrixError::ShapeMismatch`
+    pub fn transpose_in_place(&mut self) -> Result<(), MatrixError> {
+        let n = self.row;
+        if self.col != n {
+            return Err(MatrixError::ShapeMismatch {
+                expected: (n, n),
+                actual: (self.row, self.col),
+            });
+        }
+        let blocks = n.div_ceil(TRANSPOSE_BLOCK);
+        let pairs = (0..blocks)
+            .flat_map(|bi| (bi..blocks).map(move |bj| (bi, bj)))
+            .collect::<Vec<_>>();
+        let ptr = SendPtr(self.data.as_mut_ptr());
+        let per_thread = pairs.len().div_ceil(NUM_THREADS).max(1);
+        thread::scope(|s| {
+            for chunk in pairs.chunks(per_thread) {
+                let ptr = &ptr;
+                s.spawn(move || {
+                    for &(bi, bj) in chunk {
+                        // SAFETY: 每对对称位置 (i, j)、(j, i) 只属于一个分块对，
+                        // 各线程访问的元素互不重叠，且都在 n × n 的缓冲区内；
+                        // `self` 在作用域结束前一直被可变借用
+                        unsafe { swap_block(ptr.get(), n, bi, bj) };
+                    }
+                });
+            }
+        });
+        Ok(())
+    }
+}
+
+/// 交换分块 `(bi, bj)` 与其对称分块中的元素，`bi == bj` 时只交换对角线上方的元素
+///
+/// # Safety
+/// `data` 必须指向 `n × n` 个有效元素，且调用期间没有其它线程访问这两个分块
+#[cfg(feature = "std")]
+unsafe fn swap_block<T>(data: *mut T, n: usize, bi: usize, bj: usize) {
+    let rows = bi * TRANSPOSE_BLOCK..((bi + 1) * TRANSPOSE_BLOCK).min(n);
+    for i in rows {
+        let start = if bi == bj {
+            i + 1
+        } else {
+            bj * TRANSPOSE_BLOCK
+        };
+        for j in start..((bj + 1) * TRANSPOSE_BLOCK).min(n) {
+            // SAFETY: i、j 都小于 n，由调用方保证独占访问
+            unsafe { core::ptr::swap(data.add(i * n + j), data.add(j * n + i)) };
+        }
+    }
+}
+
+/// 可以在线程间传递的原始指针，由使用方保证各线程访问的元素互不重叠
+#[cfg(feature = "std")]
+struct SendPtr<T>(*mut T);
+
+// SAFETY: 只用于把元素交给其它线程独占访问，元素本身需要满足 Send
+#[cfg(feature = "std")]
+unsafe impl<T: Send> Sync for SendPtr<T> {}
+
+#[cfg(feature = "std")]
+impl<T> SendPtr<T> {
+    /// 通过方法取出指针，使闭包捕获整个包装而不是裸指针字段
+    fn get(&self) -> *mut T {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transpose() {
+        let m = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        assert_eq!(m.transpose(), Matrix::new([1, 4, 2, 5, 3, 6], 3, 2));
+        assert_eq!(m.transpose().transpose(), m);
+        assert_eq!(
+            Matrix::<i32>::new([], 0, 3).transpose(),
+            Matrix::new([], 3, 0)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_transpose_in_place() -> anyhow::Result<()> {
+        // 覆盖单个分块、恰好整块和带不完整边缘分块的情况
+        for n in [0, 1, 5, TRANSPOSE_BLOCK, 2 * TRANSPOSE_BLOCK + 7] {
+            let m = Matrix::new((0..n * n).map(|x| x.to_string()).collect::<Vec<_>>(), n, n);
+            let mut t = Matrix::new(m.data.clone(), n, n);
+            t.transpose_in_place()?;
+            assert_eq!(t, m.transpose());
+        }
+
+        let mut rect = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        assert_eq!(
+            rect.transpose_in_place(),
+            Err(MatrixError::ShapeMismatch {
+                expected: (2, 2),
+                actual: (2, 3)
+            })
+        );
+        Ok(())
+    }
+}
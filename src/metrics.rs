@@ -0,0 +1,150 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, RandomState};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
+
+/// 默认分片数
+pub const DEFAULT_METRICS_SHARDS: usize = 16;
+
+/// 运行时动态命名的并发指标表
+///
+/// 适用于编译期无法确定名称的计数器和仪表值，例如按工作线程或文件名统计。
+/// 键按哈希分散到多个 `RwLock<HashMap>` 分片中，已存在的键只需读锁即可原子更新，
+/// 只有首次出现的键才需要获取分片的写锁。句柄可以克隆，克隆体共享同一份数据
+#[derive(Debug, Clone)]
+pub struct CmapMetrics {
+    shards: Arc<[RwLock<HashMap<String, AtomicI64>>]>,
+    hasher: RandomState,
+}
+
+impl CmapMetrics {
+    /// 以默认分片数创建空的指标表
+    pub fn new() -> Self {
+        Self::with_shards(DEFAULT_METRICS_SHARDS)
+    }
+
+    /// 以指定分片数创建空的指标表，分片数至少为 1
+    pub fn with_shards(shards: usize) -> Self {
+        Self {
+            shards: (0..shards.max(1))
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// 计数器加一
+    pub fn inc(&self, key: &str) {
+        self.add(key, 1);
+    }
+
+    /// 计数器加上 `delta`，键不存在时从 0 开始
+    pub fn add(&self, key: &str, delta: i64) {
+        self.update(key, |value| {
+            value.fetch_add(delta, Ordering::Relaxed);
+        });
+    }
+
+    /// 将仪表值设置为 `value`
+    pub fn set(&self, key: &str, value: i64) {
+        self.update(key, |v| v.store(value, Ordering::Relaxed));
+    }
+
+    /// 读取单个指标，键不存在时返回 `None`
+    pub fn get(&self, key: &str) -> Option<i64> {
+        let shard = self
+            .shard(key)
+            .read()
+            .unwrap_or_else(PoisonError::into_inner);
+        shard.get(key).map(|v| v.load(Ordering::Relaxed))
+    }
+
+    /// 所有指标按键排序的快照
+    pub fn snapshot(&self) -> BTreeMap<String, i64> {
+        let mut snapshot = BTreeMap::new();
+        for shard in self.shards.iter() {
+            let shard = shard.read().unwrap_or_else(PoisonError::into_inner);
+            for (key, value) in shard.iter() {
+                snapshot.insert(key.clone(), value.load(Ordering::Relaxed));
+            }
+        }
+        snapshot
+    }
+
+    fn shard(&self, key: &str) -> &RwLock<HashMap<String, AtomicI64>> {
+        let idx = self.hasher.hash_one(key) as usize % self.shards.len();
+        &self.shards[idx]
+    }
+
+    fn update(&self, key: &str, f: impl FnOnce(&AtomicI64)) {
+        let shard = self.shard(key);
+        {
+            let map = shard.read().unwrap_or_else(PoisonError::into_inner);
+            if let Some(value) = map.get(key) {
+                f(value);
+                return;
+            }
+        }
+        let mut map = shard.write().unwrap_or_else(PoisonError::into_inner);
+        f(map.entry(key.to_string()).or_default());
+    }
+}
+
+impl Default for CmapMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 每行输出一个指标，格式为 `key: value`，按键排序
+impl fmt::Display for CmapMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (key, value)) in self.snapshot().iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_cmap_metrics_concurrent() {
+        let metrics = CmapMetrics::with_shards(4);
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let metrics = metrics.clone();
+                thread::spawn(move || {
+                    for _ in 0..1000 {
+                        metrics.inc("tasks");
+                        metrics.inc(&format!("worker.{}", i));
+                    }
+                    metrics.set("last", i);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(metrics.get("tasks"), Some(4000));
+        assert_eq!(metrics.get("worker.2"), Some(1000));
+        assert_eq!(metrics.get("missing"), None);
+        assert_eq!(metrics.snapshot().len(), 6);
+    }
+
+    #[test]
+    fn test_cmap_metrics_display() {
+        let metrics = CmapMetrics::new();
+        metrics.add("b", -2);
+        metrics.inc("a");
+        metrics.set("c", 7);
+        assert_eq!(metrics.to_string(), "a: 1\nb: -2\nc: 7");
+    }
+}
@@ -0,0 +1,164 @@
+use anyhow::{Result, anyhow};
+use bytemuck::Pod;
+use memmap2::Mmap;
+use std::fs::File;
+use std::marker::PhantomData;
+use std::ops::Range;
+use std::path::Path;
+
+use crate::matrix::Matrix;
+
+/// 基于内存映射文件的只读矩阵
+///
+/// 文件内容为按行优先排列的原始元素（本机字节序，无文件头）。
+/// 数据按需由操作系统换入，只有被访问的块才会占用物理内存，
+/// 因此可以处理远大于内存的矩阵
+///
+/// # 字段
+/// * `mmap`: 内存映射
+/// * `row`: 矩阵行数
+/// * `col`: 矩阵列数
+pub struct MmapMatrix<T> {
+    mmap: Mmap,
+    row: usize,
+    col: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod> Matrix<T> {
+    /// 以只读方式内存映射矩阵文件
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    /// * `row`: 行数
+    /// * `col`: 列数
+    ///
+    /// # 返回值
+    /// 文件大小与 `row * col` 个元素不符时返回错误
+    pub fn from_mmap(path: impl AsRef<Path>, row: usize, col: usize) -> Result<MmapMatrix<T>> {
+        MmapMatrix::open(path, row, col)
+    }
+}
+
+impl<T: Pod> MmapMatrix<T> {
+    /// 以只读方式内存映射矩阵文件
+    ///
+    /// # 参数
+    /// * `path`: 文件路径
+    /// * `row`: 行数
+    /// * `col`: 列数
+    ///
+    /// # 返回值
+    /// 文件大小与 `row * col` 个元素不符时返回错误
+    pub fn open(path: impl AsRef<Path>, row: usize, col: usize) -> Result<Self> {
+        let file = File::open(path)?;
+        // SAFETY: 映射为只读，调用方需保证映射期间文件不被其他进程截断或修改
+        let mmap = unsafe { Mmap::map(&file)? };
+        let expected = row
+            .checked_mul(col)
+            .and_then(|len| len.checked_mul(size_of::<T>()))
+            .ok_or_else(|| anyhow!("Mmap matrix error: {}x{} is too large", row, col))?;
+        if mmap.len() != expected {
+            return Err(anyhow!(
+                "Mmap matrix error: file has {} bytes, expected {}",
+                mmap.len(),
+                expected
+            ));
+        }
+        Ok(Self {
+            mmap,
+            row,
+            col,
+            _marker: PhantomData,
+        })
+    }
+
+    /// 矩阵行数
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// 矩阵列数
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// 全部元素的只读切片，按行优先排列
+    pub fn as_slice(&self) -> &[T] {
+        // 映射起始地址按页对齐，满足任意 Pod 类型的对齐要求
+        bytemuck::cast_slice(&self.mmap)
+    }
+
+    /// 第 `i` 行的只读切片
+    pub fn row_slice(&self, i: usize) -> &[T] {
+        &self.as_slice()[i * self.col..(i + 1) * self.col]
+    }
+
+    /// 将一个矩形块复制为普通矩阵
+    ///
+    /// # 参数
+    /// * `rows`: 行范围
+    /// * `cols`: 列范围
+    ///
+    /// # 返回值
+    /// 范围越界时返回错误
+    pub fn block(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>> {
+        if rows.start > rows.end
+            || rows.end > self.row
+            || cols.start > cols.end
+            || cols.end > self.col
+        {
+            return Err(anyhow!(
+                "Mmap matrix error: block {:?}x{:?} out of bounds for {}x{}",
+                rows,
+                cols,
+                self.row,
+                self.col
+            ));
+        }
+        let mut data = Vec::with_capacity(rows.len() * cols.len());
+        for i in rows.clone() {
+            data.extend_from_slice(&self.row_slice(i)[cols.clone()]);
+        }
+        Ok(Matrix {
+            data,
+            row: rows.len(),
+            col: cols.len(),
+        })
+    }
+
+    /// 将整个矩阵读入内存
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix {
+            data: self.as_slice().to_vec(),
+            row: self.row,
+            col: self.col,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_matrix_from_mmap() -> Result<()> {
+        let path =
+            std::env::temp_dir().join(format!("concurrency-mmap-{}.bin", std::process::id()));
+        let values = (0..12).map(|x| x as f64).collect::<Vec<_>>();
+        fs::write(&path, bytemuck::cast_slice(&values))?;
+
+        let m = Matrix::<f64>::from_mmap(&path, 3, 4)?;
+        assert_eq!(m.row_slice(1), &[4.0, 5.0, 6.0, 7.0]);
+        assert_eq!(
+            m.block(1..3, 2..4)?,
+            Matrix::new([6.0, 7.0, 10.0, 11.0], 2, 2)
+        );
+        assert!(m.block(0..4, 0..1).is_err());
+        assert!(Matrix::<f64>::from_mmap(&path, 4, 4).is_err());
+
+        fs::remove_file(&path)?;
+        Ok(())
+    }
+}
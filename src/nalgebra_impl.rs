@@ -0,0 +1,56 @@
+use nalgebra::{DMatrix, Scalar};
+
+use crate::matrix::Matrix;
+
+/// 从 nalgebra 动态矩阵转换
+///
+/// `DMatrix` 按列主序存储，转换时按行重新排列元素
+impl<T: Scalar> From<DMatrix<T>> for Matrix<T> {
+    fn from(m: DMatrix<T>) -> Self {
+        Self::from(&m)
+    }
+}
+
+impl<T: Scalar> From<&DMatrix<T>> for Matrix<T> {
+    fn from(m: &DMatrix<T>) -> Self {
+        let (row, col) = m.shape();
+        Matrix {
+            data: m.transpose().as_slice().to_vec(),
+            row,
+            col,
+        }
+    }
+}
+
+/// 转换为 nalgebra 动态矩阵，元素按行主序读入
+impl<T: Scalar> From<Matrix<T>> for DMatrix<T> {
+    fn from(m: Matrix<T>) -> Self {
+        DMatrix::from_row_iterator(m.row, m.col, m.data)
+    }
+}
+
+impl<T: Scalar> From<&Matrix<T>> for DMatrix<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        DMatrix::from_row_slice(m.row, m.col, &m.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+    use anyhow::Result;
+
+    #[test]
+    fn test_nalgebra_round_trip() -> Result<()> {
+        let a = DMatrix::from_row_slice(2, 3, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let b = DMatrix::from_row_slice(3, 2, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        let m = Matrix::from(&a);
+        assert_eq!(m, Matrix::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0], 2, 3));
+
+        let c = multiply_sequential(&m, &Matrix::from(b.clone()))?;
+        assert_eq!(DMatrix::from(&c), &a * &b);
+        assert_eq!(DMatrix::from(c), a * b);
+        Ok(())
+    }
+}
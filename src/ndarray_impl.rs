@@ -0,0 +1,73 @@
+use ndarray::{Array2, ArrayView2};
+
+use crate::matrix::Matrix;
+
+/// 从 ndarray 二维数组转换
+///
+/// 数组为行主序连续布局时直接复用其缓冲区，否则按逻辑顺序逐个克隆元素
+impl<T: Clone> From<Array2<T>> for Matrix<T> {
+    fn from(array: Array2<T>) -> Self {
+        let (row, col) = array.dim();
+        let data = if array.is_standard_layout() {
+            let (mut data, offset) = array.into_raw_vec_and_offset();
+            // 连续布局的元素从 offset 开始，前后可能还有切片遗留的元素
+            let offset = offset.unwrap_or(0);
+            data.truncate(offset + row * col);
+            data.drain(..offset);
+            data
+        } else {
+            array.iter().cloned().collect()
+        };
+        Matrix { data, row, col }
+    }
+}
+
+/// 转换为 ndarray 二维数组，直接移交缓冲区，不复制元素
+impl<T> From<Matrix<T>> for Array2<T> {
+    fn from(m: Matrix<T>) -> Self {
+        Array2::from_shape_vec((m.row, m.col), m.data).expect("matrix data matches its shape")
+    }
+}
+
+/// 借用矩阵为 ndarray 只读视图，不复制元素
+impl<'a, T> From<&'a Matrix<T>> for ArrayView2<'a, T> {
+    fn from(m: &'a Matrix<T>) -> Self {
+        ArrayView2::from_shape((m.row, m.col), &m.data).expect("matrix data matches its shape")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+    use alloc::vec;
+    use anyhow::Result;
+    use ndarray::{array, s};
+
+    #[test]
+    fn test_ndarray_round_trip() -> Result<()> {
+        let a = array![[1, 2, 3], [4, 5, 6]];
+        let b = array![[1, 2], [3, 4], [5, 6]];
+        let c = multiply_sequential(&Matrix::from(a.clone()), &Matrix::from(b.clone()))?;
+        assert_eq!(ArrayView2::from(&c), a.dot(&b));
+        assert_eq!(Array2::from(c), a.dot(&b));
+        Ok(())
+    }
+
+    #[test]
+    fn test_ndarray_non_standard_layout() {
+        let a = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        assert_eq!(
+            Matrix::from(a.t().to_owned()),
+            Matrix::new([1, 4, 7, 2, 5, 8, 3, 6, 9], 3, 3)
+        );
+
+        let mut sliced = a.clone();
+        sliced.slice_collapse(s![1.., ..]);
+        assert_eq!(Matrix::from(sliced), Matrix::new([4, 5, 6, 7, 8, 9], 2, 3));
+
+        let mut columns = a;
+        columns.slice_collapse(s![.., 1..]);
+        assert_eq!(Matrix::from(columns), Matrix::new([2, 3, 5, 6, 8, 9], 3, 2));
+    }
+}
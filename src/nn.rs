@@ -0,0 +1,269 @@
+use num_traits::{Float, One, Zero};
+use std::cmp::Ordering;
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, NUM_THREADS};
+use crate::par::{self, run_tasks};
+use crate::pool::ThreadPool;
+use crate::vector::Vector;
+
+/// 神经网络推理常用的逐元素和逐行运算
+///
+/// 元素为 `f32` 或 `f64` 等浮点类型，计算在私有线程池上并行执行，
+/// 运算中的 panic 以 `MatrixError::WorkerFailed` 返回
+impl<T> Matrix<T>
+where
+    T: Float + Send + Sync + 'static,
+{
+    /// 逐元素计算 `max(x, 0)`
+    pub fn relu(&self) -> Result<Matrix<T>, MatrixError> {
+        self.map_elements(|&x| x.max(T::zero()))
+    }
+
+    /// 逐元素计算 `1 / (1 + e^-x)`
+    pub fn sigmoid(&self) -> Result<Matrix<T>, MatrixError> {
+        self.map_elements(|&x| T::one() / (T::one() + (-x).exp()))
+    }
+
+    /// 对每一行计算 softmax，结果每行之和为 1
+    ///
+    /// 计算前先减去行内最大值，避免指数溢出。行按连续区间分配给工作线程
+    pub fn softmax_rows(&self) -> Result<Matrix<T>, MatrixError> {
+        par::map_rows(self, &ThreadPool::new(NUM_THREADS), softmax)
+    }
+
+    /// 逐元素并行映射，形状不变
+    fn map_elements(&self, f: fn(&T) -> T) -> Result<Matrix<T>, MatrixError> {
+        Ok(Matrix {
+            data: par::map(&self.data, f)?,
+            row: self.row,
+            col: self.col,
+        })
+    }
+}
+
+/// 分类任务中标签与得分矩阵之间的转换
+impl<T> Matrix<T>
+where
+    T: Clone + Zero + One + Send + 'static,
+{
+    /// 由类别标签构造独热编码矩阵
+    ///
+    /// 结果第 `i` 行只有第 `labels[i]` 列为 1，其余为 0。
+    /// 标签按连续区间分配给工作线程，每个任务生成自己负责的行
+    ///
+    /// # 参数
+    /// * `labels`: 每个样本的类别，必须小于 `num_classes`
+    /// * `num_classes`: 类别数，即结果的列数
+    ///
+    /// # 返回值
+    /// 标签超出范围时返回 `MatrixError::IndexOutOfBounds`
+    pub fn one_hot(labels: &[usize], num_classes: usize) -> Result<Matrix<T>, MatrixError> {
+        let row = labels.len();
+        if let Some((i, &label)) = labels
+            .iter()
+            .enumerate()
+            .find(|&(_, &label)| label >= num_classes)
+        {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (i, label),
+                shape: (row, num_classes),
+            });
+        }
+        let pool = ThreadPool::new(NUM_THREADS);
+        let chunk_len = row.div_ceil(pool.size()).max(1);
+        let blocks = run_tasks(
+            &pool,
+            labels.chunks(chunk_len).enumerate().map(|(i, chunk)| {
+                let chunk = chunk.to_vec();
+                (i * chunk_len * num_classes, move || {
+                    let mut out = vec![T::zero(); chunk.len() * num_classes];
+                    for (k, label) in chunk.into_iter().enumerate() {
+                        out[k * num_classes + label] = T::one();
+                    }
+                    out
+                })
+            }),
+        )?;
+        Ok(Matrix {
+            data: blocks.into_iter().flatten().collect(),
+            row,
+            col: num_classes,
+        })
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: PartialOrd + Clone + Send + 'static,
+{
+    /// 每行最大元素的列号，是 `one_hot` 的逆运算
+    ///
+    /// 有多个最大值时取最靠前的一个，与 NaN 的比较总为假；列数为 0 时每行返回 0。
+    /// 行按连续区间分配给工作线程
+    pub fn argmax_rows(&self) -> Result<Vector<usize>, MatrixError> {
+        self.arg_best_rows(|x, best| x > best)
+    }
+
+    /// 每行最小元素的列号
+    ///
+    /// 有多个最小值时取最靠前的一个，与 NaN 的比较总为假；列数为 0 时每行返回 0。
+    /// 行按连续区间分配给工作线程
+    pub fn argmin_rows(&self) -> Result<Vector<usize>, MatrixError> {
+        self.arg_best_rows(|x, best| x < best)
+    }
+
+    /// 每行最大的 `k` 个元素
+    ///
+    /// 每行先用部分选择找出前 `k` 个元素，只对这 `k` 个排序，比整行排序更省时。
+    /// 结果按值从大到小排列，值相等时列号小的在前，与 NaN 的比较视为相等；
+    /// `k` 超过列数时按列数计算。行按连续区间分配给工作线程
+    ///
+    /// # 参数
+    /// * `k`: 每行保留的元素个数
+    ///
+    /// # 返回值
+    /// 返回 `(列号, 值)` 两个 `行数 × k` 的矩阵
+    pub fn topk_rows(&self, k: usize) -> Result<(Matrix<usize>, Matrix<T>), MatrixError> {
+        let k = k.min(self.col);
+        let rows = par::reduce_rows(self, &ThreadPool::new(NUM_THREADS), move |row: &[T]| {
+            let mut entries = row.iter().cloned().enumerate().collect::<Vec<_>>();
+            let order = |a: &(usize, T), b: &(usize, T)| {
+                b.1.partial_cmp(&a.1)
+                    .unwrap_or(Ordering::Equal)
+                    .then(a.0.cmp(&b.0))
+            };
+            if k < entries.len() {
+                entries.select_nth_unstable_by(k, order);
+                entries.truncate(k);
+            }
+            entries.sort_unstable_by(order);
+            entries
+        })?;
+        let (indices, values) = rows.into_iter().flatten().unzip();
+        Ok((
+            Matrix {
+                data: indices,
+                row: self.row,
+                col: k,
+            },
+            Matrix {
+                data: values,
+                row: self.row,
+                col: k,
+            },
+        ))
+    }
+
+    /// 每行中按 `better` 比较最优的元素的列号，`better(x, best)` 为真时 `x` 取代当前最优值
+    fn arg_best_rows(&self, better: fn(&T, &T) -> bool) -> Result<Vector<usize>, MatrixError> {
+        let indices = par::reduce_rows(self, &ThreadPool::new(NUM_THREADS), move |row: &[T]| {
+            (1..row.len()).fold(
+                0,
+                |best, j| {
+                    if better(&row[j], &row[best]) { j } else { best }
+                },
+            )
+        })?;
+        Ok(Vector::new(indices))
+    }
+}
+
+/// 原地计算一行的 softmax
+fn softmax<T: Float>(row: &mut [T]) {
+    let max = row.iter().copied().fold(T::neg_infinity(), T::max);
+    let mut sum = T::zero();
+    for x in row.iter_mut() {
+        *x = (*x - max).exp();
+        sum = sum + *x;
+    }
+    for x in row.iter_mut() {
+        *x = *x / sum;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_one_hot_and_argmax() -> Result<()> {
+        let labels = [2, 0, 1, 2, 2, 1, 0];
+        let m = Matrix::<f32>::one_hot(&labels, 3)?;
+        assert_eq!((m.row, m.col), (7, 3));
+        assert_eq!(&m.data[..6], &[0.0, 0.0, 1.0, 1.0, 0.0, 0.0]);
+        assert_eq!(m.argmax_rows()?.as_slice(), labels);
+
+        let scores = Matrix::new([0.1, 0.7, 0.2, 0.5, 0.5, 0.0], 2, 3);
+        let predicted = scores.softmax_rows()?.argmax_rows()?;
+        assert_eq!(predicted.as_slice(), [1, 0]);
+
+        assert!(Matrix::<i32>::one_hot(&[], 4)?.data.is_empty());
+
+        // 并列时取最靠前的列，空行返回 0
+        let m = Matrix::new([3, 1, 1, 3, 2, 2, 9, 9, 5, -1, 5, 7], 4, 3);
+        assert_eq!(m.argmax_rows()?.as_slice(), [0, 0, 0, 2]);
+        assert_eq!(m.argmin_rows()?.as_slice(), [1, 1, 2, 0]);
+        let empty = Matrix::<i32>::new([], 2, 0);
+        assert_eq!(empty.argmin_rows()?.as_slice(), [0, 0]);
+        assert_eq!(
+            Matrix::<i32>::one_hot(&[0, 5], 3),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (1, 5),
+                shape: (2, 3)
+            })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_topk_rows() -> Result<()> {
+        let m = Matrix::new([5, 1, 9, 3, 9, 0, 2, 2, 8, 2, 7, 1], 2, 6);
+        let (indices, values) = m.topk_rows(3)?;
+        assert_eq!(indices, Matrix::new([2, 4, 0, 2, 4, 0], 2, 3));
+        assert_eq!(values, Matrix::new([9, 9, 5, 8, 7, 2], 2, 3));
+
+        let (indices, values) = m.topk_rows(10)?;
+        assert_eq!((indices.row, indices.col), (2, 6));
+        assert_eq!(&values.data[..6], &[9, 9, 5, 3, 1, 0]);
+
+        let (indices, values) = m.topk_rows(0)?;
+        assert!(indices.data.is_empty() && values.data.is_empty());
+        assert_eq!((values.row, values.col), (2, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_relu_and_sigmoid() -> Result<()> {
+        let m = Matrix::new([-2.0f32, -0.5, 0.0, 0.5, 2.0, 3.0], 2, 3);
+        assert_eq!(m.relu()?, Matrix::new([0.0, 0.0, 0.0, 0.5, 2.0, 3.0], 2, 3));
+
+        let s = Matrix::new([0.0f64, 1.0, -1.0, 40.0], 2, 2).sigmoid()?;
+        assert_eq!(s.data[0], 0.5);
+        assert!((s.data[1] + s.data[2] - 1.0).abs() < 1e-12);
+        assert!((s.data[3] - 1.0).abs() < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_softmax_rows() -> Result<()> {
+        let m = Matrix::new((0..35).map(|x| x as f64).collect::<Vec<_>>(), 7, 5);
+        let s = m.softmax_rows()?;
+        assert_eq!((s.row, s.col), (7, 5));
+        // 每行的输入只差一个常数，softmax 结果相同
+        for row in s.data.chunks(5) {
+            assert!((row.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            assert_eq!(row, &s.data[..5]);
+        }
+        assert!(s.data[..5].windows(2).all(|w| w[0] < w[1]));
+
+        // 大数值不会溢出
+        let s = Matrix::new([1000.0f32, 1000.0], 1, 2).softmax_rows()?;
+        assert_eq!(s.data, [0.5, 0.5]);
+
+        let empty = Matrix::<f32>::new([], 3, 0);
+        assert_eq!(empty.softmax_rows()?, empty);
+        Ok(())
+    }
+}
@@ -0,0 +1,210 @@
+//! NUMA 感知的矩阵乘法
+//!
+//! 按 NUMA 节点划分 `a` 的行，每个工作线程绑定到所属节点的一个核心。
+//! 结果缓冲区只分配一次且不初始化，各工作线程就地写入自己负责的行，
+//! 由执行它的线程首次写入（first-touch），使内存页落在该节点本地
+
+use num_traits::Zero;
+use std::mem;
+use std::ops::{AddAssign, Mul, Range};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+
+use crate::affinity::{available_cores, pin_current_thread};
+use crate::error::{MatrixError, WorkerError};
+use crate::matrix::{Matrix, NUM_THREADS, columns};
+use crate::vector::dot;
+
+/// NUMA 节点
+///
+/// # 字段
+/// * `id`: 节点编号
+/// * `cores`: 节点上当前进程可用的 CPU 核心编号
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumaNode {
+    pub id: usize,
+    pub cores: Vec<usize>,
+}
+
+/// 当前机器的 NUMA 节点，只保留当前进程可用的核心
+///
+/// 在 Linux 上读取 `/sys/devices/system/node`，无法获取拓扑时把所有可用核心视为一个节点；
+/// 连可用核心也无法获取时返回空列表
+pub fn nodes() -> Vec<NumaNode> {
+    let available = available_cores();
+    let mut nodes: Vec<NumaNode> = read_sysfs_nodes()
+        .into_iter()
+        .map(|(id, cores)| NumaNode {
+            id,
+            cores: cores
+                .into_iter()
+                .filter(|c| available.contains(c))
+                .collect(),
+        })
+        .filter(|node| !node.cores.is_empty())
+        .collect();
+    if nodes.is_empty() && !available.is_empty() {
+        nodes.push(NumaNode {
+            id: 0,
+            cores: available,
+        });
+    }
+    nodes
+}
+
+/// NUMA 感知的并行矩阵乘法
+///
+/// `a` 的行平均分给所有节点的核心，每个核心一个工作线程，因此各节点分到的行数与其核心数成正比。
+/// 适合单个 NUMA 节点放不下的大矩阵，小矩阵使用 `multiply` 即可。
+/// 无法获取拓扑时退化为 `NUM_THREADS` 个不绑定核心的线程
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，维度不匹配时返回 `MatrixError::DimensionMismatch`
+pub fn multiply_numa<T>(a: &Matrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync,
+{
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+    // 每个工作线程绑定的核心，按节点顺序排列，使同一节点的线程分到相邻的行
+    let nodes = nodes();
+    let cores: Vec<Option<usize>> = if nodes.is_empty() {
+        vec![None; NUM_THREADS]
+    } else {
+        nodes
+            .iter()
+            .flat_map(|node| node.cores.iter().copied().map(Some))
+            .collect()
+    };
+
+    let bt = columns(b);
+    let (k, n) = (a.col, b.col);
+    let len = a.row * n;
+    let mut data: Vec<T> = Vec::with_capacity(len);
+    let results = thread::scope(|s| {
+        let mut rest = &mut data.spare_capacity_mut()[..len];
+        let handles = split(0..a.row, cores.len())
+            .into_iter()
+            .zip(&cores)
+            .map(|(rows, &core)| {
+                let (out, tail) = mem::take(&mut rest).split_at_mut(rows.len() * n);
+                rest = tail;
+                let (a, bt) = (&a.data, &bt);
+                s.spawn(move || {
+                    if let Some(core) = core {
+                        pin_current_thread(core);
+                    }
+                    let first = rows.start * n;
+                    panic::catch_unwind(AssertUnwindSafe(|| {
+                        for (r, i) in rows.enumerate() {
+                            let row = &a[i * k..(i + 1) * k];
+                            for j in 0..n {
+                                out[r * n + j].write(dot(row, &bt[j * k..(j + 1) * k]));
+                            }
+                        }
+                    }))
+                    .map_err(|payload| WorkerError::panicked(first, payload))
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|h| {
+                h.join()
+                    .unwrap_or_else(|payload| Err(WorkerError::panicked(0, payload)))
+            })
+            .collect::<Vec<_>>()
+    });
+    if let Some(err) = results.into_iter().find_map(Result::err) {
+        return Err(err.into());
+    }
+    // SAFETY: 各工作线程都成功返回，`len` 个元素已按互不重叠的行区间全部写入
+    unsafe { data.set_len(len) };
+    Ok(Matrix {
+        data,
+        row: a.row,
+        col: n,
+    })
+}
+
+/// 将区间平均分成 `parts` 段，前面的段最多多一个元素
+fn split(range: Range<usize>, parts: usize) -> Vec<Range<usize>> {
+    let len = range.len();
+    let parts = parts.max(1);
+    (0..parts)
+        .map(|p| range.start + len * p / parts..range.start + len * (p + 1) / parts)
+        .collect()
+}
+
+#[cfg(target_os = "linux")]
+fn read_sysfs_nodes() -> Vec<(usize, Vec<usize>)> {
+    let Ok(entries) = std::fs::read_dir("/sys/devices/system/node") else {
+        return Vec::new();
+    };
+    let mut nodes: Vec<_> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let id = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix("node")?
+                .parse()
+                .ok()?;
+            let list = std::fs::read_to_string(entry.path().join("cpulist")).ok()?;
+            Some((id, parse_cpulist(&list)?))
+        })
+        .collect();
+    nodes.sort();
+    nodes
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_sysfs_nodes() -> Vec<(usize, Vec<usize>)> {
+    Vec::new()
+}
+
+/// 解析 `0-3,8,10-11` 形式的核心列表
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn parse_cpulist(list: &str) -> Option<Vec<usize>> {
+    let mut cores = Vec::new();
+    for part in list.trim().split(',').filter(|p| !p.is_empty()) {
+        match part.split_once('-') {
+            Some((lo, hi)) => cores.extend(lo.parse::<usize>().ok()?..=hi.parse().ok()?),
+            None => cores.push(part.parse().ok()?),
+        }
+    }
+    Some(cores)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+    use anyhow::Result;
+
+    #[test]
+    fn test_parse_cpulist() {
+        assert_eq!(
+            parse_cpulist("0-3,8,10-11\n"),
+            Some(vec![0, 1, 2, 3, 8, 10, 11])
+        );
+        assert_eq!(parse_cpulist(""), Some(vec![]));
+        assert_eq!(parse_cpulist("a-b"), None);
+    }
+
+    #[test]
+    fn test_multiply_numa() -> Result<()> {
+        let a = Matrix::new((0..35).collect::<Vec<i64>>(), 7, 5);
+        let b = Matrix::new((0..15).collect::<Vec<i64>>(), 5, 3);
+        assert_eq!(multiply_numa(&a, &b)?, multiply_sequential(&a, &b)?);
+        assert!(multiply_numa(&a, &a).is_err());
+
+        let empty = Matrix::new(Vec::<i64>::new(), 5, 0);
+        assert_eq!(multiply_numa(&a, &empty)?, multiply_sequential(&a, &empty)?);
+        Ok(())
+    }
+}
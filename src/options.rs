@@ -0,0 +1,185 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use crate::cancel::CancelToken;
+use crate::pool::{DEFAULT_QUEUE_CAPACITY, Priority, ThreadPool};
+use crate::scheduler::Scheduler;
+use crate::stats::MultiplyStats;
+use crate::trace::MultiplyTrace;
+
+/// 默认的串行计算阈值（乘加次数），约等于两个 64×64 矩阵相乘
+pub const DEFAULT_SEQUENTIAL_THRESHOLD: usize = 64 * 64 * 64;
+
+/// 并行乘法的任务粒度
+///
+/// 决定结果矩阵如何切分为提交给线程池的任务：
+/// 粒度越细调度越均衡，但复制行列数据和通道通信的开销也越大
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ChunkStrategy {
+    /// 每个结果单元一个任务，开销最大，便于演示最基本的消息传递
+    PerCell,
+    /// 每行结果一个任务，行数据只复制一次
+    #[default]
+    PerRow,
+    /// 每个 `tile × tile` 的结果分块一个任务，边缘分块可能更小，`tile` 为 0 时按 1 处理
+    Blocked { tile: usize },
+}
+
+/// 进度回调类型，参数依次为已完成的结果单元数和总单元数
+pub type ProgressFn<'a> = Box<dyn FnMut(usize, usize) + 'a>;
+
+/// 矩阵乘法的可选配置
+///
+/// 通过构建器方法逐项设置，未设置的选项保持默认行为：
+/// 不可取消、不限时、不报告进度，小矩阵走串行计算
+pub struct MultiplyOptions<'a> {
+    pub(crate) cancel: Option<CancelToken>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) progress: Option<ProgressFn<'a>>,
+    pub(crate) sequential_threshold: usize,
+    pub(crate) deterministic: bool,
+    pub(crate) channel_capacity: usize,
+    pub(crate) pool: Option<&'a ThreadPool>,
+    pub(crate) priority: Priority,
+    pub(crate) stats: Option<MultiplyStats>,
+    pub(crate) trace: Option<MultiplyTrace>,
+    pub(crate) pin_workers: bool,
+    pub(crate) scheduler: Option<Arc<dyn Scheduler>>,
+    pub(crate) chunk_strategy: ChunkStrategy,
+}
+
+impl Default for MultiplyOptions<'_> {
+    fn default() -> Self {
+        Self {
+            cancel: None,
+            timeout: None,
+            progress: None,
+            sequential_threshold: DEFAULT_SEQUENTIAL_THRESHOLD,
+            deterministic: false,
+            channel_capacity: DEFAULT_QUEUE_CAPACITY,
+            pool: None,
+            priority: Priority::Normal,
+            stats: None,
+            trace: None,
+            pin_workers: false,
+            scheduler: None,
+            chunk_strategy: ChunkStrategy::default(),
+        }
+    }
+}
+
+impl<'a> MultiplyOptions<'a> {
+    /// 创建默认配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置取消令牌
+    pub fn cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel = Some(token);
+        self
+    }
+
+    /// 设置收集结果的最长等待时间
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// 设置进度回调
+    ///
+    /// 每收到一个任务的结果调用一次，参数为 `(已完成单元数, 总单元数)`，
+    /// 串行计算时每个单元调用一次。回调在调用 `multiply_with` 的线程上执行
+    pub fn on_progress(mut self, progress: impl FnMut(usize, usize) + 'a) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// 设置串行计算阈值
+    ///
+    /// 乘加次数（`a.row * a.col * b.col`）小于该值时直接在当前线程串行计算，
+    /// 省去线程和通道的开销；设为 0 则总是并行计算
+    pub fn sequential_threshold(mut self, threshold: usize) -> Self {
+        self.sequential_threshold = threshold;
+        self
+    }
+
+    /// 设置确定性调度模式
+    ///
+    /// 开启后任务固定按 `idx % 线程数` 分配到工作线程，并严格按索引顺序依次执行，
+    /// 结果按索引顺序收集，使每次运行的调度过程完全一致，便于复现并发问题。
+    /// 该模式下各工作线程轮流执行，不再有真正的并行
+    pub fn deterministic(mut self, deterministic: bool) -> Self {
+        self.deterministic = deterministic;
+        self
+    }
+
+    /// 设置每个工作线程任务队列的容量
+    ///
+    /// 队列满时分发线程阻塞，同时先收集已完成的结果，
+    /// 因此任意时刻在途的任务数不超过 `容量 × 线程数`。
+    /// 输入只复制一份供所有任务共享，任务只携带行列范围，在途的只有各分块的结果
+    pub fn channel_capacity(mut self, capacity: usize) -> Self {
+        self.channel_capacity = capacity;
+        self
+    }
+
+    /// 在共享的线程池上执行计算
+    ///
+    /// 未设置时每次乘法创建并在结束时回收一个私有线程池。
+    /// 此时队列容量由线程池自身决定，`channel_capacity` 只控制在途结果窗口。
+    /// 确定性模式总是使用私有线程池，以免与其它任务交错
+    pub fn pool(mut self, pool: &'a ThreadPool) -> Self {
+        self.pool = Some(pool);
+        self
+    }
+
+    /// 设置任务优先级
+    ///
+    /// 在共享线程池上，高优先级的乘法任务会插到已排队的普通任务之前
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// 开启执行统计
+    ///
+    /// 乘法结束后可以从传入的句柄（或它的克隆体）读取每个工作线程的
+    /// 任务数、计算耗时、空闲时间和排队等待时间；串行计算时只有一个工作线程
+    pub fn stats(mut self, stats: MultiplyStats) -> Self {
+        self.stats = Some(stats);
+        self
+    }
+
+    /// 开启调度跟踪
+    ///
+    /// 每个任务执行完毕时记录任务索引、工作线程编号以及开始和结束时刻，
+    /// 乘法结束后可以从传入的句柄（或它的克隆体）读取；串行计算时只记录一个任务
+    pub fn trace(mut self, trace: MultiplyTrace) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    /// 设置任务调度策略
+    ///
+    /// 未设置时按任务索引轮询分配（`RoundRobin`）。确定性模式下总是按索引轮询
+    pub fn scheduler(mut self, scheduler: Arc<dyn Scheduler>) -> Self {
+        self.scheduler = Some(scheduler);
+        self
+    }
+
+    /// 设置任务粒度，默认每行结果一个任务
+    pub fn chunk_strategy(mut self, strategy: ChunkStrategy) -> Self {
+        self.chunk_strategy = strategy;
+        self
+    }
+
+    /// 将私有线程池的工作线程绑定到 CPU 核心
+    ///
+    /// 只作用于每次乘法创建的私有线程池，共享线程池需要用 `ThreadPool::pinned` 创建
+    #[cfg(feature = "affinity")]
+    pub fn pin_workers(mut self, pin: bool) -> Self {
+        self.pin_workers = pin;
+        self
+    }
+}
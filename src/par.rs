@@ -0,0 +1,601 @@
+use num_traits::Zero;
+use std::mem;
+use std::ops::AddAssign;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use crate::channel::oneshot;
+use crate::error::{MatrixError, WorkerError};
+use crate::matrix::{Matrix, NUM_THREADS, columns};
+use crate::pool::{Job, ThreadPool};
+
+/// 并行地对每个元素调用 `f`，结果顺序与输入一致
+///
+/// 输入按连续区间平均分配给私有线程池的工作线程，每个区间由一个任务计算
+///
+/// # 参数
+/// * `slice`: 输入元素
+/// * `f`: 映射函数
+///
+/// # 返回值
+/// 返回Result<Vec<R>, MatrixError>，`f` panic 时返回 `MatrixError::WorkerFailed`
+pub fn map<T, R, F>(slice: &[T], f: F) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(&T) -> R + Send + Sync + 'static,
+{
+    map_on(slice, f, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上并行映射
+///
+/// # 参数
+/// * `slice`: 输入元素
+/// * `f`: 映射函数
+/// * `pool`: 执行计算的线程池
+pub fn map_on<T, R, F>(slice: &[T], f: F, pool: &ThreadPool) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(&T) -> R + Send + Sync + 'static,
+{
+    let chunks = run_chunks(slice, pool, move |chunk| {
+        chunk.iter().map(&f).collect::<Vec<_>>()
+    })?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// 并行归约
+///
+/// 每个区间从 `identity` 开始按顺序折叠，再按区间顺序合并各区间的结果，
+/// 因此 `f` 需要满足结合律，`identity` 需要是 `f` 的单位元
+///
+/// # 参数
+/// * `slice`: 输入元素
+/// * `identity`: 单位元，输入为空时直接返回
+/// * `f`: 归约函数
+///
+/// # 返回值
+/// 返回Result<T, MatrixError>，`f` panic 时返回 `MatrixError::WorkerFailed`
+pub fn reduce<T, F>(slice: &[T], identity: T, f: F) -> Result<T, MatrixError>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(T, T) -> T + Send + Sync + 'static,
+{
+    reduce_on(slice, identity, f, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上并行归约
+///
+/// # 参数
+/// * `slice`: 输入元素
+/// * `identity`: 单位元
+/// * `f`: 归约函数
+/// * `pool`: 执行计算的线程池
+pub fn reduce_on<T, F>(slice: &[T], identity: T, f: F, pool: &ThreadPool) -> Result<T, MatrixError>
+where
+    T: Clone + Send + Sync + 'static,
+    F: Fn(T, T) -> T + Send + Sync + 'static,
+{
+    let f = Arc::new(f);
+    let partials = run_chunks(slice, pool, {
+        let f = f.clone();
+        let identity = identity.clone();
+        move |chunk| chunk.into_iter().fold(identity.clone(), |acc, v| f(acc, v))
+    })?;
+    Ok(partials.into_iter().fold(identity, |acc, v| f(acc, v)))
+}
+
+/// 并行前缀和（包含当前元素）
+///
+/// 两遍分块扫描：第一遍各区间在线程池上独立计算局部前缀和，
+/// 随后按顺序累加各区间的总和得到每个区间的偏移量，
+/// 第二遍再在线程池上把偏移量加到各区间的每个元素上
+///
+/// # 参数
+/// * `slice`: 输入元素
+///
+/// # 返回值
+/// 返回Result<Vec<T>, MatrixError>，第 `i` 个元素为 `slice[0..=i]` 的和
+pub fn prefix_sum<T>(slice: &[T]) -> Result<Vec<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Send + 'static,
+{
+    prefix_sum_on(slice, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算并行前缀和
+///
+/// # 参数
+/// * `slice`: 输入元素
+/// * `pool`: 执行计算的线程池
+pub fn prefix_sum_on<T>(slice: &[T], pool: &ThreadPool) -> Result<Vec<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Send + 'static,
+{
+    let blocks = run_chunks(slice, pool, |mut chunk| {
+        for i in 1..chunk.len() {
+            let prev = chunk[i - 1].clone();
+            chunk[i] += prev;
+        }
+        chunk
+    })?;
+
+    // 每个区间的偏移量为之前所有区间的总和，第一个区间不需要调整
+    let mut offset = T::zero();
+    let mut tasks = Vec::with_capacity(blocks.len());
+    let mut start = 0;
+    for mut block in blocks {
+        let idx = start;
+        start += block.len();
+        let block_offset = offset.clone();
+        if let Some(last) = block.last() {
+            offset += last.clone();
+        }
+        tasks.push((idx, move || {
+            if !block_offset.is_zero() {
+                for value in &mut block {
+                    *value += block_offset.clone();
+                }
+            }
+            block
+        }));
+    }
+    Ok(run_tasks(pool, tasks)?.into_iter().flatten().collect())
+}
+
+/// 并行稳定排序
+///
+/// 先把输入按连续区间分配给私有线程池的工作线程分别原地排序，
+/// 再逐轮两两合并相邻的有序区间，每次合并也作为一个任务在线程池上执行，
+/// 直到整个 `slice` 有序。元素只在 `slice` 内部交换，不需要 `Clone`
+///
+/// # 参数
+/// * `slice`: 待排序的元素
+///
+/// # 返回值
+/// 返回Result<(), MatrixError>，比较时 panic 返回 `MatrixError::WorkerFailed`，
+/// 此时 `slice` 仍包含原有的全部元素，但顺序未定义
+pub fn sort<T>(slice: &mut [T]) -> Result<(), MatrixError>
+where
+    T: Ord + Send,
+{
+    sort_on(slice, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上并行稳定排序
+///
+/// 调用方会阻塞等待 `pool` 上的任务，因此不能在 `pool` 自己的工作线程中调用：
+/// 排序任务可能排在当前任务之后，永远得不到执行而导致死锁
+///
+/// # 参数
+/// * `slice`: 待排序的元素
+/// * `pool`: 执行计算的线程池
+pub fn sort_on<T>(slice: &mut [T], pool: &ThreadPool) -> Result<(), MatrixError>
+where
+    T: Ord + Send,
+{
+    let chunk_len = slice.len().div_ceil(pool.size()).max(1);
+    run_scoped(
+        pool,
+        slice
+            .chunks_mut(chunk_len)
+            .enumerate()
+            .map(|(i, chunk)| (i * chunk_len, move || chunk.sort())),
+    )?;
+    let mut width = chunk_len;
+    while width < slice.len() {
+        let pair_len = width * 2;
+        run_scoped(
+            pool,
+            slice
+                .chunks_mut(pair_len)
+                .enumerate()
+                .map(|(i, pair)| (i * pair_len, move || merge(pair, width))),
+        )?;
+        width = pair_len;
+    }
+    Ok(())
+}
+
+/// 将 `slice` 按连续区间分配给线程池，按区间顺序返回每个任务的结果
+///
+/// 每个任务只复制自己负责的区间，任务中的 panic 以 `WorkerError` 返回，
+/// 其 `idx` 为区间的起始下标
+pub(crate) fn run_chunks<T, R, F>(
+    slice: &[T],
+    pool: &ThreadPool,
+    job: F,
+) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(Vec<T>) -> R + Send + Sync + 'static,
+{
+    let job = Arc::new(job);
+    let chunk_len = slice.len().div_ceil(pool.size()).max(1);
+    run_tasks(
+        pool,
+        slice.chunks(chunk_len).enumerate().map(|(i, chunk)| {
+            let chunk = chunk.to_vec();
+            let job = job.clone();
+            (i * chunk_len, move || job(chunk))
+        }),
+    )
+}
+
+/// 将矩阵的行按连续区间分配给线程池，在每一行上原地调用 `f`，返回形状不变的新矩阵
+///
+/// 任务中的 panic 以 `WorkerError` 返回，其 `idx` 为区间首个元素的下标
+pub(crate) fn map_rows<T, F>(
+    m: &Matrix<T>,
+    pool: &ThreadPool,
+    f: F,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    F: Fn(&mut [T]) + Send + Sync + 'static,
+{
+    let col = m.col;
+    if col == 0 {
+        return Ok(Matrix {
+            data: Vec::new(),
+            row: m.row,
+            col,
+        });
+    }
+    let f = Arc::new(f);
+    let rows_per_task = m.row.div_ceil(pool.size()).max(1);
+    let chunks = run_tasks(
+        pool,
+        m.data
+            .chunks(rows_per_task * col)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let mut chunk = chunk.to_vec();
+                let f = f.clone();
+                (i * rows_per_task * col, move || {
+                    chunk.chunks_mut(col).for_each(|row| f(row));
+                    chunk
+                })
+            }),
+    )?;
+    Ok(Matrix {
+        data: chunks.into_iter().flatten().collect(),
+        row: m.row,
+        col,
+    })
+}
+
+/// 将矩阵的行按连续区间分配给线程池，按行的顺序返回在每一行上调用 `f` 的结果
+///
+/// 任务中的 panic 以 `WorkerError` 返回，其 `idx` 为区间首行的行号
+pub(crate) fn reduce_rows<T, R, F>(
+    m: &Matrix<T>,
+    pool: &ThreadPool,
+    f: F,
+) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(&[T]) -> R + Send + Sync + 'static,
+{
+    let col = m.col;
+    if col == 0 {
+        return Ok((0..m.row).map(|_| f(&[])).collect());
+    }
+    let f = Arc::new(f);
+    let rows_per_task = m.row.div_ceil(pool.size()).max(1);
+    let chunks = run_tasks(
+        pool,
+        m.data
+            .chunks(rows_per_task * col)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let chunk = chunk.to_vec();
+                let f = f.clone();
+                (i * rows_per_task, move || {
+                    chunk.chunks(col).map(|row| f(row)).collect::<Vec<_>>()
+                })
+            }),
+    )?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// 将矩阵的列按连续区间分配给线程池，按列的顺序返回在每一列上调用 `f` 的结果
+///
+/// 任务中的 panic 以 `WorkerError` 返回，其 `idx` 为区间首列的列号
+pub(crate) fn map_cols<T, R, F>(
+    m: &Matrix<T>,
+    pool: &ThreadPool,
+    f: F,
+) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(&[T]) -> R + Send + Sync + 'static,
+{
+    let row = m.row;
+    if row == 0 {
+        return Ok((0..m.col).map(|_| f(&[])).collect());
+    }
+    let f = Arc::new(f);
+    let cols_per_task = m.col.div_ceil(pool.size()).max(1);
+    let chunks = run_tasks(
+        pool,
+        columns(m)
+            .chunks(cols_per_task * row)
+            .enumerate()
+            .map(|(i, chunk)| {
+                let chunk = chunk.to_vec();
+                let f = f.clone();
+                (i * cols_per_task, move || {
+                    chunk.chunks(row).map(|col| f(col)).collect::<Vec<_>>()
+                })
+            }),
+    )?;
+    Ok(chunks.into_iter().flatten().collect())
+}
+
+/// 将任务依次分配给线程池的工作线程，按提交顺序返回每个任务的结果
+///
+/// `tasks` 中每一项为 `(idx, 任务)`，任务 panic 时以 `idx` 创建 `WorkerError`
+pub(crate) fn run_tasks<R, F>(
+    pool: &ThreadPool,
+    tasks: impl IntoIterator<Item = (usize, F)>,
+) -> Result<Vec<R>, MatrixError>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let mut pending = Vec::new();
+    for (worker, (idx, job)) in tasks.into_iter().enumerate() {
+        let (tx, rx) = oneshot::channel();
+        let task = move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(job))
+                .map_err(|payload| WorkerError::panicked(idx, payload));
+            // 调用方已放弃等待时发送失败是正常情况
+            let _ = tx.send(result);
+        };
+        if pool.execute_on(worker, task).is_err() {
+            return Err(WorkerError::disconnected(idx).into());
+        }
+        pending.push((idx, rx));
+    }
+
+    pending
+        .into_iter()
+        .map(|(idx, rx)| match rx.recv() {
+            Ok(result) => result.map_err(MatrixError::from),
+            Err(_) => Err(WorkerError::disconnected(idx).into()),
+        })
+        .collect()
+}
+
+/// 在线程池上执行借用调用方数据的任务，所有已提交的任务结束后才返回
+///
+/// 任务中的 panic 以 `WorkerError` 返回，其 `idx` 为任务给出的下标，
+/// 多个任务失败时返回提交顺序最靠前的一个。
+/// 调用方会阻塞等待任务，因此不能在同一线程池的工作线程中调用，否则可能死锁
+fn run_scoped<'a, F>(
+    pool: &ThreadPool,
+    tasks: impl IntoIterator<Item = (usize, F)>,
+) -> Result<(), MatrixError>
+where
+    F: FnOnce() + Send + 'a,
+{
+    // 必须在提交第一个任务之前创建，函数以任何方式退出时都会先等待已提交的任务
+    let mut scope = Scope::default();
+    let mut failed = None;
+    for (worker, (idx, job)) in tasks.into_iter().enumerate() {
+        let (tx, rx) = oneshot::channel();
+        let task: Box<dyn FnOnce() + Send + 'a> = Box::new(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(job))
+                .map_err(|payload| WorkerError::panicked(idx, payload));
+            let _ = tx.send(result);
+        });
+        // SAFETY: 任务只会在执行完毕或未执行就被丢弃后才释放发送端，
+        // 而 `scope` 在离开作用域时（包括提前返回和 panic 展开）会等待每个已提交任务的
+        // 发送端被释放，因此任务借用的数据在任务结束前一直有效；
+        // 提交失败时任务已在 `execute_on` 内被丢弃
+        let task = unsafe { mem::transmute::<Box<dyn FnOnce() + Send + 'a>, Job>(task) };
+        if pool.execute_on(worker, task).is_err() {
+            failed = Some(WorkerError::disconnected(idx));
+            break;
+        }
+        scope.pending.push((idx, rx));
+    }
+
+    // 先等待全部任务结束，再报告错误
+    match scope.wait().or(failed) {
+        Some(e) => Err(e.into()),
+        None => Ok(()),
+    }
+}
+
+/// `run_scoped` 已提交任务的结果接收端，析构时等待全部任务结束
+#[derive(Default)]
+struct Scope {
+    pending: Vec<(usize, oneshot::Receiver<Result<(), WorkerError>>)>,
+}
+
+impl Scope {
+    /// 等待全部已提交的任务结束，返回提交顺序最靠前的错误
+    fn wait(&mut self) -> Option<WorkerError> {
+        let mut first = None;
+        for (idx, rx) in self.pending.drain(..) {
+            if let Err(e) = rx
+                .recv()
+                .unwrap_or_else(|_| Err(WorkerError::disconnected(idx)))
+            {
+                first.get_or_insert(e);
+            }
+        }
+        first
+    }
+}
+
+impl Drop for Scope {
+    fn drop(&mut self) {
+        self.wait();
+    }
+}
+
+/// 稳定地合并 `slice[..mid]` 与 `slice[mid..]` 两个有序区间，相等的元素前一区间的排在前面
+///
+/// 先只通过引用比较得到合并后的顺序，再按该顺序原地交换元素，
+/// 因此比较时 panic 不会移动任何元素
+fn merge<T: Ord>(slice: &mut [T], mid: usize) {
+    let mid = mid.min(slice.len());
+    let (a, b) = slice.split_at(mid);
+    let mut order = Vec::with_capacity(slice.len());
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if b[j] < a[i] {
+            order.push(mid + j);
+            j += 1;
+        } else {
+            order.push(i);
+            i += 1;
+        }
+    }
+    order.extend(i..mid);
+    order.extend(mid + j..slice.len());
+
+    // `order[k]` 为应当放到位置 k 的元素的原位置，沿置换的每个环依次交换
+    for start in 0..order.len() {
+        let mut cur = start;
+        while order[cur] != start {
+            let src = order[cur];
+            slice.swap(cur, src);
+            order[cur] = cur;
+            cur = src;
+        }
+        order[cur] = cur;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_par_map() -> Result<()> {
+        let input: Vec<i64> = (0..101).collect();
+        let squares = map(&input, |v| v * v)?;
+        assert_eq!(squares, input.iter().map(|v| v * v).collect::<Vec<_>>());
+        assert!(map(&[] as &[i64], |v| *v)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_reduce() -> Result<()> {
+        let input: Vec<u64> = (1..=1000).collect();
+        assert_eq!(reduce(&input, 0, |a, b| a + b)?, 500500);
+        let pool = ThreadPool::new(3);
+        let words: Vec<String> = ["a", "b", "c", "d", "e"].map(String::from).to_vec();
+        assert_eq!(
+            reduce_on(&words, String::new(), |a, b| a + &b, &pool)?,
+            "abcde"
+        );
+        assert_eq!(reduce(&[] as &[u64], 7, |a, b| a + b)?, 7);
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_prefix_sum() -> Result<()> {
+        let input: Vec<u64> = (1..=10).collect();
+        assert_eq!(prefix_sum(&input)?, [1, 3, 6, 10, 15, 21, 28, 36, 45, 55]);
+        let input: Vec<f64> = vec![0.5; 7];
+        let sums = prefix_sum_on(&input, &ThreadPool::new(3))?;
+        assert_eq!(sums, (1..=7).map(|i| i as f64 * 0.5).collect::<Vec<_>>());
+        assert!(prefix_sum::<i32>(&[])?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_sort() -> Result<()> {
+        let mut values: Vec<i64> = (0..1001).map(|i| (i * 7919) % 1009 - 500).collect();
+        let mut expected = values.clone();
+        expected.sort();
+        sort(&mut values)?;
+        assert_eq!(values, expected);
+
+        // 稳定性：键相同的元素保持原有的相对顺序
+        let mut pairs: Vec<(u8, usize)> = (0..100).map(|i| ((i % 3) as u8, i)).collect();
+        sort_on(&mut pairs, &ThreadPool::new(3))?;
+        assert!(pairs.windows(2).all(|w| w[0].0 < w[1].0 || w[0].1 < w[1].1));
+
+        let mut empty: [i32; 0] = [];
+        sort(&mut empty)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_sort_stable() -> Result<()> {
+        // 只按键比较，键相同而负载不同的元素必须保持原有顺序
+        #[derive(Debug)]
+        struct Keyed(u8, usize);
+
+        impl PartialEq for Keyed {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Keyed {}
+        impl PartialOrd for Keyed {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Keyed {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut values: Vec<Keyed> = (0..1000).map(|i| Keyed((i * 7 % 5) as u8, i)).collect();
+        sort_on(&mut values, &ThreadPool::new(3))?;
+        assert!(
+            values
+                .windows(2)
+                .all(|w| w[0].0 < w[1].0 || (w[0].0 == w[1].0 && w[0].1 < w[1].1))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_scoped_waits_when_unwinding() {
+        let pool = ThreadPool::new(2);
+        let mut data = vec![0u8; 4];
+        // 提交第一个任务后迭代器 panic，展开前必须等该任务写完借用的区间
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            run_scoped(
+                &pool,
+                data.chunks_mut(2).enumerate().map(|(i, chunk)| {
+                    if i == 1 {
+                        panic!("stop");
+                    }
+                    (i * 2, move || {
+                        std::thread::sleep(std::time::Duration::from_millis(50));
+                        chunk.fill(1);
+                    })
+                }),
+            )
+        }));
+        assert!(result.is_err());
+        assert_eq!(data, [1, 1, 0, 0]);
+    }
+
+    #[test]
+    fn test_par_map_panic() {
+        let input: Vec<i32> = (0..8).collect();
+        let err = map(&input, |v| if *v == 5 { panic!("bad") } else { *v }).unwrap_err();
+        assert!(matches!(
+            err,
+            MatrixError::WorkerFailed(WorkerError { idx: 4, .. })
+        ));
+    }
+}
@@ -0,0 +1,174 @@
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use num_traits::{One, Zero};
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// 置换
+///
+/// 以下标数组表示置换矩阵 `P`：`P` 的第 `i` 行只有第 `perm[i]` 列为 1。
+/// 直接按下标搬移行或列，只需 O(元素数) 的复制，不必做完整的矩阵乘法
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Permutation {
+    perm: Vec<usize>,
+}
+
+impl Permutation {
+    /// 创建 `n` 阶恒等置换
+    pub fn identity(n: usize) -> Self {
+        Self {
+            perm: (0..n).collect(),
+        }
+    }
+
+    /// 由下标数组创建置换
+    ///
+    /// # 参数
+    /// * `perm`: `0..n` 的一个排列
+    ///
+    /// # 返回值
+    /// 下标越界或重复时返回 `MatrixError::InvalidPermutation`
+    pub fn new(perm: Vec<usize>) -> Result<Self, MatrixError> {
+        let n = perm.len();
+        let mut seen = vec![false; n];
+        for (i, &p) in perm.iter().enumerate() {
+            if p >= n {
+                return Err(MatrixError::InvalidPermutation(format!(
+                    "perm[{}] = {} is out of range for length {}",
+                    i, p, n
+                )));
+            }
+            if core::mem::replace(&mut seen[p], true) {
+                return Err(MatrixError::InvalidPermutation(format!(
+                    "{} appears more than once",
+                    p
+                )));
+            }
+        }
+        Ok(Self { perm })
+    }
+
+    /// 置换的阶数
+    pub fn len(&self) -> usize {
+        self.perm.len()
+    }
+
+    /// 是否为零阶置换
+    pub fn is_empty(&self) -> bool {
+        self.perm.is_empty()
+    }
+
+    /// 下标数组，第 `i` 个元素为 `P` 第 `i` 行中 1 所在的列
+    pub fn as_slice(&self) -> &[usize] {
+        &self.perm
+    }
+
+    /// 逆置换，对应 `Pᵀ`
+    pub fn inverse(&self) -> Self {
+        let mut inverse = vec![0; self.perm.len()];
+        for (i, &p) in self.perm.iter().enumerate() {
+            inverse[p] = i;
+        }
+        Self { perm: inverse }
+    }
+
+    /// 计算 `P·A`：结果的第 `i` 行为 `m` 的第 `perm[i]` 行
+    ///
+    /// # 返回值
+    /// `m` 的行数与阶数不一致时返回 `MatrixError::ShapeMismatch`
+    pub fn apply_rows<T: Clone>(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if m.row != self.perm.len() {
+            return Err(MatrixError::ShapeMismatch {
+                expected: (self.perm.len(), m.col),
+                actual: (m.row, m.col),
+            });
+        }
+        let mut data = Vec::with_capacity(m.data.len());
+        for &p in &self.perm {
+            data.extend_from_slice(&m.data[p * m.col..(p + 1) * m.col]);
+        }
+        Ok(Matrix {
+            data,
+            row: m.row,
+            col: m.col,
+        })
+    }
+
+    /// 计算 `A·Pᵀ`：结果的第 `j` 列为 `m` 的第 `perm[j]` 列
+    ///
+    /// # 返回值
+    /// `m` 的列数与阶数不一致时返回 `MatrixError::ShapeMismatch`
+    pub fn apply_cols<T: Clone>(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        if m.col != self.perm.len() {
+            return Err(MatrixError::ShapeMismatch {
+                expected: (m.row, self.perm.len()),
+                actual: (m.row, m.col),
+            });
+        }
+        let mut data = Vec::with_capacity(m.data.len());
+        for row in m.data.chunks(m.col.max(1)).take(m.row) {
+            data.extend(self.perm.iter().map(|&p| row[p].clone()));
+        }
+        Ok(Matrix {
+            data,
+            row: m.row,
+            col: m.col,
+        })
+    }
+
+    /// 转换为稠密的置换矩阵 `P`
+    pub fn to_matrix<T: Clone + Zero + One>(&self) -> Matrix<T> {
+        let n = self.perm.len();
+        let mut m = Matrix::zeros(n, n);
+        for (i, &p) in self.perm.iter().enumerate() {
+            m.data[i * n + p] = T::one();
+        }
+        m
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+    use anyhow::Result;
+
+    #[test]
+    fn test_permutation() -> Result<()> {
+        let p = Permutation::new(vec![2, 0, 1])?;
+        let m = Matrix::new([1, 2, 3, 4, 5, 6, 7, 8, 9], 3, 3);
+        let dense = p.to_matrix::<i32>();
+        assert_eq!(dense, Matrix::new([0, 0, 1, 1, 0, 0, 0, 1, 0], 3, 3));
+
+        let rows = p.apply_rows(&m)?;
+        assert_eq!(rows, Matrix::new([7, 8, 9, 1, 2, 3, 4, 5, 6], 3, 3));
+        assert_eq!(rows, multiply_sequential(&dense, &m)?);
+
+        let cols = p.apply_cols(&m)?;
+        assert_eq!(cols, Matrix::new([3, 1, 2, 6, 4, 5, 9, 7, 8], 3, 3));
+        assert_eq!(
+            cols,
+            multiply_sequential(&m, &p.inverse().to_matrix::<i32>())?
+        );
+        assert_eq!(p.inverse().apply_rows(&rows)?, m);
+        assert_eq!(Permutation::identity(3).apply_cols(&m)?, m);
+        Ok(())
+    }
+
+    #[test]
+    fn test_permutation_errors() {
+        assert!(matches!(
+            Permutation::new(vec![0, 3, 1]),
+            Err(MatrixError::InvalidPermutation(_))
+        ));
+        assert!(matches!(
+            Permutation::new(vec![1, 1, 0]),
+            Err(MatrixError::InvalidPermutation(_))
+        ));
+        let p = Permutation::identity(2);
+        assert!(p.apply_rows(&Matrix::new([1, 2, 3], 3, 1)).is_err());
+        assert!(p.apply_cols(&Matrix::new([1, 2, 3], 1, 3)).is_err());
+    }
+}
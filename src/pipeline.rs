@@ -0,0 +1,310 @@
+use anyhow::{Result, anyhow};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+
+use crate::channel::{self, Receiver, Sender};
+
+/// 生产者-消费者流水线
+///
+/// 生产者和消费者各自运行在独立线程中，通过有界通道传递消息。
+/// 所有生产者结束后通道自动关闭，消费者取完剩余消息即可退出，
+/// `run` 等待全部线程结束并按顺序返回每个消费者的结果
+///
+/// ```
+/// use concurrency::pipeline::ProducerConsumer;
+///
+/// let mut pipeline = ProducerConsumer::new(16);
+/// pipeline.spawn_producers(4, |index, tx| {
+///     for i in 0..10 {
+///         tx.send(index * 10 + i)?;
+///     }
+///     Ok(())
+/// });
+/// let sums = pipeline.run(2, |rx| rx.iter().sum::<usize>()).unwrap();
+/// assert_eq!(sums.iter().sum::<usize>(), (0..40).sum());
+/// ```
+pub struct ProducerConsumer<T> {
+    tx: Sender<T>,
+    rx: Receiver<T>,
+    producers: Vec<JoinHandle<Result<()>>>,
+}
+
+impl<T: Send + 'static> ProducerConsumer<T> {
+    /// 创建流水线
+    ///
+    /// # 参数
+    /// * `capacity`: 通道容量，通道已满时生产者阻塞
+    pub fn new(capacity: usize) -> Self {
+        let (tx, rx) = channel::bounded(capacity);
+        Self {
+            tx,
+            rx,
+            producers: Vec::new(),
+        }
+    }
+
+    /// 启动 `n` 个生产者线程，可以多次调用以启动不同逻辑的生产者
+    ///
+    /// # 参数
+    /// * `n`: 生产者数量
+    /// * `producer`: 生产者逻辑，参数为本次调用内从 0 开始的生产者编号和发送端，
+    ///   返回时该生产者结束
+    pub fn spawn_producers<F>(&mut self, n: usize, producer: F)
+    where
+        F: Fn(usize, &Sender<T>) -> Result<()> + Send + Sync + 'static,
+    {
+        let producer = Arc::new(producer);
+        for index in 0..n {
+            let producer = producer.clone();
+            let tx = self.tx.clone();
+            self.producers
+                .push(thread::spawn(move || producer(index, &tx)));
+        }
+    }
+
+    /// 启动 `n` 个消费者线程并等待流水线结束
+    ///
+    /// # 参数
+    /// * `n`: 消费者数量，至少为 1
+    /// * `consumer`: 消费者逻辑，接收端在通道关闭且消息取完后结束迭代
+    ///
+    /// # 返回值
+    /// 按启动顺序排列的消费者结果；任意生产者返回错误或任意线程 panic 时返回错误
+    pub fn run<R, C>(self, n: usize, consumer: C) -> Result<Vec<R>>
+    where
+        R: Send + 'static,
+        C: Fn(Receiver<T>) -> R + Send + Sync + 'static,
+    {
+        let Self { tx, rx, producers } = self;
+        // 释放自身持有的发送端，否则生产者全部结束后通道也不会关闭
+        drop(tx);
+        let consumer = Arc::new(consumer);
+        let consumers: Vec<_> = (0..n.max(1))
+            .map(|_| {
+                let consumer = consumer.clone();
+                let rx = rx.clone();
+                thread::spawn(move || consumer(rx))
+            })
+            .collect();
+        drop(rx);
+
+        let mut first_err = None;
+        for (index, producer) in producers.into_iter().enumerate() {
+            let result = producer
+                .join()
+                .map_err(|e| anyhow!("Producer {} panicked: {:?}", index, e))
+                .and_then(|r| r);
+            if let Err(e) = result {
+                first_err.get_or_insert(e);
+            }
+        }
+        let results = consumers
+            .into_iter()
+            .enumerate()
+            .map(|(index, consumer)| {
+                consumer
+                    .join()
+                    .map_err(|e| anyhow!("Consumer {} panicked: {:?}", index, e))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        match first_err {
+            Some(e) => Err(e),
+            None => Ok(results),
+        }
+    }
+}
+
+/// 多级流水线
+///
+/// 由 `source` 开始，经过任意个 `map` 阶段，最后由 `sink` 结束，
+/// 每个阶段运行在自己的线程上，相邻阶段之间通过有界通道连接，
+/// 下游处理不过来时上游在发送处阻塞。上游全部结束后通道关闭，
+/// 关闭逐级向下游传递；`sink` 提前返回时，上游在下一次发送时停止
+///
+/// ```
+/// use concurrency::pipeline::Pipeline;
+///
+/// let total = Pipeline::source(8, |tx| {
+///     for i in 1..=100u64 {
+///         tx.send(i)?;
+///     }
+///     Ok(())
+/// })
+/// .map(4, |v| v * 2)
+/// .sink(|rx| rx.iter().sum::<u64>())
+/// .unwrap();
+/// assert_eq!(total, 10100);
+/// ```
+pub struct Pipeline<T> {
+    rx: Receiver<T>,
+    capacity: usize,
+    stages: Vec<JoinHandle<Result<()>>>,
+}
+
+impl<T: Send + 'static> Pipeline<T> {
+    /// 创建流水线的数据源阶段
+    ///
+    /// # 参数
+    /// * `capacity`: 每个阶段输出通道的容量
+    /// * `source`: 数据源逻辑，返回时数据源结束
+    pub fn source<F>(capacity: usize, source: F) -> Self
+    where
+        F: FnOnce(&Sender<T>) -> Result<()> + Send + 'static,
+    {
+        let (tx, rx) = channel::bounded(capacity);
+        let stage = thread::spawn(move || match source(&tx) {
+            // 下游已经停止接收，属于正常结束
+            Err(_) if tx.is_closed() => Ok(()),
+            result => result,
+        });
+        Self {
+            rx,
+            capacity,
+            stages: vec![stage],
+        }
+    }
+
+    /// 追加一个映射阶段，由 `workers` 个线程并行处理，输出顺序不保证与输入一致
+    ///
+    /// # 参数
+    /// * `workers`: 线程数，至少为 1
+    /// * `f`: 映射函数
+    pub fn map<U, F>(self, workers: usize, f: F) -> Pipeline<U>
+    where
+        U: Send + 'static,
+        F: Fn(T) -> U + Send + Sync + 'static,
+    {
+        let Self {
+            rx,
+            capacity,
+            mut stages,
+        } = self;
+        let (tx, next) = channel::bounded(capacity);
+        let f = Arc::new(f);
+        for _ in 0..workers.max(1) {
+            let (rx, tx, f) = (rx.clone(), tx.clone(), f.clone());
+            stages.push(thread::spawn(move || {
+                for value in rx.iter() {
+                    if tx.send(f(value)).is_err() {
+                        // 下游已经停止接收，同时通知上游停止发送
+                        rx.close();
+                        break;
+                    }
+                }
+                Ok(())
+            }));
+        }
+        Pipeline {
+            rx: next,
+            capacity,
+            stages,
+        }
+    }
+
+    /// 以 `sink` 结束流水线并等待所有阶段退出
+    ///
+    /// # 参数
+    /// * `sink`: 汇聚逻辑，运行在独立线程上
+    ///
+    /// # 返回值
+    /// `sink` 的结果；任意阶段返回错误或 panic 时返回错误
+    pub fn sink<R, F>(self, sink: F) -> Result<R>
+    where
+        R: Send + 'static,
+        F: FnOnce(Receiver<T>) -> R + Send + 'static,
+    {
+        let Self { rx, stages, .. } = self;
+        let sink = thread::spawn(move || {
+            let result = sink(rx.clone());
+            // sink 提前返回时关闭通道，让上游停止发送
+            rx.close();
+            result
+        });
+        let result = sink
+            .join()
+            .map_err(|e| anyhow!("Pipeline sink panicked: {:?}", e));
+        let mut first_err = None;
+        for (index, stage) in stages.into_iter().enumerate() {
+            let stage = stage
+                .join()
+                .map_err(|e| anyhow!("Pipeline stage {} panicked: {:?}", index, e))
+                .and_then(|r| r);
+            if let Err(e) = stage {
+                first_err.get_or_insert(e);
+            }
+        }
+        match first_err {
+            Some(e) => Err(e),
+            None => result,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pipeline_collects_all_messages() -> Result<()> {
+        let mut pipeline = ProducerConsumer::new(2);
+        pipeline.spawn_producers(3, |index, tx| {
+            for i in 0..50 {
+                tx.send(index * 50 + i)?;
+            }
+            Ok(())
+        });
+        pipeline.spawn_producers(1, |_, tx| Ok(tx.send(1000)?));
+        let mut received: Vec<_> = pipeline
+            .run(2, |rx| rx.iter().collect::<Vec<_>>())?
+            .into_iter()
+            .flatten()
+            .collect();
+        received.sort();
+        let mut expected: Vec<_> = (0..150).collect();
+        expected.push(1000);
+        assert_eq!(received, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline_reports_producer_error() {
+        let mut pipeline = ProducerConsumer::<usize>::new(1);
+        pipeline.spawn_producers(2, |index, _| match index {
+            0 => Ok(()),
+            _ => Err(anyhow!("producer failed")),
+        });
+        let err = pipeline.run(1, |rx| rx.iter().count()).unwrap_err();
+        assert_eq!(err.to_string(), "producer failed");
+    }
+
+    #[test]
+    fn test_pipeline_stages() -> Result<()> {
+        let mut received = Pipeline::source(2, |tx| {
+            for i in 0..100 {
+                tx.send(i)?;
+            }
+            Ok(())
+        })
+        .map(3, |v: i32| v * 10)
+        .map(1, |v| v.to_string())
+        .sink(|rx| rx.iter().collect::<Vec<_>>())?;
+        received.sort_by_key(|s| s.parse::<i32>().unwrap());
+        assert_eq!(received.len(), 100);
+        assert_eq!(received[99], "990");
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline_sink_stops_early() -> Result<()> {
+        let first = Pipeline::source(1, |tx| {
+            for i in 0.. {
+                tx.send(i)?;
+            }
+            Ok(())
+        })
+        .map(2, |v: u64| v + 1)
+        .sink(|rx| rx.iter().take(3).count())?;
+        assert_eq!(first, 3);
+        Ok(())
+    }
+}
@@ -0,0 +1,716 @@
+use anyhow::{Result, anyhow};
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use crate::cancel::CancelToken;
+
+/// 每个工作线程任务队列的默认容量
+pub const DEFAULT_QUEUE_CAPACITY: usize = 1024;
+
+/// 带截止时间提交时检查取消令牌的间隔，取消信号本身不会唤醒等待中的提交方
+const SUBMIT_POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+/// 线程池任务类型
+pub type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// 限时提交失败的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SubmitError {
+    /// 工作线程已退出
+    Closed,
+    /// 队列一直满到截止时间
+    Timeout,
+    /// 等待队列空位期间令牌被取消
+    Cancelled,
+}
+
+/// 任务优先级
+///
+/// 工作线程总是先清空高优先级队列再处理普通队列，
+/// 使交互式的小任务不必排在先提交的批处理任务之后
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    High,
+    #[default]
+    Normal,
+}
+
+/// 固定大小的线程池
+///
+/// 每个工作线程拥有独立的有界任务队列，队列分为高、普通两个优先级通道，
+/// 任务由调用方显式分配到指定线程，通道已满时提交方阻塞等待，从而形成背压。
+/// 线程池可以在多个线程间共享（`&ThreadPool` 或 `Arc<ThreadPool>`）。
+/// 任务中的 panic 会被捕获，不会导致工作线程退出。
+/// 线程池被 drop 时关闭所有队列，并等待工作线程处理完剩余任务后退出，
+/// 保证不会遗留任何后台线程
+pub struct ThreadPool {
+    workers: Vec<Worker>,
+    next: AtomicUsize,
+    pending: Arc<Pending>,
+    started: Instant,
+}
+
+/// 线程池运行状态快照
+///
+/// # 字段
+/// * `queued`: 排队等待执行的任务数
+/// * `active`: 正在执行任务的工作线程数
+/// * `completed`: 已执行完毕的任务数
+/// * `uptime`: 线程池创建至今的时间
+/// * `workers`: 每个工作线程的状态，下标为工作线程编号
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolStats {
+    pub queued: usize,
+    pub active: usize,
+    pub completed: u64,
+    pub uptime: Duration,
+    pub workers: Vec<PoolWorkerStats>,
+}
+
+/// 单个工作线程的运行状态
+///
+/// # 字段
+/// * `queued`: 该线程队列中排队的任务数
+/// * `active`: 是否正在执行任务
+/// * `completed`: 已执行完毕的任务数
+/// * `busy_time`: 执行已完成任务的耗时总和
+/// * `utilization`: `busy_time` 占线程池运行时间的比例
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PoolWorkerStats {
+    pub queued: usize,
+    pub active: bool,
+    pub completed: u64,
+    pub busy_time: Duration,
+    pub utilization: f64,
+}
+
+/// 已提交但尚未执行完毕的任务计数
+///
+/// # 字段
+/// * `count`: 未完成的任务数
+/// * `idle`: 计数归零时通知 `join`
+#[derive(Default)]
+struct Pending {
+    count: Mutex<usize>,
+    idle: Condvar,
+}
+
+/// 工作线程
+///
+/// # 字段
+/// * `queue`: 该线程的任务队列
+/// * `handle`: 线程句柄，用于在 drop 时 join
+/// * `counters`: 该线程的运行计数
+struct Worker {
+    queue: Arc<Queue>,
+    handle: Option<JoinHandle<()>>,
+    counters: Arc<Counters>,
+}
+
+/// 工作线程的运行计数，由提交时包装的任务更新、`ThreadPool::stats` 读取
+#[derive(Default)]
+struct Counters {
+    active: AtomicBool,
+    completed: AtomicU64,
+    busy_nanos: AtomicU64,
+}
+
+/// 单个工作线程的有界优先级队列
+///
+/// # 字段
+/// * `lanes`: 两个优先级通道及关闭标记
+/// * `not_empty`: 有新任务或队列关闭时通知工作线程
+/// * `not_full`: 有任务被取走或队列关闭时通知提交方
+/// * `capacity`: 每个优先级通道的容量
+struct Queue {
+    lanes: Mutex<Lanes>,
+    not_empty: Condvar,
+    not_full: Condvar,
+    capacity: usize,
+}
+
+#[derive(Default)]
+struct Lanes {
+    high: VecDeque<Job>,
+    normal: VecDeque<Job>,
+    closed: bool,
+}
+
+impl ThreadPool {
+    /// 创建线程池，每个工作线程的队列容量为 `DEFAULT_QUEUE_CAPACITY`
+    ///
+    /// # 参数
+    /// * `size`: 工作线程数，必须大于 0
+    ///
+    /// # 返回值
+    /// 返回ThreadPool实例
+    pub fn new(size: usize) -> Self {
+        Self::with_capacity(size, DEFAULT_QUEUE_CAPACITY)
+    }
+
+    /// 创建指定队列容量的线程池
+    ///
+    /// # 参数
+    /// * `size`: 工作线程数，必须大于 0
+    /// * `capacity`: 每个工作线程每个优先级通道的容量，至少为 1
+    ///
+    /// # 返回值
+    /// 返回ThreadPool实例
+    pub fn with_capacity(size: usize, capacity: usize) -> Self {
+        Self::build(size, capacity, &[])
+    }
+
+    /// 创建工作线程绑定到 CPU 核心的线程池，每个工作线程的队列容量为 `DEFAULT_QUEUE_CAPACITY`
+    ///
+    /// 第 `i` 个工作线程绑定到当前进程可用核心中的第 `i % 核心数` 个，
+    /// 避免线程在核心之间迁移，减少 NUMA 机器上的性能波动。无法绑定时退化为普通线程池
+    ///
+    /// # 参数
+    /// * `size`: 工作线程数，必须大于 0
+    #[cfg(feature = "affinity")]
+    pub fn pinned(size: usize) -> Self {
+        Self::pinned_with_capacity(size, DEFAULT_QUEUE_CAPACITY)
+    }
+
+    /// 创建指定队列容量、工作线程绑定到 CPU 核心的线程池
+    ///
+    /// # 参数
+    /// * `size`: 工作线程数，必须大于 0
+    /// * `capacity`: 每个工作线程每个优先级通道的容量，至少为 1
+    #[cfg(feature = "affinity")]
+    pub fn pinned_with_capacity(size: usize, capacity: usize) -> Self {
+        Self::build(size, capacity, &crate::affinity::available_cores())
+    }
+
+    /// 为 `cores` 中的每个核心创建一个绑定到该核心的工作线程
+    ///
+    /// # 参数
+    /// * `cores`: CPU 核心编号，不能为空
+    #[cfg(feature = "affinity")]
+    pub fn pinned_to(cores: &[usize]) -> Self {
+        Self::build(cores.len(), DEFAULT_QUEUE_CAPACITY, cores)
+    }
+
+    /// 创建线程池，`cores` 非空时第 `i` 个工作线程绑定到 `cores[i % cores.len()]`
+    fn build(size: usize, capacity: usize, cores: &[usize]) -> Self {
+        assert!(size > 0, "ThreadPool size must be greater than 0");
+        let workers = (0..size)
+            .map(|i| {
+                let core = (!cores.is_empty()).then(|| cores[i % cores.len()]);
+                Worker::spawn(capacity.max(1), core)
+            })
+            .collect();
+        Self {
+            workers,
+            next: AtomicUsize::new(0),
+            pending: Arc::default(),
+            started: Instant::now(),
+        }
+    }
+
+    /// 工作线程数
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// 运行状态快照
+    ///
+    /// 各项计数分别读取，线程池繁忙时彼此之间不保证严格一致；
+    /// 正在执行的任务结束后才计入 `busy_time`
+    pub fn stats(&self) -> PoolStats {
+        let uptime = self.started.elapsed();
+        let workers: Vec<_> = self
+            .workers
+            .iter()
+            .map(|worker| {
+                let busy_time =
+                    Duration::from_nanos(worker.counters.busy_nanos.load(Ordering::Relaxed));
+                PoolWorkerStats {
+                    queued: worker.queue.len(),
+                    active: worker.counters.active.load(Ordering::Relaxed),
+                    completed: worker.counters.completed.load(Ordering::Relaxed),
+                    busy_time,
+                    utilization: (busy_time.as_secs_f64() / uptime.as_secs_f64()).min(1.0),
+                }
+            })
+            .collect();
+        PoolStats {
+            queued: workers.iter().map(|w| w.queued).sum(),
+            active: workers.iter().filter(|w| w.active).count(),
+            completed: workers.iter().map(|w| w.completed).sum(),
+            uptime,
+            workers,
+        }
+    }
+
+    /// 以普通优先级提交任务，按轮询方式分配到工作线程，通道已满时阻塞
+    ///
+    /// # 参数
+    /// * `job`: 要执行的任务
+    ///
+    /// # 返回值
+    /// 工作线程已退出时返回错误
+    pub fn execute<F>(&self, job: F) -> Result<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let worker = self.next.fetch_add(1, Ordering::Relaxed);
+        self.execute_on(worker, job)
+    }
+
+    /// 阻塞直到所有已提交的任务执行完毕，包括等待期间新提交的任务
+    pub fn join(&self) {
+        let mut count = self.pending.lock();
+        while *count > 0 {
+            count = self
+                .pending
+                .idle
+                .wait(count)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 以普通优先级将任务提交到指定的工作线程，通道已满时阻塞
+    ///
+    /// # 参数
+    /// * `worker`: 工作线程编号，超出范围时按线程数取模
+    /// * `job`: 要执行的任务
+    ///
+    /// # 返回值
+    /// 工作线程已退出时返回错误
+    pub fn execute_on<F>(&self, worker: usize, job: F) -> Result<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        self.execute_on_with_priority(worker, Priority::Normal, job)
+    }
+
+    /// 以指定优先级将任务提交到指定的工作线程，通道已满时阻塞
+    ///
+    /// # 参数
+    /// * `worker`: 工作线程编号，超出范围时按线程数取模
+    /// * `priority`: 任务优先级
+    /// * `job`: 要执行的任务
+    ///
+    /// # 返回值
+    /// 工作线程已退出时返回错误
+    pub fn execute_on_with_priority<F>(
+        &self,
+        worker: usize,
+        priority: Priority,
+        job: F,
+    ) -> Result<()>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let worker = worker % self.workers.len();
+        let job = self.wrap(worker, job);
+        self.workers[worker].queue.push(priority, job).map_err(|_| {
+            self.pending.done();
+            anyhow!("Worker {} has exited", worker)
+        })
+    }
+
+    /// 以指定优先级将任务提交到指定的工作线程，通道已满时最多等到截止时间
+    ///
+    /// 等待期间会定期检查取消令牌，保证调用方的超时和取消不会被满队列拖住
+    ///
+    /// # 参数
+    /// * `worker`: 工作线程编号，超出范围时按线程数取模
+    /// * `priority`: 任务优先级
+    /// * `deadline`: 截止时间，None 表示不限时
+    /// * `cancel`: 取消令牌
+    /// * `job`: 要执行的任务
+    ///
+    /// # 返回值
+    /// 任务未能入队时返回失败原因，任务被丢弃
+    pub(crate) fn execute_on_until<F>(
+        &self,
+        worker: usize,
+        priority: Priority,
+        deadline: Option<Instant>,
+        cancel: &CancelToken,
+        job: F,
+    ) -> Result<(), SubmitError>
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let worker = worker % self.workers.len();
+        let job = self.wrap(worker, job);
+        self.workers[worker]
+            .queue
+            .push_until(priority, job, deadline, cancel)
+            .inspect_err(|_| self.pending.done())
+    }
+
+    /// 登记待完成任务，并包装任务以更新工作线程计数
+    fn wrap<F>(&self, worker: usize, job: F) -> Job
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        *self.pending.lock() += 1;
+        let pending = self.pending.clone();
+        let counters = self.workers[worker].counters.clone();
+        Box::new(move || {
+            counters.active.store(true, Ordering::Relaxed);
+            let start = Instant::now();
+            // 捕获 panic，单个任务失败不影响工作线程继续处理后续任务
+            let _ = panic::catch_unwind(AssertUnwindSafe(job));
+            let elapsed = start.elapsed().as_nanos() as u64;
+            counters.busy_nanos.fetch_add(elapsed, Ordering::Relaxed);
+            counters.completed.fetch_add(1, Ordering::Relaxed);
+            counters.active.store(false, Ordering::Relaxed);
+            // 计数更新完毕后才通知 join，保证 join 返回后的快照包含全部任务
+            pending.done();
+        })
+    }
+}
+
+impl Pending {
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.count.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn done(&self) {
+        let mut count = self.lock();
+        *count -= 1;
+        if *count == 0 {
+            self.idle.notify_all();
+        }
+    }
+}
+
+impl Worker {
+    #[cfg_attr(not(feature = "affinity"), allow(unused_variables))]
+    fn spawn(capacity: usize, core: Option<usize>) -> Self {
+        let queue = Arc::new(Queue::new(capacity));
+        let handle = thread::spawn({
+            let queue = queue.clone();
+            move || {
+                #[cfg(feature = "affinity")]
+                if let Some(core) = core {
+                    // 绑定失败不影响执行，只是失去亲和性
+                    crate::affinity::pin_current_thread(core);
+                }
+                // 线程因任何原因退出时关闭队列，避免提交方永久阻塞
+                let _close = CloseOnDrop(&queue);
+                // 队列关闭且任务处理完毕后退出
+                while let Some(job) = queue.pop() {
+                    job();
+                }
+            }
+        });
+        Self {
+            queue,
+            handle: Some(handle),
+            counters: Arc::default(),
+        }
+    }
+}
+
+impl Queue {
+    fn new(capacity: usize) -> Self {
+        Self {
+            lanes: Mutex::new(Lanes::default()),
+            not_empty: Condvar::new(),
+            not_full: Condvar::new(),
+            capacity,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Lanes> {
+        self.lanes.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// 排队中的任务数
+    fn len(&self) -> usize {
+        let lanes = self.lock();
+        lanes.high.len() + lanes.normal.len()
+    }
+
+    /// 放入任务，对应通道已满时阻塞；队列已关闭时原样返回任务
+    fn push(&self, priority: Priority, job: Job) -> Result<(), Job> {
+        let mut lanes = self.lock();
+        loop {
+            if lanes.closed {
+                return Err(job);
+            }
+            let lane = match priority {
+                Priority::High => &mut lanes.high,
+                Priority::Normal => &mut lanes.normal,
+            };
+            if lane.len() < self.capacity {
+                lane.push_back(job);
+                self.not_empty.notify_one();
+                return Ok(());
+            }
+            lanes = self
+                .not_full
+                .wait(lanes)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 放入任务，对应通道已满时最多等到截止时间，期间定期检查取消令牌
+    fn push_until(
+        &self,
+        priority: Priority,
+        job: Job,
+        deadline: Option<Instant>,
+        cancel: &CancelToken,
+    ) -> Result<(), SubmitError> {
+        let mut lanes = self.lock();
+        loop {
+            if lanes.closed {
+                return Err(SubmitError::Closed);
+            }
+            let lane = match priority {
+                Priority::High => &mut lanes.high,
+                Priority::Normal => &mut lanes.normal,
+            };
+            if lane.len() < self.capacity {
+                lane.push_back(job);
+                self.not_empty.notify_one();
+                return Ok(());
+            }
+            if cancel.is_cancelled() {
+                return Err(SubmitError::Cancelled);
+            }
+            let mut wait = SUBMIT_POLL_INTERVAL;
+            if let Some(deadline) = deadline {
+                let now = Instant::now();
+                if now >= deadline {
+                    return Err(SubmitError::Timeout);
+                }
+                wait = wait.min(deadline - now);
+            }
+            lanes = self
+                .not_full
+                .wait_timeout(lanes, wait)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+
+    /// 取出任务，高优先级通道优先；队列关闭且为空时返回 None
+    fn pop(&self) -> Option<Job> {
+        let mut lanes = self.lock();
+        loop {
+            if let Some(job) = lanes.high.pop_front().or_else(|| lanes.normal.pop_front()) {
+                self.not_full.notify_all();
+                return Some(job);
+            }
+            if lanes.closed {
+                return None;
+            }
+            lanes = self
+                .not_empty
+                .wait(lanes)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 关闭队列，已排队的任务仍会被取出执行
+    fn close(&self) {
+        self.lock().closed = true;
+        self.not_empty.notify_all();
+        self.not_full.notify_all();
+    }
+}
+
+/// 离开作用域时关闭队列
+struct CloseOnDrop<'a>(&'a Queue);
+
+impl Drop for CloseOnDrop<'_> {
+    fn drop(&mut self) {
+        self.0.close();
+    }
+}
+
+impl Drop for ThreadPool {
+    fn drop(&mut self) {
+        // 先关闭全部队列，让所有线程并行收尾，再逐个 join
+        for worker in &self.workers {
+            worker.queue.close();
+        }
+        for worker in &mut self.workers {
+            if let Some(handle) = worker.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc;
+
+    #[test]
+    fn test_pool_runs_all_jobs_before_drop_returns() -> Result<()> {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPool::new(4);
+        for i in 0..100 {
+            let counter = counter.clone();
+            pool.execute_on(i, move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })?;
+        }
+        drop(pool);
+        assert_eq!(counter.load(Ordering::SeqCst), 100);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_bounded_queue_applies_backpressure() -> Result<()> {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPool::with_capacity(1, 1);
+        for _ in 0..10 {
+            let counter = counter.clone();
+            pool.execute_on(0, move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+            })?;
+        }
+        // 容量为 1 时最多一个任务排队、一个任务执行中，提交完时至少已完成 8 个
+        assert!(counter.load(Ordering::SeqCst) >= 8);
+        drop(pool);
+        assert_eq!(counter.load(Ordering::SeqCst), 10);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_execute_on_until_gives_up_when_full() -> Result<()> {
+        let pool = ThreadPool::with_capacity(1, 1);
+        let (release, blocked) = mpsc::channel::<()>();
+        pool.execute_on(0, move || {
+            let _ = blocked.recv();
+        })?;
+        pool.execute_on(0, || {})?;
+
+        let token = CancelToken::new();
+        let deadline = Instant::now() + Duration::from_millis(20);
+        let result = pool.execute_on_until(0, Priority::Normal, Some(deadline), &token, || {});
+        assert_eq!(result, Err(SubmitError::Timeout));
+
+        // 取消信号不会唤醒等待方，靠轮询间隔发现
+        let canceller = token.clone();
+        let handle = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(20));
+            canceller.cancel();
+        });
+        let result = pool.execute_on_until(0, Priority::Normal, None, &token, || {});
+        assert_eq!(result, Err(SubmitError::Cancelled));
+        handle.join().map_err(|_| anyhow!("canceller panicked"))?;
+
+        release.send(())?;
+        pool.join();
+        // 失败的提交不计入待完成任务，join 能正常返回
+        assert_eq!(pool.stats().workers[0].completed, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_runs_high_priority_first() -> Result<()> {
+        let pool = ThreadPool::new(1);
+        let (order_tx, order_rx) = mpsc::channel();
+        // 先用一个任务占住工作线程，保证后续任务都在排队
+        let (gate_tx, gate_rx) = mpsc::channel::<()>();
+        pool.execute_on(0, move || {
+            let _ = gate_rx.recv();
+        })?;
+        for (priority, label) in [
+            (Priority::Normal, "normal-1"),
+            (Priority::Normal, "normal-2"),
+            (Priority::High, "high"),
+        ] {
+            let order_tx = order_tx.clone();
+            pool.execute_on_with_priority(0, priority, move || {
+                let _ = order_tx.send(label);
+            })?;
+        }
+        gate_tx.send(())?;
+        drop(pool);
+        drop(order_tx);
+        let order = order_rx.iter().collect::<Vec<_>>();
+        assert_eq!(order, vec!["high", "normal-1", "normal-2"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_stats() -> Result<()> {
+        let pool = ThreadPool::new(2);
+        let (gate_tx, gate_rx) = mpsc::channel::<()>();
+        let (started_tx, started_rx) = mpsc::channel();
+        pool.execute_on(0, move || {
+            let _ = started_tx.send(());
+            let _ = gate_rx.recv();
+        })?;
+        for _ in 0..3 {
+            pool.execute_on(0, || {})?;
+        }
+        started_rx.recv()?;
+        let stats = pool.stats();
+        assert_eq!((stats.queued, stats.active, stats.completed), (3, 1, 0));
+        assert!(stats.workers[0].active && !stats.workers[1].active);
+
+        gate_tx.send(())?;
+        pool.execute_on(1, || thread::sleep(std::time::Duration::from_millis(10)))?;
+        pool.join();
+        let stats = pool.stats();
+        assert_eq!((stats.queued, stats.active, stats.completed), (0, 0, 5));
+        assert_eq!(stats.workers[1].completed, 1);
+        assert!(stats.workers[1].busy_time >= std::time::Duration::from_millis(10));
+        assert!(stats.workers[1].utilization > 0.0 && stats.workers[1].utilization <= 1.0);
+        Ok(())
+    }
+
+    #[cfg(all(feature = "affinity", target_os = "linux"))]
+    #[test]
+    fn test_pinned_pool() -> Result<()> {
+        let cores = crate::affinity::available_cores();
+        let pool = ThreadPool::pinned(2);
+        let (tx, rx) = mpsc::channel();
+        for worker in 0..2 {
+            let tx = tx.clone();
+            pool.execute_on(worker, move || {
+                let _ = tx.send((worker, crate::affinity::available_cores()));
+            })?;
+        }
+        drop(tx);
+        for (worker, affinity) in rx {
+            assert_eq!(affinity, vec![cores[worker % cores.len()]]);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_pool_execute_isolates_panics_and_joins() -> Result<()> {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let pool = ThreadPool::new(2);
+        for i in 0..50 {
+            let counter = counter.clone();
+            pool.execute(move || {
+                if i % 10 == 0 {
+                    panic!("job {} failed", i);
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+            })?;
+        }
+        pool.join();
+        assert_eq!(counter.load(Ordering::SeqCst), 45);
+
+        // panic 之后工作线程仍然可用
+        let counter2 = counter.clone();
+        pool.execute(move || {
+            counter2.fetch_add(1, Ordering::SeqCst);
+        })?;
+        pool.join();
+        assert_eq!(counter.load(Ordering::SeqCst), 46);
+        Ok(())
+    }
+}
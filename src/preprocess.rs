@@ -0,0 +1,191 @@
+use num_traits::Float;
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, NUM_THREADS};
+use crate::par;
+use crate::pool::ThreadPool;
+
+/// 按列标准化的变换参数，由 `Matrix::standardize_cols` 返回
+///
+/// # 字段
+/// * `mean`: 每列的均值
+/// * `std`: 每列的总体标准差，为零的列记为 1，使常数列变换后全为零
+#[derive(Debug, Clone, PartialEq)]
+pub struct StandardScaler<T> {
+    pub mean: Vec<T>,
+    pub std: Vec<T>,
+}
+
+/// 按列最小最大归一化的变换参数，由 `Matrix::minmax_normalize_cols` 返回
+///
+/// # 字段
+/// * `min`: 每列的最小值
+/// * `max`: 每列的最大值
+#[derive(Debug, Clone, PartialEq)]
+pub struct MinMaxScaler<T> {
+    pub min: Vec<T>,
+    pub max: Vec<T>,
+}
+
+impl<T> Matrix<T>
+where
+    T: Float + Send + Sync + 'static,
+{
+    /// 按列标准化：每列减去均值再除以标准差
+    ///
+    /// 每列的均值和标准差由并行的列归约计算，变换也按行并行执行
+    ///
+    /// # 返回值
+    /// 返回变换后的矩阵和变换参数，参数可以用 `StandardScaler::apply` 作用于新数据
+    pub fn standardize_cols(&self) -> Result<(Matrix<T>, StandardScaler<T>), MatrixError> {
+        let pool = ThreadPool::new(NUM_THREADS);
+        let (mean, std) = par::map_cols(self, &pool, |col: &[T]| {
+            let n = T::from(col.len().max(1)).unwrap_or_else(T::one);
+            let mean = col.iter().fold(T::zero(), |sum, &x| sum + x) / n;
+            let var = col
+                .iter()
+                .fold(T::zero(), |sum, &x| sum + (x - mean) * (x - mean))
+                / n;
+            let std = if var.is_zero() { T::one() } else { var.sqrt() };
+            (mean, std)
+        })?
+        .into_iter()
+        .unzip();
+        let scaler = StandardScaler { mean, std };
+        Ok((scaler.apply_on(self, &pool)?, scaler))
+    }
+
+    /// 按列最小最大归一化：每列线性映射到 `[0, 1]`
+    ///
+    /// 每列的最小值和最大值由并行的列归约计算，常数列变换后全为零
+    ///
+    /// # 返回值
+    /// 返回变换后的矩阵和变换参数，参数可以用 `MinMaxScaler::apply` 作用于新数据
+    pub fn minmax_normalize_cols(&self) -> Result<(Matrix<T>, MinMaxScaler<T>), MatrixError> {
+        let pool = ThreadPool::new(NUM_THREADS);
+        let (min, max) = par::map_cols(self, &pool, |col: &[T]| {
+            col.iter()
+                .fold((T::infinity(), T::neg_infinity()), |(min, max), &x| {
+                    (min.min(x), max.max(x))
+                })
+        })?
+        .into_iter()
+        .unzip();
+        let scaler = MinMaxScaler { min, max };
+        Ok((scaler.apply_on(self, &pool)?, scaler))
+    }
+}
+
+impl<T> StandardScaler<T>
+where
+    T: Float + Send + Sync + 'static,
+{
+    /// 用同样的参数变换新数据
+    ///
+    /// # 返回值
+    /// 列数与参数不一致时返回 `MatrixError::ShapeMismatch`
+    pub fn apply(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.apply_on(m, &ThreadPool::new(NUM_THREADS))
+    }
+
+    fn apply_on(&self, m: &Matrix<T>, pool: &ThreadPool) -> Result<Matrix<T>, MatrixError> {
+        check_cols(m, self.mean.len())?;
+        let (mean, std) = (self.mean.clone(), self.std.clone());
+        par::map_rows(m, pool, move |row| {
+            for ((x, &mean), &std) in row.iter_mut().zip(&mean).zip(&std) {
+                *x = (*x - mean) / std;
+            }
+        })
+    }
+}
+
+impl<T> MinMaxScaler<T>
+where
+    T: Float + Send + Sync + 'static,
+{
+    /// 用同样的参数变换新数据，超出原始范围的值会落在 `[0, 1]` 之外
+    ///
+    /// # 返回值
+    /// 列数与参数不一致时返回 `MatrixError::ShapeMismatch`
+    pub fn apply(&self, m: &Matrix<T>) -> Result<Matrix<T>, MatrixError> {
+        self.apply_on(m, &ThreadPool::new(NUM_THREADS))
+    }
+
+    fn apply_on(&self, m: &Matrix<T>, pool: &ThreadPool) -> Result<Matrix<T>, MatrixError> {
+        check_cols(m, self.min.len())?;
+        let (min, max) = (self.min.clone(), self.max.clone());
+        par::map_rows(m, pool, move |row| {
+            for ((x, &min), &max) in row.iter_mut().zip(&min).zip(&max) {
+                let range = max - min;
+                *x = if range > T::zero() {
+                    (*x - min) / range
+                } else {
+                    T::zero()
+                };
+            }
+        })
+    }
+}
+
+/// 检查矩阵列数是否与变换参数一致
+fn check_cols<T>(m: &Matrix<T>, cols: usize) -> Result<(), MatrixError> {
+    if m.col != cols {
+        return Err(MatrixError::ShapeMismatch {
+            expected: (m.row, cols),
+            actual: (m.row, m.col),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+
+    #[test]
+    fn test_standardize_cols() -> Result<()> {
+        let m = Matrix::new([1.0, 10.0, 5.0, 2.0, 20.0, 5.0, 3.0, 30.0, 5.0], 3, 3);
+        let (z, scaler) = m.standardize_cols()?;
+        assert_eq!(scaler.mean, [2.0, 20.0, 5.0]);
+        assert_eq!(scaler.std[2], 1.0);
+        let s = (2.0f64 / 3.0).sqrt();
+        for i in 0..3 {
+            let expected = (i as f64 - 1.0) / s;
+            assert!((z.data[i * 3] - expected).abs() < 1e-12);
+            assert!((z.data[i * 3 + 1] - expected).abs() < 1e-12);
+            assert_eq!(z.data[i * 3 + 2], 0.0);
+        }
+
+        // 参数可以作用于新数据
+        let new = scaler.apply(&Matrix::new([2.0, 20.0, 6.0], 1, 3))?;
+        assert_eq!(new.data, [0.0, 0.0, 1.0]);
+        assert!(matches!(
+            scaler.apply(&Matrix::new([1.0, 2.0], 1, 2)),
+            Err(MatrixError::ShapeMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_minmax_normalize_cols() -> Result<()> {
+        let m = Matrix::new([1.0f32, -4.0, 7.0, 3.0, 0.0, 7.0, 2.0, 4.0, 7.0], 3, 3);
+        let (n, scaler) = m.minmax_normalize_cols()?;
+        assert_eq!(scaler.min, [1.0, -4.0, 7.0]);
+        assert_eq!(scaler.max, [3.0, 4.0, 7.0]);
+        assert_eq!(
+            n,
+            Matrix::new([0.0, 0.0, 0.0, 1.0, 0.5, 0.0, 0.5, 1.0, 0.0], 3, 3)
+        );
+        assert_eq!(
+            scaler.apply(&Matrix::new([5.0, 8.0, 9.0], 1, 3))?.data,
+            [2.0, 1.5, 0.0]
+        );
+
+        let empty = Matrix::<f64>::new([], 0, 2);
+        let (n, scaler) = empty.minmax_normalize_cols()?;
+        assert_eq!((n.row, n.col), (0, 2));
+        assert_eq!(scaler.min.len(), 2);
+        Ok(())
+    }
+}
@@ -0,0 +1,39 @@
+use rayon::prelude::*;
+use rayon::slice::{Chunks, Iter};
+
+use crate::matrix::Matrix;
+
+/// 按行主序并行遍历矩阵元素
+impl<'a, T: Sync> IntoParallelIterator for &'a Matrix<T> {
+    type Iter = Iter<'a, T>;
+    type Item = &'a T;
+
+    fn into_par_iter(self) -> Self::Iter {
+        self.data.par_iter()
+    }
+}
+
+impl<T: Sync> Matrix<T> {
+    /// 并行遍历矩阵的每一行，每项为长度等于列数的切片
+    pub fn par_rows(&self) -> Chunks<'_, T> {
+        // 列数为 0 时没有元素，块大小取 1 只为避免 par_chunks 的断言
+        self.data.par_chunks(self.col.max(1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_iter() {
+        let m = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        assert_eq!(m.par_iter().sum::<i32>(), 21);
+        assert_eq!(
+            m.par_rows()
+                .map(|r| r.iter().sum::<i32>())
+                .collect::<Vec<_>>(),
+            vec![6, 15]
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+/// 矩阵乘法的任务调度策略
+///
+/// `assign` 在分发线程上为每个任务选择工作线程，
+/// `on_complete` 在工作线程上于任务完成后调用，可用于实现按负载分配等策略。
+/// 通过 `MultiplyOptions::scheduler` 传入，确定性模式下不使用
+pub trait Scheduler: Send + Sync {
+    /// 为索引为 `task_idx` 的任务选择工作线程，任务按 `ChunkStrategy` 切分并按行主序编号，返回值超出范围时按 `num_workers` 取模
+    fn assign(&self, task_idx: usize, num_workers: usize) -> usize;
+
+    /// 任务在 `worker` 号工作线程上执行完毕
+    fn on_complete(&self, task_idx: usize, worker: usize) {
+        let _ = (task_idx, worker);
+    }
+}
+
+/// 按任务索引轮询分配，默认策略
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RoundRobin;
+
+impl Scheduler for RoundRobin {
+    fn assign(&self, task_idx: usize, num_workers: usize) -> usize {
+        task_idx % num_workers
+    }
+}
+
+/// 随机分配
+///
+/// 默认使用线程本地的随机数生成器；`seeded` 创建的调度器使用固定种子，
+/// `assign` 总是在分发线程上按任务顺序调用，因此相同种子得到相同的分配序列
+#[derive(Debug, Default)]
+pub struct RandomScheduler {
+    rng: Option<Mutex<StdRng>>,
+}
+
+impl RandomScheduler {
+    /// 使用线程本地随机数生成器的调度器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 使用种子为 `seed` 的随机数生成器，分配结果可以复现
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    /// 使用指定的随机数生成器
+    pub fn from_rng(rng: StdRng) -> Self {
+        Self {
+            rng: Some(Mutex::new(rng)),
+        }
+    }
+}
+
+impl Scheduler for RandomScheduler {
+    fn assign(&self, _task_idx: usize, num_workers: usize) -> usize {
+        match &self.rng {
+            Some(rng) => rng
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .random_range(0..num_workers),
+            None => rand::random_range(0..num_workers),
+        }
+    }
+}
+
+/// 分配给在途任务最少的工作线程
+///
+/// 在途任务数在 `assign` 时加一、`on_complete` 时减一，
+/// 计算耗时不均匀时比轮询分配更均衡
+#[derive(Debug, Default)]
+pub struct LeastLoaded {
+    in_flight: Vec<AtomicUsize>,
+}
+
+impl LeastLoaded {
+    /// 创建最多跟踪 `max_workers` 个工作线程的调度器，超出的线程按取模合并统计
+    pub fn new(max_workers: usize) -> Self {
+        Self {
+            in_flight: (0..max_workers.max(1))
+                .map(|_| AtomicUsize::new(0))
+                .collect(),
+        }
+    }
+}
+
+impl Scheduler for LeastLoaded {
+    fn assign(&self, _task_idx: usize, num_workers: usize) -> usize {
+        let slots = num_workers.min(self.in_flight.len()).max(1);
+        // 第 s 个计数合并了编号为 s、s + slots、s + 2·slots… 的线程
+        let merged = |s: usize| num_workers.saturating_sub(s).div_ceil(slots).max(1);
+        let loads = self.in_flight[..slots]
+            .iter()
+            .map(|count| count.load(Ordering::Relaxed))
+            .collect::<Vec<_>>();
+        // 按每个线程的平均在途任务数比较，交叉相乘避免除法
+        let slot = (0..slots)
+            .min_by(|&x, &y| (loads[x] * merged(y)).cmp(&(loads[y] * merged(x))))
+            .unwrap_or(0);
+        // 合并到同一计数的线程按该计数的在途任务数轮流分配
+        let previous = self.in_flight[slot].fetch_add(1, Ordering::Relaxed);
+        slot + slots * (previous % merged(slot))
+    }
+
+    fn on_complete(&self, _task_idx: usize, worker: usize) {
+        let slot = worker.checked_rem(self.in_flight.len());
+        if let Some(count) = slot.and_then(|slot| self.in_flight.get(slot)) {
+            count.fetch_sub(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{Matrix, multiply_sequential, multiply_with};
+    use crate::options::MultiplyOptions;
+    use anyhow::Result;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+
+    /// 记录分配和完成情况，全部分配到 0 号线程
+    #[derive(Default)]
+    struct Recording {
+        completed: Mutex<Vec<(usize, usize)>>,
+    }
+
+    impl Scheduler for Recording {
+        fn assign(&self, _task_idx: usize, _num_workers: usize) -> usize {
+            0
+        }
+
+        fn on_complete(&self, task_idx: usize, worker: usize) {
+            self.completed.lock().unwrap().push((task_idx, worker));
+        }
+    }
+
+    #[test]
+    fn test_custom_scheduler() -> Result<()> {
+        let a = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let b = Matrix::new([1, 2, 3, 4, 5, 6], 3, 2);
+        let scheduler = Arc::new(Recording::default());
+        let c = multiply_with(
+            &a,
+            &b,
+            MultiplyOptions::new()
+                .sequential_threshold(0)
+                .scheduler(scheduler.clone()),
+        )?;
+        assert_eq!(c, multiply_sequential(&a, &b)?);
+        let mut completed = scheduler.completed.lock().unwrap().clone();
+        completed.sort();
+        // 默认每行一个任务
+        assert_eq!(completed, [(0, 0), (1, 0)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_builtin_schedulers() -> Result<()> {
+        let a = Matrix::new((0..20).collect::<Vec<i32>>(), 4, 5);
+        let b = Matrix::new((0..15).collect::<Vec<i32>>(), 5, 3);
+        let expected = multiply_sequential(&a, &b)?;
+        let schedulers: [Arc<dyn Scheduler>; 4] = [
+            Arc::new(RoundRobin),
+            Arc::new(RandomScheduler::new()),
+            Arc::new(RandomScheduler::seeded(1)),
+            Arc::new(LeastLoaded::new(2)),
+        ];
+        for scheduler in schedulers {
+            let options = MultiplyOptions::new()
+                .sequential_threshold(0)
+                .scheduler(scheduler);
+            assert_eq!(multiply_with(&a, &b, options)?, expected);
+        }
+
+        let (x, y) = (RandomScheduler::seeded(3), RandomScheduler::seeded(3));
+        let xs = (0..16).map(|i| x.assign(i, 4)).collect::<Vec<_>>();
+        assert_eq!(xs, (0..16).map(|i| y.assign(i, 4)).collect::<Vec<_>>());
+        assert!(xs.iter().all(|&w| w < 4));
+
+        let least = LeastLoaded::new(2);
+        assert_eq!(least.assign(0, 4), 0);
+        assert_eq!(least.assign(1, 4), 1);
+        least.on_complete(0, 0);
+        assert_eq!(least.assign(2, 4), 0);
+
+        // 线程数超过跟踪数时，超出的线程按取模合并后轮流分配
+        let least = LeastLoaded::new(2);
+        let mut workers = (0..4).map(|i| least.assign(i, 4)).collect::<Vec<_>>();
+        workers.sort();
+        assert_eq!(workers, [0, 1, 2, 3]);
+        least.on_complete(0, 3);
+        assert_eq!(least.assign(4, 4), 3);
+        Ok(())
+    }
+}
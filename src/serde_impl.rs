@@ -0,0 +1,96 @@
+use alloc::format;
+use alloc::vec::Vec;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+
+/// 矩阵的序列化表示
+///
+/// 反序列化时先读入该结构，校验 `data` 长度与形状一致后再构造矩阵
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixRepr<T> {
+    row: usize,
+    col: usize,
+    data: Vec<T>,
+}
+
+#[derive(Serialize)]
+#[serde(rename = "Matrix")]
+struct MatrixRef<'a, T> {
+    row: usize,
+    col: usize,
+    data: &'a [T],
+}
+
+impl<T: Serialize> Serialize for Matrix<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        MatrixRef {
+            row: self.row,
+            col: self.col,
+            data: &self.data,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Matrix<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let MatrixRepr { row, col, data } = MatrixRepr::deserialize(deserializer)?;
+        if row.checked_mul(col) != Some(data.len()) {
+            return Err(D::Error::custom(format!(
+                "matrix shape {}x{} does not match {} elements",
+                row,
+                col,
+                data.len()
+            )));
+        }
+        Ok(Matrix { data, row, col })
+    }
+}
+
+/// 向量序列化为普通的元素序列
+impl<T: Serialize> Serialize for Vector<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Vector<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<T>::deserialize(deserializer).map(Vector::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use anyhow::Result;
+
+    #[test]
+    fn test_matrix_serde_round_trip() -> Result<()> {
+        let m = Matrix::new([1, 2, 3, 4, 5, 6], 2, 3);
+        let json = serde_json::to_string(&m)?;
+        assert_eq!(json, r#"{"row":2,"col":3,"data":[1,2,3,4,5,6]}"#);
+        assert_eq!(serde_json::from_str::<Matrix<i32>>(&json)?, m);
+        Ok(())
+    }
+
+    #[test]
+    fn test_matrix_deserialize_rejects_bad_shape() {
+        let json = r#"{"row":2,"col":2,"data":[1,2,3]}"#;
+        assert!(serde_json::from_str::<Matrix<i32>>(json).is_err());
+    }
+
+    #[test]
+    fn test_vector_serde_round_trip() -> Result<()> {
+        let v = Vector::new([1.5, 2.5]);
+        let json = serde_json::to_string(&v)?;
+        assert_eq!(json, "[1.5,2.5]");
+        assert_eq!(*serde_json::from_str::<Vector<f64>>(&json)?, vec![1.5, 2.5]);
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use std::ops::Range;
+use std::sync::{Arc, PoisonError, RwLock};
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// 可在多个线程间并发读写的矩阵
+///
+/// 每一行由独立的读写锁保护，不同线程更新不同的行时互不阻塞，
+/// 适合多个工作线程把部分结果累加到同一个矩阵中。
+/// 句柄可以克隆，克隆体共享同一份数据
+#[derive(Debug, Clone)]
+pub struct SharedMatrix<T> {
+    rows: Arc<[RwLock<Vec<T>>]>,
+    col: usize,
+}
+
+impl<T> SharedMatrix<T> {
+    /// 行数
+    pub fn row(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// 列数
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// 写入 `(i, j)` 处的元素
+    pub fn write_cell(&self, i: usize, j: usize, value: T) -> Result<(), MatrixError> {
+        self.update_cell(i, j, |cell| *cell = value)
+    }
+
+    /// 在持有行锁的情况下修改 `(i, j)` 处的元素，例如 `|v| *v += x` 进行累加
+    pub fn update_cell<R>(
+        &self,
+        i: usize,
+        j: usize,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Result<R, MatrixError> {
+        self.check(i, j)?;
+        let mut row = self.rows[i].write().unwrap_or_else(PoisonError::into_inner);
+        Ok(f(&mut row[j]))
+    }
+
+    /// 按行号顺序锁住 `rows` 区间内的所有行，并以可变切片的形式交给 `f`
+    ///
+    /// 总是按行号从小到大加锁，多个线程锁住重叠的区间时也不会死锁
+    ///
+    /// # 返回值
+    /// 区间超出行数时返回 `MatrixError::IndexOutOfBounds`
+    pub fn with_rows<R>(
+        &self,
+        rows: Range<usize>,
+        f: impl FnOnce(&mut [&mut [T]]) -> R,
+    ) -> Result<R, MatrixError> {
+        if rows.start > rows.end || rows.end > self.rows.len() {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (rows.end, 0),
+                shape: (self.rows.len(), self.col),
+            });
+        }
+        let mut guards: Vec<_> = self.rows[rows]
+            .iter()
+            .map(|row| row.write().unwrap_or_else(PoisonError::into_inner))
+            .collect();
+        let mut slices: Vec<&mut [T]> = guards.iter_mut().map(|g| g.as_mut_slice()).collect();
+        Ok(f(&mut slices))
+    }
+
+    fn check(&self, i: usize, j: usize) -> Result<(), MatrixError> {
+        if i >= self.rows.len() || j >= self.col {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (i, j),
+                shape: (self.rows.len(), self.col),
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: Clone> SharedMatrix<T> {
+    /// 读取 `(i, j)` 处的元素
+    pub fn read_cell(&self, i: usize, j: usize) -> Result<T, MatrixError> {
+        self.check(i, j)?;
+        let row = self.rows[i].read().unwrap_or_else(PoisonError::into_inner);
+        Ok(row[j].clone())
+    }
+
+    /// 逐行复制出当前内容，各行分别加读锁，不保证多行之间的一致性
+    pub fn snapshot(&self) -> Matrix<T> {
+        let mut data = Vec::with_capacity(self.rows.len() * self.col);
+        for row in self.rows.iter() {
+            data.extend_from_slice(&row.read().unwrap_or_else(PoisonError::into_inner));
+        }
+        Matrix {
+            data,
+            row: self.rows.len(),
+            col: self.col,
+        }
+    }
+}
+
+impl<T> From<Matrix<T>> for SharedMatrix<T> {
+    fn from(m: Matrix<T>) -> Self {
+        let Matrix { data, row, col } = m;
+        let mut data = data.into_iter();
+        let rows = (0..row)
+            .map(|_| RwLock::new(data.by_ref().take(col).collect()))
+            .collect();
+        Self { rows, col }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Result;
+    use std::thread;
+
+    #[test]
+    fn test_shared_matrix_accumulate() -> Result<()> {
+        let shared = SharedMatrix::from(Matrix::new(vec![0; 6], 2, 3));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let shared = shared.clone();
+                thread::spawn(move || {
+                    for i in 0..2 {
+                        for j in 0..3 {
+                            shared.update_cell(i, j, |v| *v += i * 3 + j).unwrap();
+                        }
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(shared.snapshot(), Matrix::new([0, 4, 8, 12, 16, 20], 2, 3));
+        assert_eq!(shared.read_cell(1, 2)?, 20);
+        Ok(())
+    }
+
+    #[test]
+    fn test_shared_matrix_with_rows() -> Result<()> {
+        let shared = SharedMatrix::from(Matrix::new([1, 2, 3, 4, 5, 6], 3, 2));
+        shared.with_rows(1..3, |rows| {
+            rows[0].swap(0, 1);
+            rows[1][0] = 50;
+        })?;
+        shared.write_cell(0, 1, 20)?;
+        assert_eq!(shared.snapshot(), Matrix::new([1, 20, 4, 3, 50, 6], 3, 2));
+        assert!(matches!(
+            shared.read_cell(3, 0),
+            Err(MatrixError::IndexOutOfBounds { .. })
+        ));
+        assert!(shared.with_rows(2..4, |_| ()).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,219 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+use num_traits::Zero;
+
+use super::CsrMatrix;
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// 坐标（COO）格式的稀疏矩阵构建器
+///
+/// 按任意顺序逐个追加 `(行, 列, 值)` 三元组，允许同一位置出现多次，
+/// 转换为 CSR 或稠密矩阵时重复的元素会被累加
+///
+/// # 字段
+/// * `rows`: 每个三元组的行号
+/// * `cols`: 每个三元组的列号
+/// * `values`: 每个三元组的值
+/// * `row`: 矩阵行数
+/// * `col`: 矩阵列数
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix<T> {
+    rows: Vec<usize>,
+    cols: Vec<usize>,
+    values: Vec<T>,
+    row: usize,
+    col: usize,
+}
+
+impl<T> CooMatrix<T> {
+    /// 创建空的 `row×col` 稀疏矩阵
+    pub fn new(row: usize, col: usize) -> Self {
+        Self::with_capacity(row, col, 0)
+    }
+
+    /// 创建预留 `capacity` 个三元组空间的空稀疏矩阵
+    pub fn with_capacity(row: usize, col: usize, capacity: usize) -> Self {
+        Self {
+            rows: Vec::with_capacity(capacity),
+            cols: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+            row,
+            col,
+        }
+    }
+
+    /// 追加一个三元组
+    ///
+    /// # 参数
+    /// * `i`: 行号，从 0 开始
+    /// * `j`: 列号，从 0 开始
+    /// * `value`: 元素值，与同一位置已有的值相加
+    ///
+    /// # 返回值
+    /// 位置超出矩阵范围时返回 `MatrixError::IndexOutOfBounds`
+    pub fn push(&mut self, i: usize, j: usize, value: T) -> Result<(), MatrixError> {
+        if i >= self.row || j >= self.col {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (i, j),
+                shape: (self.row, self.col),
+            });
+        }
+        self.rows.push(i);
+        self.cols.push(j);
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// 行数
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// 列数
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// 已追加的三元组个数，包括重复的位置
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 按追加顺序遍历三元组
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, &T)> {
+        self.rows
+            .iter()
+            .zip(&self.cols)
+            .zip(&self.values)
+            .map(|((&i, &j), v)| (i, j, v))
+    }
+}
+
+impl<T: Clone + Zero + AddAssign> CooMatrix<T> {
+    /// 转换为 CSR 矩阵，重复的位置累加为一个元素，每行按列号排序
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        // 按行计数排序，得到每行三元组的起始位置
+        let mut row_ptr = vec![0; self.row + 1];
+        for &i in &self.rows {
+            row_ptr[i + 1] += 1;
+        }
+        for i in 0..self.row {
+            row_ptr[i + 1] += row_ptr[i];
+        }
+        let mut next = row_ptr.clone();
+        let mut order = vec![0; self.values.len()];
+        for (k, &i) in self.rows.iter().enumerate() {
+            order[next[i]] = k;
+            next[i] += 1;
+        }
+
+        let mut values = Vec::with_capacity(self.values.len());
+        let mut col_indices = Vec::with_capacity(self.values.len());
+        let mut out_ptr = Vec::with_capacity(self.row + 1);
+        out_ptr.push(0);
+        for i in 0..self.row {
+            let entries = &mut order[row_ptr[i]..row_ptr[i + 1]];
+            // 稳定排序保证重复元素按追加顺序累加
+            entries.sort_by_key(|&k| self.cols[k]);
+            for &k in entries.iter() {
+                let j = self.cols[k];
+                if col_indices.len() > out_ptr[i] && col_indices.last() == Some(&j) {
+                    if let Some(last) = values.last_mut() {
+                        *last += self.values[k].clone();
+                    }
+                } else {
+                    col_indices.push(j);
+                    values.push(self.values[k].clone());
+                }
+            }
+            out_ptr.push(values.len());
+        }
+        CsrMatrix {
+            values,
+            col_indices,
+            row_ptr: out_ptr,
+            row: self.row,
+            col: self.col,
+        }
+    }
+
+    /// 转换为稠密矩阵，重复的位置累加，未出现的位置为零
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.row, self.col);
+        for (i, j, value) in self.triplets() {
+            m.data[i * self.col + j] += value.clone();
+        }
+        m
+    }
+}
+
+impl<T: Clone + Zero + AddAssign> From<&CooMatrix<T>> for CsrMatrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_csr()
+    }
+}
+
+impl<T: Clone + Zero + AddAssign> From<&CooMatrix<T>> for Matrix<T> {
+    fn from(coo: &CooMatrix<T>) -> Self {
+        coo.to_dense()
+    }
+}
+
+/// 从 CSR 矩阵转换，每个存储的元素成为一个三元组
+impl<T: Clone> From<&CsrMatrix<T>> for CooMatrix<T> {
+    fn from(csr: &CsrMatrix<T>) -> Self {
+        let mut rows = Vec::with_capacity(csr.nnz());
+        for i in 0..csr.row {
+            rows.extend(core::iter::repeat_n(i, csr.row_ptr[i + 1] - csr.row_ptr[i]));
+        }
+        Self {
+            rows,
+            cols: csr.col_indices.clone(),
+            values: csr.values.clone(),
+            row: csr.row,
+            col: csr.col,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coo_sums_duplicates() -> Result<(), MatrixError> {
+        let mut coo = CooMatrix::new(3, 3);
+        coo.push(2, 2, 4)?;
+        coo.push(0, 0, 1)?;
+        coo.push(2, 0, 3)?;
+        coo.push(1, 2, 2)?;
+        coo.push(2, 2, 5)?;
+        assert_eq!(coo.nnz(), 5);
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.values(), &[1, 2, 3, 9]);
+        assert_eq!(csr.col_indices(), &[0, 2, 0, 2]);
+        assert_eq!(csr.row_ptr(), &[0, 1, 2, 4]);
+        assert_eq!(
+            coo.to_dense(),
+            Matrix::new([1, 0, 0, 0, 0, 2, 3, 0, 9], 3, 3)
+        );
+        assert_eq!(CooMatrix::from(&csr).to_csr(), csr);
+        Ok(())
+    }
+
+    #[test]
+    fn test_coo_push_out_of_range() {
+        let mut coo = CooMatrix::new(2, 3);
+        assert_eq!(
+            coo.push(2, 0, 1.0),
+            Err(MatrixError::IndexOutOfBounds {
+                index: (2, 0),
+                shape: (2, 3)
+            })
+        );
+        assert_eq!(coo.nnz(), 0);
+    }
+}
@@ -0,0 +1,201 @@
+use alloc::format;
+use alloc::vec::Vec;
+use num_traits::Zero;
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// 压缩稀疏行（CSR）矩阵
+///
+/// 第 `i` 行的非零元素存放在 `values[row_ptr[i]..row_ptr[i + 1]]`，
+/// 对应的列号存放在 `col_indices` 的同一区间，每行内列号严格递增
+///
+/// # 字段
+/// * `values`: 非零元素
+/// * `col_indices`: 每个非零元素的列号
+/// * `row_ptr`: 每行在 `values` 中的起始位置，长度为行数加一
+/// * `row`: 矩阵行数
+/// * `col`: 矩阵列数
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix<T> {
+    pub(crate) values: Vec<T>,
+    pub(crate) col_indices: Vec<usize>,
+    pub(crate) row_ptr: Vec<usize>,
+    pub(crate) row: usize,
+    pub(crate) col: usize,
+}
+
+impl<T> CsrMatrix<T> {
+    /// 由 CSR 三个数组创建稀疏矩阵
+    ///
+    /// # 参数
+    /// * `row`: 行数
+    /// * `col`: 列数
+    /// * `values`: 非零元素
+    /// * `col_indices`: 每个非零元素的列号
+    /// * `row_ptr`: 每行的起始位置，长度为 `row + 1`
+    ///
+    /// # 返回值
+    /// 数组长度不一致、`row_ptr` 不单调、列号越界或行内列号未严格递增时
+    /// 返回 `MatrixError::InvalidSparse`
+    pub fn new(
+        row: usize,
+        col: usize,
+        values: Vec<T>,
+        col_indices: Vec<usize>,
+        row_ptr: Vec<usize>,
+    ) -> Result<Self, MatrixError> {
+        if row_ptr.len() != row + 1 {
+            return Err(MatrixError::InvalidSparse(format!(
+                "row_ptr has {} entries, expected {}",
+                row_ptr.len(),
+                row + 1
+            )));
+        }
+        if values.len() != col_indices.len() || row_ptr[row] != values.len() {
+            return Err(MatrixError::InvalidSparse(format!(
+                "{} values, {} column indices and row_ptr end {} disagree",
+                values.len(),
+                col_indices.len(),
+                row_ptr[row]
+            )));
+        }
+        if row_ptr[0] != 0 {
+            return Err(MatrixError::InvalidSparse("row_ptr must start at 0".into()));
+        }
+        for i in 0..row {
+            let (start, end) = (row_ptr[i], row_ptr[i + 1]);
+            if start > end || end > values.len() {
+                return Err(MatrixError::InvalidSparse(format!(
+                    "row_ptr is not monotonic at row {}",
+                    i
+                )));
+            }
+            let cols = &col_indices[start..end];
+            if cols.iter().any(|&j| j >= col) {
+                return Err(MatrixError::InvalidSparse(format!(
+                    "column index out of range in row {}",
+                    i
+                )));
+            }
+            if cols.windows(2).any(|w| w[0] >= w[1]) {
+                return Err(MatrixError::InvalidSparse(format!(
+                    "column indices in row {} are not strictly increasing",
+                    i
+                )));
+            }
+        }
+        Ok(Self {
+            values,
+            col_indices,
+            row_ptr,
+            row,
+            col,
+        })
+    }
+
+    /// 行数
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    /// 列数
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    /// 存储的非零元素个数
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 非零元素
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// 非零元素的列号
+    pub fn col_indices(&self) -> &[usize] {
+        &self.col_indices
+    }
+
+    /// 每行的起始位置
+    pub fn row_ptr(&self) -> &[usize] {
+        &self.row_ptr
+    }
+
+    /// 第 `i` 行的列号和非零元素
+    pub(crate) fn row_entries(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.row_ptr[i]..self.row_ptr[i + 1];
+        (&self.col_indices[range.clone()], &self.values[range])
+    }
+}
+
+impl<T: Clone + Zero> CsrMatrix<T> {
+    /// 转换为稠密矩阵，未存储的元素为零
+    pub fn to_dense(&self) -> Matrix<T> {
+        let mut m = Matrix::zeros(self.row, self.col);
+        for i in 0..self.row {
+            let (cols, values) = self.row_entries(i);
+            for (&j, value) in cols.iter().zip(values) {
+                m.data[i * self.col + j] = value.clone();
+            }
+        }
+        m
+    }
+}
+
+/// 从稠密矩阵转换，只保留非零元素
+impl<T: Clone + Zero> From<&Matrix<T>> for CsrMatrix<T> {
+    fn from(m: &Matrix<T>) -> Self {
+        let mut values = Vec::new();
+        let mut col_indices = Vec::new();
+        let mut row_ptr = Vec::with_capacity(m.row + 1);
+        row_ptr.push(0);
+        for i in 0..m.row {
+            for j in 0..m.col {
+                let value = &m.data[i * m.col + j];
+                if !value.is_zero() {
+                    values.push(value.clone());
+                    col_indices.push(j);
+                }
+            }
+            row_ptr.push(values.len());
+        }
+        Self {
+            values,
+            col_indices,
+            row_ptr,
+            row: m.row,
+            col: m.col,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_csr_dense_round_trip() {
+        let m = Matrix::new([1, 0, 0, 0, 0, 2, 3, 0, 4], 3, 3);
+        let csr = CsrMatrix::from(&m);
+        assert_eq!(csr.values(), &[1, 2, 3, 4]);
+        assert_eq!(csr.col_indices(), &[0, 2, 0, 2]);
+        assert_eq!(csr.row_ptr(), &[0, 1, 2, 4]);
+        assert_eq!(csr.to_dense(), m);
+        assert_eq!(
+            CsrMatrix::new(3, 3, vec![1, 2, 3, 4], vec![0, 2, 0, 2], vec![0, 1, 2, 4]),
+            Ok(csr)
+        );
+    }
+
+    #[test]
+    fn test_csr_rejects_invalid_arrays() {
+        assert!(CsrMatrix::new(2, 2, vec![1], vec![0], vec![0, 1]).is_err());
+        assert!(CsrMatrix::new(2, 2, vec![1], vec![2], vec![0, 1, 1]).is_err());
+        assert!(CsrMatrix::new(1, 2, vec![1, 2], vec![1, 0], vec![0, 2]).is_err());
+        assert!(CsrMatrix::new(2, 2, vec![1, 2], vec![0, 1], vec![0, 3, 2]).is_err());
+    }
+}
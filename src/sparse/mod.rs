@@ -0,0 +1,14 @@
+mod coo;
+mod csr;
+#[cfg(feature = "std")]
+mod parallel;
+mod vector;
+
+pub use coo::CooMatrix;
+pub use csr::CsrMatrix;
+#[cfg(feature = "std")]
+pub use parallel::{
+    multiply, multiply_on, multiply_transposed, multiply_transposed_on, spmm, spmm_on, spmv,
+    spmv_on, spmv_sparse, spmv_sparse_on,
+};
+pub use vector::SparseVector;
@@ -0,0 +1,469 @@
+use num_traits::Zero;
+use std::ops::{AddAssign, Mul, Range};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+
+use super::vector::sparse_dot;
+use super::{CsrMatrix, SparseVector};
+use crate::channel::oneshot;
+use crate::error::{MatrixError, WorkerError};
+use crate::matrix::{Matrix, NUM_THREADS};
+use crate::pool::ThreadPool;
+
+/// 稀疏矩阵乘以稠密向量
+///
+/// 行按连续区间平均分配给私有线程池的工作线程，每个区间由一个任务计算
+///
+/// # 参数
+/// * `a`: 稀疏矩阵
+/// * `x`: 向量，长度必须等于 `a` 的列数
+///
+/// # 返回值
+/// 返回Result<Vec<T>, MatrixError>，长度不匹配时返回 `MatrixError::LengthMismatch`
+pub fn spmv<T>(a: &CsrMatrix<T>, x: &[T]) -> Result<Vec<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    spmv_on(a, x, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算稀疏矩阵乘以稠密向量
+///
+/// # 参数
+/// * `a`: 稀疏矩阵
+/// * `x`: 向量，长度必须等于 `a` 的列数
+/// * `pool`: 执行计算的线程池
+pub fn spmv_on<T>(a: &CsrMatrix<T>, x: &[T], pool: &ThreadPool) -> Result<Vec<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.col != x.len() {
+        return Err(MatrixError::LengthMismatch {
+            a: a.col,
+            b: x.len(),
+        });
+    }
+
+    let x = Arc::new(x.to_vec());
+    let blocks = run_row_ranges(a, pool, move |panel, _| {
+        (0..panel.row)
+            .map(|i| {
+                let (cols, values) = panel.row_entries(i);
+                let mut sum = T::zero();
+                for (&j, value) in cols.iter().zip(values) {
+                    sum += value.clone() * x[j].clone();
+                }
+                sum
+            })
+            .collect::<Vec<_>>()
+    })?;
+    Ok(blocks.into_iter().flatten().collect())
+}
+
+/// 稀疏矩阵乘以稀疏向量
+///
+/// 行按连续区间平均分配给私有线程池的工作线程，每行与 `x` 按列号归并求点积，
+/// 只访问两边都非零的位置
+///
+/// # 参数
+/// * `a`: 稀疏矩阵
+/// * `x`: 稀疏向量，长度必须等于 `a` 的列数
+///
+/// # 返回值
+/// 返回稠密的结果向量，长度不匹配时返回 `MatrixError::LengthMismatch`
+pub fn spmv_sparse<T>(a: &CsrMatrix<T>, x: &SparseVector<T>) -> Result<Vec<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    spmv_sparse_on(a, x, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算稀疏矩阵乘以稀疏向量
+///
+/// # 参数
+/// * `a`: 稀疏矩阵
+/// * `x`: 稀疏向量，长度必须等于 `a` 的列数
+/// * `pool`: 执行计算的线程池
+pub fn spmv_sparse_on<T>(
+    a: &CsrMatrix<T>,
+    x: &SparseVector<T>,
+    pool: &ThreadPool,
+) -> Result<Vec<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.col != x.len {
+        return Err(MatrixError::LengthMismatch { a: a.col, b: x.len });
+    }
+
+    let x = Arc::new(x.clone());
+    let blocks = run_row_ranges(a, pool, move |panel, _| {
+        (0..panel.row)
+            .map(|i| {
+                let (cols, values) = panel.row_entries(i);
+                sparse_dot(cols, values, &x.indices, &x.values)
+            })
+            .collect::<Vec<_>>()
+    })?;
+    Ok(blocks.into_iter().flatten().collect())
+}
+
+/// 稀疏矩阵乘以稀疏矩阵
+///
+/// 行按连续区间平均分配给私有线程池的工作线程，
+/// 每行用稠密累加器按 Gustavson 算法计算，结果只包含被累加过的位置
+///
+/// # 参数
+/// * `a`: 左操作数
+/// * `b`: 右操作数
+///
+/// # 返回值
+/// 返回Result<CsrMatrix<T>, MatrixError>，维度不匹配时返回 `MatrixError::DimensionMismatch`
+pub fn spmm<T>(a: &CsrMatrix<T>, b: &CsrMatrix<T>) -> Result<CsrMatrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    spmm_on(a, b, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算稀疏矩阵乘以稀疏矩阵
+///
+/// # 参数
+/// * `a`: 左操作数
+/// * `b`: 右操作数
+/// * `pool`: 执行计算的线程池
+pub fn spmm_on<T>(
+    a: &CsrMatrix<T>,
+    b: &CsrMatrix<T>,
+    pool: &ThreadPool,
+) -> Result<CsrMatrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+
+    let b = Arc::new(b.clone());
+    let n = b.col;
+    let blocks = run_row_ranges(a, pool, move |panel, _| {
+        let mut acc = vec![T::zero(); n];
+        let mut touched = vec![false; n];
+        let mut values = Vec::new();
+        let mut col_indices = Vec::new();
+        let mut row_nnz = Vec::with_capacity(panel.row);
+        for i in 0..panel.row {
+            let start = col_indices.len();
+            let (a_cols, a_values) = panel.row_entries(i);
+            for (&k, a_ik) in a_cols.iter().zip(a_values) {
+                let (b_cols, b_values) = b.row_entries(k);
+                for (&j, b_kj) in b_cols.iter().zip(b_values) {
+                    acc[j] += a_ik.clone() * b_kj.clone();
+                    if !touched[j] {
+                        touched[j] = true;
+                        col_indices.push(j);
+                    }
+                }
+            }
+            col_indices[start..].sort_unstable();
+            for &j in &col_indices[start..] {
+                values.push(std::mem::replace(&mut acc[j], T::zero()));
+                touched[j] = false;
+            }
+            row_nnz.push(col_indices.len() - start);
+        }
+        (values, col_indices, row_nnz)
+    })?;
+
+    let mut out = CsrMatrix {
+        values: Vec::new(),
+        col_indices: Vec::new(),
+        row_ptr: Vec::with_capacity(a.row + 1),
+        row: a.row,
+        col: n,
+    };
+    out.row_ptr.push(0);
+    for (values, col_indices, row_nnz) in blocks {
+        out.values.extend(values);
+        out.col_indices.extend(col_indices);
+        for nnz in row_nnz {
+            out.row_ptr.push(out.row_ptr[out.row_ptr.len() - 1] + nnz);
+        }
+    }
+    Ok(out)
+}
+
+/// 稀疏矩阵乘以稠密矩阵
+///
+/// 稀疏矩阵的行按连续区间平均分配给私有线程池的工作线程，
+/// 结果的第 `i` 行是 `a` 第 `i` 行非零元素对 `b` 中对应行的加权和
+///
+/// # 参数
+/// * `a`: 稀疏左操作数
+/// * `b`: 稠密右操作数
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，维度不匹配时返回 `MatrixError::DimensionMismatch`
+pub fn multiply<T>(a: &CsrMatrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_on(a, b, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算稀疏矩阵乘以稠密矩阵
+///
+/// # 参数
+/// * `a`: 稀疏左操作数
+/// * `b`: 稠密右操作数
+/// * `pool`: 执行计算的线程池
+pub fn multiply_on<T>(
+    a: &CsrMatrix<T>,
+    b: &Matrix<T>,
+    pool: &ThreadPool,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.col != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row, a.col),
+            b: (b.row, b.col),
+        });
+    }
+
+    let n = b.col;
+    let b = Arc::new(b.data.clone());
+    let blocks = run_row_ranges(a, pool, move |panel, _| {
+        let mut out = vec![T::zero(); panel.row * n];
+        for i in 0..panel.row {
+            let (cols, values) = panel.row_entries(i);
+            let out_row = &mut out[i * n..(i + 1) * n];
+            for (&k, a_ik) in cols.iter().zip(values) {
+                for (sum, b_kj) in out_row.iter_mut().zip(&b[k * n..(k + 1) * n]) {
+                    *sum += a_ik.clone() * b_kj.clone();
+                }
+            }
+        }
+        out
+    })?;
+    Ok(Matrix {
+        data: blocks.into_iter().flatten().collect(),
+        row: a.row,
+        col: n,
+    })
+}
+
+/// 稀疏矩阵的转置乘以稠密矩阵，即 `aᵀ · b`，不需要显式构造转置
+///
+/// 稀疏矩阵的行按连续区间分配给工作线程，每个任务把 `a` 的第 `r` 行
+/// 与 `b` 的第 `r` 行的外积累加到自己的部分结果中，最后把各部分结果相加，
+/// 因此额外内存为每个任务一个 `a.col × b.col` 的稠密矩阵
+///
+/// # 参数
+/// * `a`: 稀疏矩阵，行数必须等于 `b` 的行数
+/// * `b`: 稠密矩阵
+///
+/// # 返回值
+/// 返回Result<Matrix<T>, MatrixError>，行数不一致时返回 `MatrixError::DimensionMismatch`
+pub fn multiply_transposed<T>(a: &CsrMatrix<T>, b: &Matrix<T>) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    multiply_transposed_on(a, b, &ThreadPool::new(NUM_THREADS))
+}
+
+/// 在指定线程池上计算 `aᵀ · b`
+///
+/// # 参数
+/// * `a`: 稀疏矩阵，行数必须等于 `b` 的行数
+/// * `b`: 稠密矩阵
+/// * `pool`: 执行计算的线程池
+pub fn multiply_transposed_on<T>(
+    a: &CsrMatrix<T>,
+    b: &Matrix<T>,
+    pool: &ThreadPool,
+) -> Result<Matrix<T>, MatrixError>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+{
+    if a.row != b.row {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.col, a.row),
+            b: (b.row, b.col),
+        });
+    }
+
+    let (m, n) = (a.col, b.col);
+    let b = Arc::new(b.data.clone());
+    let partials = run_row_ranges(a, pool, move |panel, rows| {
+        let mut out = vec![T::zero(); m * n];
+        for (i, r) in rows.enumerate() {
+            let (cols, values) = panel.row_entries(i);
+            let b_row = &b[r * n..(r + 1) * n];
+            for (&k, a_rk) in cols.iter().zip(values) {
+                for (sum, b_rj) in out[k * n..(k + 1) * n].iter_mut().zip(b_row) {
+                    *sum += a_rk.clone() * b_rj.clone();
+                }
+            }
+        }
+        out
+    })?;
+
+    let mut out = Matrix::zeros(m, n);
+    for partial in partials {
+        for (sum, value) in out.data.iter_mut().zip(partial) {
+            *sum += value;
+        }
+    }
+    Ok(out)
+}
+
+/// 将 `a` 的行按连续区间分配给线程池，按区间顺序返回每个任务的结果
+///
+/// 每个任务只复制自己负责的行块，`job` 的第二个参数为该行块在 `a` 中的行区间。
+/// 任务中的 panic 以 `WorkerError` 返回，其 `idx` 为行块的起始行号
+fn run_row_ranges<T, R, F>(
+    a: &CsrMatrix<T>,
+    pool: &ThreadPool,
+    job: F,
+) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(CsrMatrix<T>, Range<usize>) -> R + Send + Sync + 'static,
+{
+    let job = Arc::new(job);
+    let rows_per_job = a.row.div_ceil(pool.size()).max(1);
+    let mut pending = Vec::with_capacity(pool.size());
+    for (worker, start) in (0..a.row).step_by(rows_per_job).enumerate() {
+        let rows = start..(start + rows_per_job).min(a.row);
+        let panel = row_panel(a, rows.clone());
+        let (tx, rx) = oneshot::channel();
+        let job = job.clone();
+        let task = move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| job(panel, rows)))
+                .map_err(|payload| WorkerError::panicked(start, payload));
+            // 调用方已放弃等待时发送失败是正常情况
+            let _ = tx.send(result);
+        };
+        if pool.execute_on(worker, task).is_err() {
+            return Err(WorkerError::disconnected(start).into());
+        }
+        pending.push((start, rx));
+    }
+
+    pending
+        .into_iter()
+        .map(|(start, rx)| match rx.recv() {
+            Ok(result) => result.map_err(MatrixError::from),
+            Err(_) => Err(WorkerError::disconnected(start).into()),
+        })
+        .collect()
+}
+
+/// 复制 `a` 中 `rows` 区间的行，组成一个独立的稀疏矩阵
+fn row_panel<T: Clone>(a: &CsrMatrix<T>, rows: Range<usize>) -> CsrMatrix<T> {
+    let range = a.row_ptr[rows.start]..a.row_ptr[rows.end];
+    CsrMatrix {
+        values: a.values[range.clone()].to_vec(),
+        col_indices: a.col_indices[range.clone()].to_vec(),
+        row_ptr: a.row_ptr[rows.start..=rows.end]
+            .iter()
+            .map(|p| p - range.start)
+            .collect(),
+        row: rows.len(),
+        col: a.col,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{Matrix, multiply};
+    use anyhow::Result;
+
+    fn sample(row: usize, col: usize, seed: i64) -> Matrix<i64> {
+        // 约三分之二的元素为零
+        let data = (0..row * col)
+            .map(|idx| {
+                let v = (idx as i64 * 7 + seed) % 9;
+                if v < 6 { 0 } else { v - 5 }
+            })
+            .collect::<Vec<_>>();
+        Matrix::new(data, row, col)
+    }
+
+    #[test]
+    fn test_spmv() -> Result<()> {
+        let a = sample(9, 5, 1);
+        let x = [1, 2, 3, 4, 5];
+        let y = spmv(&CsrMatrix::from(&a), &x)?;
+        let expected = multiply(&a, &Matrix::new(x, 5, 1))?;
+        assert_eq!(Matrix::new(y, 9, 1), expected);
+
+        assert!(matches!(
+            spmv(&CsrMatrix::from(&a), &[1, 2]),
+            Err(MatrixError::LengthMismatch { a: 5, b: 2 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_spmv_sparse() -> Result<()> {
+        let a = CsrMatrix::from(&sample(9, 6, 2));
+        let dense = [0, 3, 0, 0, 2, 1];
+        let x = SparseVector::from(&dense[..]);
+        assert_eq!(spmv_sparse(&a, &x)?, spmv(&a, &dense)?);
+
+        assert!(matches!(
+            spmv_sparse(&a, &SparseVector::from(&[1, 2][..])),
+            Err(MatrixError::LengthMismatch { a: 6, b: 2 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_multiply_sparse_dense() -> Result<()> {
+        let a = sample(9, 6, 4);
+        let b = Matrix::new((0..24).collect::<Vec<i64>>(), 6, 4);
+        let csr = CsrMatrix::from(&a);
+        assert_eq!(super::multiply(&csr, &b)?, multiply(&a, &b)?);
+
+        let c = Matrix::new((0..36).collect::<Vec<i64>>(), 9, 4);
+        let at = (0..6)
+            .flat_map(|j| (0..9).map(move |i| (i, j)))
+            .map(|(i, j)| a.data[i * 6 + j])
+            .collect::<Vec<_>>();
+        let at = Matrix::new(at, 6, 9);
+        let pool = ThreadPool::new(2);
+        assert_eq!(multiply_transposed_on(&csr, &c, &pool)?, multiply(&at, &c)?);
+
+        assert!(matches!(
+            super::multiply(&csr, &c),
+            Err(MatrixError::DimensionMismatch { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_spmm() -> Result<()> {
+        let a = sample(7, 6, 2);
+        let b = sample(6, 8, 3);
+        let pool = ThreadPool::new(3);
+        let c = spmm_on(&CsrMatrix::from(&a), &CsrMatrix::from(&b), &pool)?;
+        assert_eq!(c.to_dense(), multiply(&a, &b)?);
+        assert!(
+            c.row_ptr()
+                .windows(2)
+                .all(|w| c.col_indices()[w[0]..w[1]].is_sorted())
+        );
+
+        let empty = CsrMatrix::from(&Matrix::<i64>::zeros(0, 6));
+        assert_eq!(spmm(&empty, &CsrMatrix::from(&b))?.row(), 0);
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use alloc::format;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::{AddAssign, Mul};
+use num_traits::Zero;
+
+use crate::error::MatrixError;
+
+/// 稀疏向量，只存储非零元素及其下标
+///
+/// # 字段
+/// * `indices`: 非零元素的下标，严格递增
+/// * `values`: 非零元素
+/// * `len`: 向量长度
+#[derive(Debug, Clone, PartialEq)]
+pub struct SparseVector<T> {
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Vec<T>,
+    pub(crate) len: usize,
+}
+
+impl<T> SparseVector<T> {
+    /// 由下标和非零元素创建稀疏向量
+    ///
+    /// # 参数
+    /// * `len`: 向量长度
+    /// * `indices`: 非零元素的下标
+    /// * `values`: 非零元素
+    ///
+    /// # 返回值
+    /// 两个数组长度不同、下标越界或未严格递增时返回 `MatrixError::InvalidSparse`
+    pub fn new(len: usize, indices: Vec<usize>, values: Vec<T>) -> Result<Self, MatrixError> {
+        if indices.len() != values.len() {
+            return Err(MatrixError::InvalidSparse(format!(
+                "{} indices but {} values",
+                indices.len(),
+                values.len()
+            )));
+        }
+        if indices.iter().any(|&i| i >= len) {
+            return Err(MatrixError::InvalidSparse(format!(
+                "index out of range for length {}",
+                len
+            )));
+        }
+        if indices.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(MatrixError::InvalidSparse(
+                "indices are not strictly increasing".into(),
+            ));
+        }
+        Ok(Self {
+            indices,
+            values,
+            len,
+        })
+    }
+
+    /// 向量长度
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// 长度是否为零
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// 存储的非零元素个数
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 非零元素的下标
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// 非零元素
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+}
+
+impl<T> SparseVector<T>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    /// 转换为稠密向量，未存储的元素为零
+    pub fn to_dense(&self) -> Vec<T> {
+        let mut data = vec![T::zero(); self.len];
+        for (&i, value) in self.indices.iter().zip(&self.values) {
+            data[i] = value.clone();
+        }
+        data
+    }
+
+    /// 与稠密向量的点积，只访问非零元素
+    ///
+    /// # 返回值
+    /// 长度不同时返回 `MatrixError::LengthMismatch`
+    pub fn dot_dense(&self, dense: &[T]) -> Result<T, MatrixError> {
+        if self.len != dense.len() {
+            return Err(MatrixError::LengthMismatch {
+                a: self.len,
+                b: dense.len(),
+            });
+        }
+        let mut sum = T::zero();
+        for (&i, value) in self.indices.iter().zip(&self.values) {
+            sum += value.clone() * dense[i].clone();
+        }
+        Ok(sum)
+    }
+
+    /// 与另一个稀疏向量的点积，按下标归并两个非零序列
+    ///
+    /// # 返回值
+    /// 长度不同时返回 `MatrixError::LengthMismatch`
+    pub fn dot(&self, other: &SparseVector<T>) -> Result<T, MatrixError> {
+        if self.len != other.len {
+            return Err(MatrixError::LengthMismatch {
+                a: self.len,
+                b: other.len,
+            });
+        }
+        Ok(sparse_dot(
+            &self.indices,
+            &self.values,
+            &other.indices,
+            &other.values,
+        ))
+    }
+}
+
+/// 两个按下标严格递增的稀疏序列的点积
+pub(crate) fn sparse_dot<T>(ai: &[usize], av: &[T], bi: &[usize], bv: &[T]) -> T
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    let (mut p, mut q) = (0, 0);
+    let mut sum = T::zero();
+    while p < ai.len() && q < bi.len() {
+        match ai[p].cmp(&bi[q]) {
+            Ordering::Less => p += 1,
+            Ordering::Greater => q += 1,
+            Ordering::Equal => {
+                sum += av[p].clone() * bv[q].clone();
+                p += 1;
+                q += 1;
+            }
+        }
+    }
+    sum
+}
+
+/// 从稠密向量转换，只保留非零元素
+impl<T: Clone + Zero> From<&[T]> for SparseVector<T> {
+    fn from(dense: &[T]) -> Self {
+        let (indices, values) = dense
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| !v.is_zero())
+            .map(|(i, v)| (i, v.clone()))
+            .unzip();
+        Self {
+            indices,
+            values,
+            len: dense.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_vector_dot() -> Result<(), MatrixError> {
+        let a = SparseVector::new(6, vec![0, 2, 5], vec![1, 2, 3])?;
+        let b = SparseVector::from(&[4, 0, 5, 7, 0, 6][..]);
+        assert_eq!(b.indices(), &[0, 2, 3, 5]);
+        assert_eq!(b.to_dense(), vec![4, 0, 5, 7, 0, 6]);
+        assert_eq!(a.dot(&b)?, 4 + 10 + 18);
+        assert_eq!(a.dot_dense(&b.to_dense())?, 32);
+        assert_eq!(
+            a.dot_dense(&[1, 2]),
+            Err(MatrixError::LengthMismatch { a: 6, b: 2 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparse_vector_rejects_invalid() {
+        assert!(SparseVector::new(3, vec![0, 1], vec![1]).is_err());
+        assert!(SparseVector::new(3, vec![3], vec![1]).is_err());
+        assert!(SparseVector::new(3, vec![1, 1], vec![1, 2]).is_err());
+    }
+}
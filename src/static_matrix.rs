@@ -0,0 +1,165 @@
+use alloc::vec::Vec;
+use core::ops::{Add, AddAssign, Index, IndexMut, Mul};
+use num_traits::Zero;
+
+use crate::error::MatrixError;
+use crate::matrix::Matrix;
+
+/// 栈上分配的定长矩阵
+///
+/// 行数 `R` 和列数 `C` 是类型的一部分，乘法和加法的维度在编译期检查，
+/// 运算不分配堆内存，也没有运行时维度检查，适合 3×3、4×4 这类小矩阵
+///
+/// # 泛型参数
+/// * `T`: 矩阵元素类型
+/// * `R`: 行数
+/// * `C`: 列数
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StaticMatrix<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+impl<T, const R: usize, const C: usize> StaticMatrix<T, R, C> {
+    /// 由按行排列的二维数组创建矩阵
+    pub const fn new(data: [[T; C]; R]) -> Self {
+        Self { data }
+    }
+
+    /// 按行排列的元素
+    pub fn rows(&self) -> &[[T; C]; R] {
+        &self.data
+    }
+}
+
+impl<T: Default, const R: usize, const C: usize> Default for StaticMatrix<T, R, C> {
+    fn default() -> Self {
+        Self {
+            data: core::array::from_fn(|_| core::array::from_fn(|_| T::default())),
+        }
+    }
+}
+
+/// 按 `(行, 列)` 访问元素，越界时 panic
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for StaticMatrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        &self.data[i][j]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for StaticMatrix<T, R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        &mut self.data[i][j]
+    }
+}
+
+/// `R×K` 乘以 `K×C` 得到 `R×C`，维度不匹配的乘法无法通过编译
+impl<T, const R: usize, const K: usize, const C: usize> Mul<StaticMatrix<T, K, C>>
+    for StaticMatrix<T, R, K>
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    type Output = StaticMatrix<T, R, C>;
+
+    fn mul(self, rhs: StaticMatrix<T, K, C>) -> Self::Output {
+        StaticMatrix {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| {
+                    let mut sum = T::zero();
+                    for k in 0..K {
+                        sum += self.data[i][k].clone() * rhs.data[k][j].clone();
+                    }
+                    sum
+                })
+            }),
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Add for StaticMatrix<T, R, C>
+where
+    T: Clone + Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            data: core::array::from_fn(|i| {
+                core::array::from_fn(|j| self.data[i][j].clone() + rhs.data[i][j].clone())
+            }),
+        }
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<StaticMatrix<T, R, C>> for Matrix<T> {
+    fn from(m: StaticMatrix<T, R, C>) -> Self {
+        Matrix {
+            data: m.data.into_iter().flatten().collect(),
+            row: R,
+            col: C,
+        }
+    }
+}
+
+/// 从动态矩阵转换，形状不是 `R×C` 时返回 `MatrixError::ShapeMismatch`
+impl<T, const R: usize, const C: usize> TryFrom<Matrix<T>> for StaticMatrix<T, R, C> {
+    type Error = MatrixError;
+
+    fn try_from(m: Matrix<T>) -> Result<Self, Self::Error> {
+        if m.row != R || m.col != C || m.data.len() != R * C {
+            return Err(MatrixError::ShapeMismatch {
+                expected: (R, C),
+                actual: (m.row, m.col),
+            });
+        }
+        let mut values = m.data.into_iter();
+        let rows = core::array::from_fn(|_| {
+            let row: Vec<T> = values.by_ref().take(C).collect();
+            match row.try_into() {
+                Ok(row) => row,
+                Err(_) => unreachable!("length checked above"),
+            }
+        });
+        Ok(Self { data: rows })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply_sequential;
+
+    #[test]
+    fn test_static_matrix_ops() {
+        let a = StaticMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = StaticMatrix::new([[7, 8], [9, 10], [11, 12]]);
+        let c = a * b;
+        assert_eq!(c, StaticMatrix::new([[58, 64], [139, 154]]));
+        assert_eq!(c[(1, 0)], 139);
+        assert_eq!(c + c, StaticMatrix::new([[116, 128], [278, 308]]));
+        assert_eq!(
+            StaticMatrix::<i32, 2, 2>::default(),
+            StaticMatrix::new([[0, 0], [0, 0]])
+        );
+    }
+
+    #[test]
+    fn test_static_matrix_conversions() {
+        let a = StaticMatrix::new([[1, 2, 3], [4, 5, 6]]);
+        let b = StaticMatrix::new([[7, 8], [9, 10], [11, 12]]);
+        let Ok(c) = multiply_sequential(&Matrix::from(a), &Matrix::from(b)) else {
+            panic!("multiply should succeed");
+        };
+        assert_eq!(StaticMatrix::try_from(c), Ok(a * b));
+
+        let wrong = Matrix::new([1, 2, 3, 4], 2, 2);
+        assert_eq!(
+            StaticMatrix::<i32, 4, 1>::try_from(wrong),
+            Err(MatrixError::ShapeMismatch {
+                expected: (4, 1),
+                actual: (2, 2)
+            })
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// 矩阵乘法的执行统计句柄
+///
+/// 通过 `MultiplyOptions::stats` 传入后，乘法结束时可以读取每个工作线程的统计数据，
+/// 用于观察轮询分配造成的负载不均衡。句柄可以克隆，克隆体共享同一份数据，
+/// 每次乘法开始时数据会被重置
+#[derive(Debug, Clone, Default)]
+pub struct MultiplyStats {
+    workers: Arc<Mutex<Vec<WorkerStats>>>,
+}
+
+/// 单个工作线程的统计数据
+///
+/// # 字段
+/// * `tasks`: 执行的任务数
+/// * `compute_time`: 计算耗时总和
+/// * `idle_time`: 乘法总耗时中未在计算的时间
+/// * `queue_wait`: 任务从提交到开始执行的等待时间总和
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WorkerStats {
+    pub tasks: usize,
+    pub compute_time: Duration,
+    pub idle_time: Duration,
+    pub queue_wait: Duration,
+}
+
+impl MultiplyStats {
+    /// 创建空的统计句柄
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 每个工作线程的统计数据快照，下标为工作线程编号
+    pub fn workers(&self) -> Vec<WorkerStats> {
+        self.lock().clone()
+    }
+
+    /// 所有工作线程执行的任务总数
+    pub fn total_tasks(&self) -> usize {
+        self.lock().iter().map(|w| w.tasks).sum()
+    }
+
+    /// 重置为 `num_workers` 个空的工作线程统计
+    pub(crate) fn reset(&self, num_workers: usize) {
+        *self.lock() = vec![WorkerStats::default(); num_workers];
+    }
+
+    /// 记录工作线程执行完一个任务
+    pub(crate) fn record(&self, worker: usize, queue_wait: Duration, compute_time: Duration) {
+        if let Some(stats) = self.lock().get_mut(worker) {
+            stats.tasks += 1;
+            stats.queue_wait += queue_wait;
+            stats.compute_time += compute_time;
+        }
+    }
+
+    /// 乘法结束时根据总耗时计算各线程的空闲时间
+    pub(crate) fn finish(&self, elapsed: Duration) {
+        for stats in self.lock().iter_mut() {
+            stats.idle_time = elapsed.saturating_sub(stats.compute_time);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<WorkerStats>> {
+        self.workers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
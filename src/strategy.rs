@@ -0,0 +1,86 @@
+//! 生成矩阵的 proptest 策略
+//!
+//! 先生成形状再生成元素，收缩时先缩小形状再缩小元素，失败用例会收缩到最小的矩阵。
+//! `prop_compose!` 不支持泛型参数，因此形状由它生成，元素类型由包装函数的策略决定
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::fmt;
+
+use crate::matrix::Matrix;
+
+prop_compose! {
+    /// 行数和列数均取自 `0..=max_dim` 的形状
+    pub fn shape(max_dim: usize)(row in 0..=max_dim, col in 0..=max_dim) -> (usize, usize) {
+        (row, col)
+    }
+}
+
+prop_compose! {
+    /// 可以相乘的形状 `(m, k, n)`，对应 `m×k` 与 `k×n` 两个矩阵，各维度均取自 `0..=max_dim`
+    pub fn multiplicable_shape(max_dim: usize)
+        (m in 0..=max_dim, k in 0..=max_dim, n in 0..=max_dim)
+        -> (usize, usize, usize)
+    {
+        (m, k, n)
+    }
+}
+
+/// 形状为 `row×col`、元素取自 `element` 的矩阵
+pub fn matrix_with_shape<S>(
+    row: usize,
+    col: usize,
+    element: S,
+) -> impl Strategy<Value = Matrix<S::Value>>
+where
+    S: Strategy,
+    S::Value: fmt::Display,
+{
+    vec(element, row * col).prop_map(move |data| Matrix { data, row, col })
+}
+
+/// 行数和列数取自 `0..=max_dim`、元素取自 `element` 的矩阵
+pub fn matrix<S>(max_dim: usize, element: S) -> impl Strategy<Value = Matrix<S::Value>>
+where
+    S: Strategy + Clone,
+    S::Value: fmt::Display,
+{
+    shape(max_dim).prop_flat_map(move |(row, col)| matrix_with_shape(row, col, element.clone()))
+}
+
+/// 可以相乘的一对矩阵 `m×k` 和 `k×n`，三个维度均取自 `0..=max_dim`
+pub fn multiplicable<S>(
+    max_dim: usize,
+    element: S,
+) -> impl Strategy<Value = (Matrix<S::Value>, Matrix<S::Value>)>
+where
+    S: Strategy + Clone,
+    S::Value: fmt::Display,
+{
+    multiplicable_shape(max_dim).prop_flat_map(move |(m, k, n)| {
+        (
+            matrix_with_shape(m, k, element.clone()),
+            matrix_with_shape(k, n, element.clone()),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::{multiply, multiply_seq};
+
+    proptest! {
+        #[test]
+        fn test_matrix_shape(m in matrix(6, -9i64..=9)) {
+            prop_assert!(m.row <= 6 && m.col <= 6);
+            prop_assert_eq!(m.data.len(), m.row * m.col);
+        }
+
+        #[test]
+        fn test_multiply_matches_reference((a, b) in multiplicable(12, -9i64..=9)) {
+            prop_assert_eq!(a.col, b.row);
+            prop_assert_eq!(multiply(&a, &b).unwrap(), multiply_seq(&a, &b).unwrap());
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use anyhow::{Result, anyhow};
+use num_traits::Zero;
+use std::fmt;
+use std::ops::{AddAssign, Mul, Range};
+
+use crate::error::MatrixError;
+use crate::matrix::{Matrix, multiply_into_with};
+use crate::options::MultiplyOptions;
+use crate::pool::ThreadPool;
+
+/// 可以按块读取的矩阵数据源
+///
+/// 流式乘法只通过该 trait 访问输入，因此输入既可以是内存中的矩阵，
+/// 也可以是内存映射文件或按需生成块的自定义数据源
+pub trait BlockSource<T> {
+    /// 矩阵行数
+    fn row(&self) -> usize;
+
+    /// 矩阵列数
+    fn col(&self) -> usize;
+
+    /// 读取一个矩形块
+    fn block(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>>;
+}
+
+/// 可以按块写入的矩阵输出
+pub trait BlockSink<T> {
+    /// 写入一个结果块，块的左上角位于 `(row, col)`
+    fn write_block(&mut self, row: usize, col: usize, block: &Matrix<T>) -> Result<()>;
+}
+
+/// 流式分块矩阵乘法
+///
+/// 依次读取 A 的行块和 B 的列块，在线程池上计算每个结果块并立即写出。
+/// 任意时刻内存中只保留 A、B 各一个块和一个结果块，适合无法整体放入内存的矩阵
+///
+/// # 参数
+/// * `a`: 左操作数数据源
+/// * `b`: 右操作数数据源
+/// * `sink`: 结果输出
+/// * `block_size`: 分块边长，必须大于 0
+/// * `pool`: 执行块乘法的线程池
+///
+/// # 返回值
+/// 返回Result<()>，读取、计算或写出失败时返回错误
+pub fn multiply_streaming<T, A, B, S>(
+    a: &A,
+    b: &B,
+    sink: &mut S,
+    block_size: usize,
+    pool: &ThreadPool,
+) -> Result<()>
+where
+    T: fmt::Debug + Clone + Zero + AddAssign + Mul<Output = T> + Send + Sync + 'static,
+    A: BlockSource<T> + ?Sized,
+    B: BlockSource<T> + ?Sized,
+    S: BlockSink<T> + ?Sized,
+{
+    if a.col() != b.row() {
+        return Err(MatrixError::DimensionMismatch {
+            a: (a.row(), a.col()),
+            b: (b.row(), b.col()),
+        }
+        .into());
+    }
+    if block_size == 0 {
+        return Err(anyhow!(
+            "Streaming multiply error: block_size must be greater than 0"
+        ));
+    }
+
+    for i in (0..a.row()).step_by(block_size) {
+        let rows = i..(i + block_size).min(a.row());
+        for j in (0..b.col()).step_by(block_size) {
+            let cols = j..(j + block_size).min(b.col());
+            let mut acc = Matrix {
+                data: vec![T::zero(); rows.len() * cols.len()],
+                row: rows.len(),
+                col: cols.len(),
+            };
+            let mut partial = Matrix {
+                data: vec![T::zero(); rows.len() * cols.len()],
+                row: rows.len(),
+                col: cols.len(),
+            };
+            // 沿公共维度累加 A[i, k] * B[k, j]
+            for k in (0..a.col()).step_by(block_size) {
+                let inner = k..(k + block_size).min(a.col());
+                let a_block = a.block(rows.clone(), inner.clone())?;
+                let b_block = b.block(inner, cols.clone())?;
+                multiply_into_with(
+                    &a_block,
+                    &b_block,
+                    &mut partial,
+                    MultiplyOptions::new().pool(pool),
+                )?;
+                for (sum, value) in acc.data.iter_mut().zip(&partial.data) {
+                    *sum += value.clone();
+                }
+            }
+            sink.write_block(rows.start, cols.start, &acc)?;
+        }
+    }
+    Ok(())
+}
+
+impl<T: Clone> BlockSource<T> for Matrix<T> {
+    fn row(&self) -> usize {
+        self.row
+    }
+
+    fn col(&self) -> usize {
+        self.col
+    }
+
+    fn block(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>> {
+        if rows.start > rows.end
+            || rows.end > self.row
+            || cols.start > cols.end
+            || cols.end > self.col
+        {
+            return Err(anyhow!(
+                "Matrix block error: {:?}x{:?} out of bounds for {}x{}",
+                rows,
+                cols,
+                self.row,
+                self.col
+            ));
+        }
+        let mut data = Vec::with_capacity(rows.len() * cols.len());
+        for i in rows.clone() {
+            data.extend_from_slice(&self.data[i * self.col + cols.start..i * self.col + cols.end]);
+        }
+        Ok(Matrix {
+            data,
+            row: rows.len(),
+            col: cols.len(),
+        })
+    }
+}
+
+impl<T: Clone> BlockSink<T> for Matrix<T> {
+    fn write_block(&mut self, row: usize, col: usize, block: &Matrix<T>) -> Result<()> {
+        if row + block.row > self.row || col + block.col > self.col {
+            return Err(anyhow!(
+                "Matrix block error: {}x{} block at ({}, {}) out of bounds for {}x{}",
+                block.row,
+                block.col,
+                row,
+                col,
+                self.row,
+                self.col
+            ));
+        }
+        for (i, src) in block
+            .data
+            .chunks(block.col.max(1))
+            .enumerate()
+            .take(block.row)
+        {
+            let start = (row + i) * self.col + col;
+            self.data[start..start + block.col].clone_from_slice(src);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mmap")]
+mod file {
+    use anyhow::{Result, anyhow};
+    use bytemuck::Pod;
+    use std::fs::File;
+    use std::io::{Seek, SeekFrom, Write};
+    use std::marker::PhantomData;
+    use std::ops::Range;
+    use std::path::Path;
+
+    use super::{BlockSink, BlockSource};
+    use crate::matrix::Matrix;
+    use crate::mmap::MmapMatrix;
+
+    /// 将结果块直接写入文件的输出
+    ///
+    /// 文件格式与 `Matrix::from_mmap` 相同：按行优先排列的原始元素，无文件头
+    pub struct FileSink<T> {
+        file: File,
+        row: usize,
+        col: usize,
+        _marker: PhantomData<T>,
+    }
+
+    impl<T: Pod> FileSink<T> {
+        /// 创建（或截断）输出文件，并预先设置为完整矩阵的大小
+        ///
+        /// # 参数
+        /// * `path`: 文件路径
+        /// * `row`: 结果矩阵行数
+        /// * `col`: 结果矩阵列数
+        ///
+        /// # 返回值
+        /// 文件大小超出 `usize` 范围时返回错误，此时不会创建文件
+        pub fn create(path: impl AsRef<Path>, row: usize, col: usize) -> Result<Self> {
+            let len = row
+                .checked_mul(col)
+                .and_then(|len| len.checked_mul(size_of::<T>()))
+                .ok_or_else(|| anyhow!("File sink error: {}x{} is too large", row, col))?;
+            let file = File::create(path)?;
+            file.set_len(len as u64)?;
+            Ok(Self {
+                file,
+                row,
+                col,
+                _marker: PhantomData,
+            })
+        }
+    }
+
+    impl<T: Pod> BlockSink<T> for FileSink<T> {
+        fn write_block(&mut self, row: usize, col: usize, block: &Matrix<T>) -> Result<()> {
+            if row + block.row > self.row || col + block.col > self.col {
+                return Err(anyhow!(
+                    "File sink error: {}x{} block at ({}, {}) out of bounds for {}x{}",
+                    block.row,
+                    block.col,
+                    row,
+                    col,
+                    self.row,
+                    self.col
+                ));
+            }
+            for (i, src) in block
+                .data
+                .chunks(block.col.max(1))
+                .enumerate()
+                .take(block.row)
+            {
+                let offset = ((row + i) * self.col + col) * size_of::<T>();
+                self.file.seek(SeekFrom::Start(offset as u64))?;
+                self.file.write_all(bytemuck::cast_slice(src))?;
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Pod> BlockSource<T> for MmapMatrix<T> {
+        fn row(&self) -> usize {
+            MmapMatrix::row(self)
+        }
+
+        fn col(&self) -> usize {
+            MmapMatrix::col(self)
+        }
+
+        fn block(&self, rows: Range<usize>, cols: Range<usize>) -> Result<Matrix<T>> {
+            MmapMatrix::block(self, rows, cols)
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+pub use file::FileSink;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix::multiply;
+
+    #[test]
+    fn test_multiply_streaming_in_memory() -> Result<()> {
+        let a = Matrix::new((0..35).collect::<Vec<i64>>(), 5, 7);
+        let b = Matrix::new((0..42).collect::<Vec<i64>>(), 7, 6);
+        let mut c = Matrix::new(vec![0; 30], 5, 6);
+        let pool = ThreadPool::new(2);
+        multiply_streaming(&a, &b, &mut c, 3, &pool)?;
+        assert_eq!(c, multiply(&a, &b)?);
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_multiply_streaming_files() -> Result<()> {
+        use crate::mmap::MmapMatrix;
+        use std::fs;
+
+        let dir = std::env::temp_dir();
+        let id = std::process::id();
+        let a_path = dir.join(format!("concurrency-stream-a-{id}.bin"));
+        let b_path = dir.join(format!("concurrency-stream-b-{id}.bin"));
+        let c_path = dir.join(format!("concurrency-stream-c-{id}.bin"));
+
+        let a = Matrix::new((0..20).map(|x| x as f64).collect::<Vec<_>>(), 4, 5);
+        let b = Matrix::new((0..15).map(|x| x as f64).collect::<Vec<_>>(), 5, 3);
+        fs::write(&a_path, bytemuck::cast_slice(&a.data))?;
+        fs::write(&b_path, bytemuck::cast_slice(&b.data))?;
+
+        let a_map = MmapMatrix::<f64>::open(&a_path, 4, 5)?;
+        let b_map = MmapMatrix::<f64>::open(&b_path, 5, 3)?;
+        let mut sink = FileSink::<f64>::create(&c_path, 4, 3)?;
+        multiply_streaming(&a_map, &b_map, &mut sink, 2, &ThreadPool::new(2))?;
+        drop(sink);
+
+        let c = MmapMatrix::<f64>::open(&c_path, 4, 3)?.to_matrix();
+        assert_eq!(c, multiply(&a, &b)?);
+
+        // 大小溢出时报错，且不创建文件
+        let huge_path = dir.join(format!("concurrency-stream-huge-{id}.bin"));
+        assert!(FileSink::<f64>::create(&huge_path, usize::MAX / 2, 3).is_err());
+        assert!(!huge_path.exists());
+
+        for path in [a_path, b_path, c_path] {
+            fs::remove_file(path)?;
+        }
+        Ok(())
+    }
+}
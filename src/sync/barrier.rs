@@ -0,0 +1,122 @@
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// 可重复使用的屏障
+///
+/// 每凑齐 `n` 个调用 `wait` 的线程就一起放行，然后自动进入下一代，
+/// 适合多阶段并行算法（例如分块 LU 分解）在阶段之间同步。
+/// 代数用于区分前后两轮：被唤醒的线程只认自己那一代是否结束，
+/// 即使先放行的线程已经进入下一轮等待也不会互相干扰
+#[derive(Debug)]
+pub struct Barrier {
+    n: usize,
+    state: Mutex<BarrierState>,
+    released: Condvar,
+}
+
+/// # 字段
+/// * `arrived`: 本代已经到达的线程数
+/// * `generation`: 当前代数
+#[derive(Debug, Default)]
+struct BarrierState {
+    arrived: usize,
+    generation: u64,
+}
+
+/// `Barrier::wait` 的结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BarrierWaitResult {
+    leader: bool,
+    generation: u64,
+}
+
+impl BarrierWaitResult {
+    /// 是否为本代最后到达的线程，每一代恰好有一个线程返回 `true`
+    pub fn is_leader(&self) -> bool {
+        self.leader
+    }
+
+    /// 本次放行的代数，从 0 开始
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+}
+
+impl Barrier {
+    /// 创建每代放行 `n` 个线程的屏障，`n` 为 0 时按 1 处理
+    pub fn new(n: usize) -> Self {
+        Self {
+            n: n.max(1),
+            state: Mutex::new(BarrierState::default()),
+            released: Condvar::new(),
+        }
+    }
+
+    /// 阻塞直到本代的 `n` 个线程全部到达
+    pub fn wait(&self) -> BarrierWaitResult {
+        let mut state = self.lock();
+        let generation = state.generation;
+        state.arrived += 1;
+        if state.arrived == self.n {
+            state.arrived = 0;
+            state.generation += 1;
+            self.released.notify_all();
+            return BarrierWaitResult {
+                leader: true,
+                generation,
+            };
+        }
+        while state.generation == generation {
+            state = self
+                .released
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        BarrierWaitResult {
+            leader: false,
+            generation,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, BarrierState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+
+    #[test]
+    fn test_barrier_phases() {
+        const THREADS: usize = 4;
+        const PHASES: usize = 10;
+        let barrier = Arc::new(Barrier::new(THREADS));
+        let done = Arc::new(AtomicUsize::new(0));
+        let leaders = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| {
+                let (barrier, done, leaders) = (barrier.clone(), done.clone(), leaders.clone());
+                thread::spawn(move || {
+                    for phase in 0..PHASES {
+                        done.fetch_add(1, Ordering::SeqCst);
+                        let result = barrier.wait();
+                        assert_eq!(result.generation(), 2 * phase as u64);
+                        // 放行时本阶段所有线程都已完成
+                        assert!(done.load(Ordering::SeqCst) >= (phase + 1) * THREADS);
+                        if result.is_leader() {
+                            leaders.fetch_add(1, Ordering::SeqCst);
+                        }
+                        barrier.wait();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(leaders.load(Ordering::SeqCst), PHASES);
+    }
+}
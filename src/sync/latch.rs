@@ -0,0 +1,88 @@
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+/// 倒计时门闩
+///
+/// 计数减到 0 后所有等待的线程被唤醒，之后 `wait` 立即返回。
+/// 门闩只能使用一次，需要多轮同步时使用 `Barrier`
+#[derive(Debug)]
+pub struct CountdownLatch {
+    count: Mutex<usize>,
+    zero: Condvar,
+}
+
+impl CountdownLatch {
+    /// 创建计数为 `count` 的门闩
+    pub fn new(count: usize) -> Self {
+        Self {
+            count: Mutex::new(count),
+            zero: Condvar::new(),
+        }
+    }
+
+    /// 计数减一，已经为 0 时不变
+    pub fn count_down(&self) {
+        let mut count = self.lock();
+        if *count > 0 {
+            *count -= 1;
+            if *count == 0 {
+                self.zero.notify_all();
+            }
+        }
+    }
+
+    /// 当前计数
+    pub fn count(&self) -> usize {
+        *self.lock()
+    }
+
+    /// 阻塞直到计数为 0
+    pub fn wait(&self) {
+        let mut count = self.lock();
+        while *count > 0 {
+            count = self
+                .zero
+                .wait(count)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// 最多等待 `timeout`，返回计数是否已经为 0
+    pub fn wait_timeout(&self, timeout: Duration) -> bool {
+        let count = self.lock();
+        let (count, _) = self
+            .zero
+            .wait_timeout_while(count, timeout, |count| *count > 0)
+            .unwrap_or_else(PoisonError::into_inner);
+        *count == 0
+    }
+
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.count.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_latch_releases_waiters() {
+        let latch = Arc::new(CountdownLatch::new(3));
+        assert!(!latch.wait_timeout(Duration::from_millis(1)));
+        let waiter = thread::spawn({
+            let latch = latch.clone();
+            move || latch.wait()
+        });
+        for _ in 0..3 {
+            let latch = latch.clone();
+            thread::spawn(move || latch.count_down());
+        }
+        waiter.join().unwrap();
+        assert_eq!(latch.count(), 0);
+        latch.count_down();
+        assert!(latch.wait_timeout(Duration::ZERO));
+    }
+}
@@ -0,0 +1,13 @@
+mod barrier;
+mod latch;
+mod mutex;
+mod rate_limiter;
+mod semaphore;
+mod spin;
+
+pub use barrier::{Barrier, BarrierWaitResult};
+pub use latch::CountdownLatch;
+pub use mutex::{Mutex, MutexGuard};
+pub use rate_limiter::RateLimiter;
+pub use semaphore::{Semaphore, SemaphorePermit};
+pub use spin::{SpinLock, SpinLockGuard};
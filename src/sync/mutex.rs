@@ -0,0 +1,173 @@
+use std::cell::UnsafeCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread::{self, Thread};
+
+use super::SpinLock;
+
+/// 基于线程挂起（park）的互斥锁
+///
+/// 获取锁失败时把当前线程登记到等待队列后挂起，解锁时唤醒队首的线程，
+/// 等待期间不占用 CPU。等待队列本身由 `SpinLock` 保护，其临界区只有入队出队操作。
+/// 被唤醒的线程需要重新竞争锁，因此不保证公平
+pub struct Mutex<T: ?Sized> {
+    locked: AtomicBool,
+    waiters: SpinLock<VecDeque<Thread>>,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: 同一时刻只有持有锁的线程能访问 `value`
+unsafe impl<T: ?Sized + Send> Sync for Mutex<T> {}
+unsafe impl<T: ?Sized + Send> Send for Mutex<T> {}
+
+/// 互斥锁的 RAII 守卫，drop 时解锁并唤醒一个等待线程
+#[must_use = "the lock is released as soon as the guard is dropped"]
+pub struct MutexGuard<'a, T: ?Sized> {
+    mutex: &'a Mutex<T>,
+}
+
+impl<T> Mutex<T> {
+    /// 创建未加锁的互斥锁
+    pub fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            waiters: SpinLock::new(VecDeque::new()),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// 取出被保护的值
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> Mutex<T> {
+    /// 获取锁，锁被占用时挂起当前线程
+    pub fn lock(&self) -> MutexGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_lock() {
+                return guard;
+            }
+            self.waiters.lock().push_back(thread::current());
+            // 入队后再检查一次：若持有者在入队前已经解锁，它不会唤醒我们
+            if let Some(guard) = self.try_lock() {
+                self.forget_current();
+                return guard;
+            }
+            // park 可能被虚假唤醒，也可能因为先前残留的 unpark 立即返回，醒来后一律重新竞争
+            thread::park();
+            self.forget_current();
+        }
+    }
+
+    /// 尝试获取锁，锁被占用时立即返回 `None`
+    pub fn try_lock(&self) -> Option<MutexGuard<'_, T>> {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| MutexGuard { mutex: self })
+    }
+
+    /// 通过独占引用直接访问被保护的值，无需加锁
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+
+    /// 从等待队列中移除当前线程，避免解锁方唤醒一个并未等待的线程
+    fn forget_current(&self) {
+        let id = thread::current().id();
+        self.waiters.lock().retain(|t| t.id() != id);
+    }
+
+    fn unlock(&self) {
+        self.locked.store(false, Ordering::Release);
+        if let Some(waiter) = self.waiters.lock().pop_front() {
+            waiter.unpark();
+        }
+    }
+}
+
+impl<T: Default> Default for Mutex<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Mutex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Mutex")
+            .field("locked", &self.locked.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized> Deref for MutexGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: 守卫存在期间当前线程独占锁
+        unsafe { &*self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for MutexGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: 守卫存在期间当前线程独占锁
+        unsafe { &mut *self.mutex.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for MutexGuard<'_, T> {
+    fn drop(&mut self) {
+        self.mutex.unlock();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_mutex_stress() {
+        let mutex = Arc::new(Mutex::new(0u64));
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let mutex = mutex.clone();
+                thread::spawn(move || {
+                    for _ in 0..200 {
+                        let mut guard = mutex.lock();
+                        // 非原子的读改写，只有互斥成立时结果才正确
+                        let value = *guard;
+                        thread::yield_now();
+                        *guard = value + 1;
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*mutex.lock(), 800);
+    }
+
+    #[test]
+    fn test_mutex_wakes_parked_waiter() {
+        let mutex = Arc::new(Mutex::new(Vec::new()));
+        let guard = mutex.lock();
+        let waiter = thread::spawn({
+            let mutex = mutex.clone();
+            move || mutex.lock().push("waiter")
+        });
+        thread::sleep(Duration::from_millis(20));
+        assert!(!waiter.is_finished());
+        drop(guard);
+        waiter.join().unwrap();
+        assert_eq!(*mutex.lock(), vec!["waiter"]);
+        assert!(mutex.try_lock().is_some());
+    }
+}
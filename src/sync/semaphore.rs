@@ -0,0 +1,122 @@
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+
+/// 计数信号量
+///
+/// 用于限制同时进行的操作数，例如同时在途的矩阵乘法或打开的文件数。
+/// `acquire` 返回的 `SemaphorePermit` 在 drop 时自动归还许可
+#[derive(Debug)]
+pub struct Semaphore {
+    permits: Mutex<usize>,
+    available: Condvar,
+}
+
+/// 信号量许可，drop 时归还
+#[derive(Debug)]
+#[must_use = "the permit is released as soon as it is dropped"]
+pub struct SemaphorePermit<'a> {
+    semaphore: &'a Semaphore,
+}
+
+impl Semaphore {
+    /// 创建拥有 `permits` 个许可的信号量
+    pub fn new(permits: usize) -> Self {
+        Self {
+            permits: Mutex::new(permits),
+            available: Condvar::new(),
+        }
+    }
+
+    /// 获取一个许可，没有可用许可时阻塞等待
+    pub fn acquire(&self) -> SemaphorePermit<'_> {
+        let mut permits = self.lock();
+        while *permits == 0 {
+            permits = self
+                .available
+                .wait(permits)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+        *permits -= 1;
+        SemaphorePermit { semaphore: self }
+    }
+
+    /// 尝试获取一个许可，没有可用许可时立即返回 `None`
+    pub fn try_acquire(&self) -> Option<SemaphorePermit<'_>> {
+        let mut permits = self.lock();
+        if *permits == 0 {
+            return None;
+        }
+        *permits -= 1;
+        Some(SemaphorePermit { semaphore: self })
+    }
+
+    /// 当前可用的许可数
+    pub fn available_permits(&self) -> usize {
+        *self.lock()
+    }
+
+    /// 增加 `n` 个许可
+    pub fn add_permits(&self, n: usize) {
+        *self.lock() += n;
+        self.available.notify_all();
+    }
+
+    fn release(&self) {
+        *self.lock() += 1;
+        self.available.notify_one();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, usize> {
+        self.permits.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Drop for SemaphorePermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_semaphore_bounds_concurrency() {
+        let semaphore = Arc::new(Semaphore::new(2));
+        let active = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                let (semaphore, active, peak) = (semaphore.clone(), active.clone(), peak.clone());
+                thread::spawn(move || {
+                    let _permit = semaphore.acquire();
+                    let now = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(now, Ordering::SeqCst);
+                    thread::sleep(Duration::from_millis(5));
+                    active.fetch_sub(1, Ordering::SeqCst);
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+        assert_eq!(semaphore.available_permits(), 2);
+    }
+
+    #[test]
+    fn test_semaphore_try_acquire() {
+        let semaphore = Semaphore::new(1);
+        let permit = semaphore.try_acquire();
+        assert!(permit.is_some());
+        assert!(semaphore.try_acquire().is_none());
+        drop(permit);
+        assert!(semaphore.try_acquire().is_some());
+        semaphore.add_permits(2);
+        assert_eq!(semaphore.available_permits(), 3);
+    }
+}
@@ -0,0 +1,142 @@
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::hint;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// 自旋锁
+///
+/// 获取锁失败时忙等而不是让出线程，只适合临界区极短的场景。
+/// 加锁使用 `Acquire`、解锁使用 `Release`，保证临界区内的读写不会越过加解锁操作
+pub struct SpinLock<T: ?Sized> {
+    locked: AtomicBool,
+    value: UnsafeCell<T>,
+}
+
+// SAFETY: 同一时刻只有持有锁的线程能访问 `value`，因此只要 `T` 可以在线程间移动，
+// 锁就可以在线程间共享
+unsafe impl<T: ?Sized + Send> Sync for SpinLock<T> {}
+unsafe impl<T: ?Sized + Send> Send for SpinLock<T> {}
+
+/// 自旋锁的 RAII 守卫，drop 时解锁
+#[must_use = "the lock is released as soon as the guard is dropped"]
+pub struct SpinLockGuard<'a, T: ?Sized> {
+    lock: &'a SpinLock<T>,
+}
+
+impl<T> SpinLock<T> {
+    /// 创建未加锁的自旋锁
+    pub const fn new(value: T) -> Self {
+        Self {
+            locked: AtomicBool::new(false),
+            value: UnsafeCell::new(value),
+        }
+    }
+
+    /// 取出被保护的值
+    pub fn into_inner(self) -> T {
+        self.value.into_inner()
+    }
+}
+
+impl<T: ?Sized> SpinLock<T> {
+    /// 获取锁，锁被占用时自旋等待
+    pub fn lock(&self) -> SpinLockGuard<'_, T> {
+        loop {
+            if let Some(guard) = self.try_lock() {
+                return guard;
+            }
+            // 只读等待，避免在锁被占用时反复发起独占的 compare_exchange
+            while self.locked.load(Ordering::Relaxed) {
+                hint::spin_loop();
+            }
+        }
+    }
+
+    /// 尝试获取锁，锁被占用时立即返回 `None`
+    pub fn try_lock(&self) -> Option<SpinLockGuard<'_, T>> {
+        self.locked
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()
+            .map(|_| SpinLockGuard { lock: self })
+    }
+
+    /// 通过独占引用直接访问被保护的值，无需加锁
+    pub fn get_mut(&mut self) -> &mut T {
+        self.value.get_mut()
+    }
+}
+
+impl<T: Default> Default for SpinLock<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for SpinLock<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SpinLock")
+            .field("locked", &self.locked.load(Ordering::Relaxed))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: ?Sized> Deref for SpinLockGuard<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: 守卫存在期间当前线程独占锁
+        unsafe { &*self.lock.value.get() }
+    }
+}
+
+impl<T: ?Sized> DerefMut for SpinLockGuard<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: 守卫存在期间当前线程独占锁
+        unsafe { &mut *self.lock.value.get() }
+    }
+}
+
+impl<T: ?Sized> Drop for SpinLockGuard<'_, T> {
+    fn drop(&mut self) {
+        self.lock.locked.store(false, Ordering::Release);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+
+    #[test]
+    fn test_spin_lock_stress() {
+        let lock = Arc::new(SpinLock::new(Vec::new()));
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let lock = lock.clone();
+                thread::spawn(move || {
+                    for j in 0..1000 {
+                        lock.lock().push(i * 1000 + j);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let mut values = Arc::try_unwrap(lock).unwrap().into_inner();
+        values.sort();
+        assert_eq!(values, (0..8000).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_spin_lock_try_lock() {
+        let lock = SpinLock::new(1);
+        let guard = lock.lock();
+        assert!(lock.try_lock().is_none());
+        drop(guard);
+        *lock.try_lock().unwrap() += 1;
+        assert_eq!(*lock.lock(), 2);
+    }
+}
@@ -0,0 +1,171 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Receiver, Sender};
+
+use crate::error::WorkerError;
+use crate::pool::ThreadPool;
+
+/// 单个任务的结果，任务 panic 或线程池已退出时为 `WorkerError`，其 `idx` 为任务编号
+pub type TaskResult<R> = Result<R, WorkerError>;
+
+/// 任务组
+///
+/// 在线程池上提交一批任务，之后按完成顺序逐个取回结果，
+/// 取代手工维护 `Vec<Receiver>` 的写法。任务编号从 0 开始按提交顺序分配。
+/// 任务组被 drop 时会等待所有已提交的任务结束，因此任务不会比任务组活得更久
+///
+/// ```
+/// use concurrency::{TaskGroup, ThreadPool};
+///
+/// let pool = ThreadPool::new(2);
+/// let mut group = TaskGroup::new(&pool);
+/// for i in 0..4 {
+///     group.spawn(move || i * i);
+/// }
+/// let results = group.join_all();
+/// assert_eq!(results.into_iter().map(Result::unwrap).collect::<Vec<_>>(), [0, 1, 4, 9]);
+/// ```
+pub struct TaskGroup<'a, R> {
+    pool: &'a ThreadPool,
+    tx: Sender<(usize, TaskResult<R>)>,
+    rx: Receiver<(usize, TaskResult<R>)>,
+    spawned: usize,
+    pending: usize,
+}
+
+impl<'a, R: Send + 'static> TaskGroup<'a, R> {
+    /// 创建在 `pool` 上执行任务的空任务组
+    pub fn new(pool: &'a ThreadPool) -> Self {
+        let (tx, rx) = mpsc::channel();
+        Self {
+            pool,
+            tx,
+            rx,
+            spawned: 0,
+            pending: 0,
+        }
+    }
+
+    /// 提交任务，返回任务编号
+    pub fn spawn<F>(&mut self, job: F) -> usize
+    where
+        F: FnOnce() -> R + Send + 'static,
+    {
+        let id = self.spawned;
+        self.spawned += 1;
+        self.pending += 1;
+        let tx = self.tx.clone();
+        let task = move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(job))
+                .map_err(|payload| WorkerError::panicked(id, payload));
+            let _ = tx.send((id, result));
+        };
+        if self.pool.execute(task).is_err() {
+            let _ = self.tx.send((id, Err(WorkerError::disconnected(id))));
+        }
+        id
+    }
+
+    /// 尚未取回结果的任务数
+    pub fn len(&self) -> usize {
+        self.pending
+    }
+
+    /// 是否所有任务的结果都已取回
+    pub fn is_empty(&self) -> bool {
+        self.pending == 0
+    }
+
+    /// 阻塞等待下一个完成的任务，返回其编号和结果；没有未完成的任务时返回 `None`
+    pub fn join_next(&mut self) -> Option<(usize, TaskResult<R>)> {
+        if self.pending == 0 {
+            return None;
+        }
+        // 自身持有一个发送端，所以接收不会因为通道断开而失败
+        let next = self.rx.recv().ok()?;
+        self.pending -= 1;
+        Some(next)
+    }
+
+    /// 等待所有未完成的任务，按任务编号顺序返回它们的结果
+    pub fn join_all(&mut self) -> Vec<TaskResult<R>> {
+        let mut results: Vec<_> = self.by_ref().collect();
+        results.sort_by_key(|(id, _)| *id);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+}
+
+/// 按完成顺序迭代任务结果
+impl<R: Send + 'static> Iterator for TaskGroup<'_, R> {
+    type Item = (usize, TaskResult<R>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.join_next()
+    }
+}
+
+impl<R> Drop for TaskGroup<'_, R> {
+    fn drop(&mut self) {
+        for _ in 0..self.pending {
+            if self.rx.recv().is_err() {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::WorkerErrorKind;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use std::time::Duration;
+
+    #[test]
+    fn test_task_group_completion_order() {
+        let pool = ThreadPool::new(2);
+        let mut group = TaskGroup::new(&pool);
+        let slow = group.spawn(|| {
+            thread::sleep(Duration::from_millis(50));
+            "slow"
+        });
+        let fast = group.spawn(|| "fast");
+        assert_eq!(group.len(), 2);
+        assert_eq!(
+            group.join_next().map(|(id, r)| (id, r.unwrap())),
+            Some((fast, "fast"))
+        );
+        assert_eq!(
+            group.join_next().map(|(id, r)| (id, r.unwrap())),
+            Some((slow, "slow"))
+        );
+        assert!(group.join_next().is_none());
+    }
+
+    #[test]
+    fn test_task_group_panic_and_drop() {
+        let pool = ThreadPool::new(2);
+        let mut group = TaskGroup::new(&pool);
+        group.spawn(|| 1);
+        group.spawn(|| panic!("task failed"));
+        let results = group.join_all();
+        assert_eq!(results[0], Ok(1));
+        assert!(matches!(
+            &results[1],
+            Err(WorkerError { idx: 1, kind: WorkerErrorKind::Panicked(m) }) if m == "task failed"
+        ));
+
+        let done = Arc::new(AtomicUsize::new(0));
+        let mut group = TaskGroup::new(&pool);
+        for _ in 0..8 {
+            let done = done.clone();
+            group.spawn(move || {
+                thread::sleep(Duration::from_millis(5));
+                done.fetch_add(1, Ordering::SeqCst);
+            });
+        }
+        drop(group);
+        assert_eq!(done.load(Ordering::SeqCst), 8);
+    }
+}
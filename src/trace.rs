@@ -0,0 +1,131 @@
+use std::fmt::Write;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+
+/// 矩阵乘法的调度跟踪句柄
+///
+/// 通过 `MultiplyOptions::trace` 传入后，每个任务执行完毕时记录一条 `TraceEvent`，
+/// 可据此绘制甘特图，直观地看到轮询分配造成的负载不均衡。
+/// 句柄可以克隆，克隆体共享同一份数据，每次乘法开始时数据会被重置
+#[derive(Debug, Clone, Default)]
+pub struct MultiplyTrace {
+    inner: Arc<Mutex<TraceInner>>,
+}
+
+#[derive(Debug, Default)]
+struct TraceInner {
+    origin: Option<Instant>,
+    events: Vec<TraceEvent>,
+}
+
+/// 一个任务的执行记录
+///
+/// # 字段
+/// * `task`: 任务索引，与 `Scheduler::assign` 收到的索引一致
+/// * `worker`: 执行任务的工作线程编号
+/// * `start`: 开始执行的时刻，相对于乘法开始
+/// * `end`: 执行完毕的时刻，相对于乘法开始
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TraceEvent {
+    pub task: usize,
+    pub worker: usize,
+    pub start: Duration,
+    pub end: Duration,
+}
+
+impl MultiplyTrace {
+    /// 创建空的跟踪句柄
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 按开始时间排序的事件快照
+    pub fn events(&self) -> Vec<TraceEvent> {
+        let mut events = self.lock().events.clone();
+        events.sort_by_key(|e| (e.start, e.task));
+        events
+    }
+
+    /// 以文本甘特图显示各工作线程的忙碌区间，每个工作线程一行，`#` 表示正在执行任务
+    ///
+    /// # 参数
+    /// * `width`: 时间轴的字符数，为 0 时按 1 处理
+    pub fn gantt(&self, width: usize) -> String {
+        let events = self.events();
+        let width = width.max(1);
+        let workers = events.iter().map(|e| e.worker + 1).max().unwrap_or(0);
+        let total = events.iter().map(|e| e.end).max().unwrap_or_default();
+        let column = |t: Duration| {
+            let scaled = t.as_secs_f64() / total.as_secs_f64().max(f64::MIN_POSITIVE);
+            ((scaled * width as f64) as usize).min(width)
+        };
+
+        let mut out = String::new();
+        for worker in 0..workers {
+            let mut line = vec![b'.'; width];
+            for e in events.iter().filter(|e| e.worker == worker) {
+                // 极短的任务也至少占一个字符
+                let start = column(e.start).min(width - 1);
+                let end = column(e.end).max(start + 1);
+                line[start..end].fill(b'#');
+            }
+            let _ = writeln!(out, "w{:<3}|{}|", worker, String::from_utf8_lossy(&line));
+        }
+        out
+    }
+
+    /// 清空事件，并把 `origin` 作为之后记录的时间零点
+    pub(crate) fn reset(&self, origin: Instant) {
+        let mut inner = self.lock();
+        inner.origin = Some(origin);
+        inner.events.clear();
+    }
+
+    /// 记录任务在 `worker` 号工作线程上从 `start` 执行到 `end`
+    pub(crate) fn record(&self, task: usize, worker: usize, start: Instant, end: Instant) {
+        let mut inner = self.lock();
+        let origin = inner.origin.unwrap_or(start);
+        inner.events.push(TraceEvent {
+            task,
+            worker,
+            start: start.saturating_duration_since(origin),
+            end: end.saturating_duration_since(origin),
+        });
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TraceInner> {
+        self.inner.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trace_events_and_gantt() {
+        let trace = MultiplyTrace::new();
+        let origin = Instant::now();
+        trace.reset(origin);
+        let ms = Duration::from_millis;
+        trace.record(1, 1, origin + ms(5), origin + ms(10));
+        trace.record(0, 0, origin, origin + ms(5));
+
+        let events = trace.events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(
+            events[0],
+            TraceEvent {
+                task: 0,
+                worker: 0,
+                start: Duration::ZERO,
+                end: ms(5)
+            }
+        );
+        assert_eq!(trace.gantt(10), "w0  |#####.....|\nw1  |.....#####|\n");
+
+        trace.reset(Instant::now());
+        assert!(trace.events().is_empty());
+        assert_eq!(trace.gantt(10), "");
+    }
+}
@@ -1,5 +1,19 @@
-use anyhow::{Result, anyhow};
-use std::ops::{Add, AddAssign, Deref, Mul};
+use alloc::vec::Vec;
+use core::ops::{AddAssign, Deref, Mul};
+use num_traits::Zero;
+
+use crate::error::MatrixError;
+
+#[cfg(feature = "std")]
+mod elementwise;
+#[cfg(feature = "std")]
+mod sort;
+mod split;
+#[cfg(feature = "std")]
+mod stats;
+
+#[cfg(feature = "std")]
+pub use stats::DEFAULT_PARALLEL_THRESHOLD;
 
 pub struct Vector<T> {
     data: Vec<T>,
@@ -7,21 +21,31 @@ pub struct Vector<T> {
 
 // pretend this is a heavy operation, CPU intensive
 // 假装这是一个繁重的操作，CPU密集型的
-pub fn dot_product<T>(a: Vector<T>, b: Vector<T>) -> Result<T>
+pub fn dot_product<T>(a: Vector<T>, b: Vector<T>) -> Result<T, MatrixError>
 where
-    T: Copy + Default + Add<Output = T> + AddAssign + Mul<Output = T>,
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
 {
     if a.len() != b.len() {
         // a.len => a.data.len() (Deref trait)
-        return Err(anyhow!("Dot product error: a.len != b.len"));
+        return Err(MatrixError::LengthMismatch {
+            a: a.len(),
+            b: b.len(),
+        });
     }
 
-    let mut sum = T::default();
-    for i in 0..a.len() {
-        sum += a[i] * b[i];
-    }
+    Ok(dot(&a, &b))
+}
 
-    Ok(sum)
+/// 切片点积，调用方保证两个切片长度相同
+pub(crate) fn dot<T>(a: &[T], b: &[T]) -> T
+where
+    T: Clone + Zero + AddAssign + Mul<Output = T>,
+{
+    let mut sum = T::zero();
+    for (x, y) in a.iter().zip(b) {
+        sum += x.clone() * y.clone();
+    }
+    sum
 }
 
 impl<T> Deref for Vector<T> {
@@ -36,4 +60,10 @@ impl<T> Vector<T> {
     pub fn new(data: impl Into<Vec<T>>) -> Self {
         Self { data: data.into() }
     }
+
+    /// 取出底层数据
+    #[cfg(feature = "std")]
+    pub(crate) fn into_inner(self) -> Vec<T> {
+        self.data
+    }
 }
@@ -0,0 +1,101 @@
+use alloc::vec::Vec;
+use std::sync::Arc;
+
+use super::{DEFAULT_PARALLEL_THRESHOLD, Vector};
+use crate::error::MatrixError;
+use crate::matrix::NUM_THREADS;
+use crate::par;
+use crate::pool::ThreadPool;
+
+impl<T> Vector<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    /// 对每个元素调用 `f`，返回新向量
+    ///
+    /// 长度达到 `DEFAULT_PARALLEL_THRESHOLD` 时按连续区间在线程池上并行计算
+    ///
+    /// # 返回值
+    /// `f` panic 时返回 `MatrixError::WorkerFailed`
+    pub fn map<R, F>(&self, f: F) -> Result<Vector<R>, MatrixError>
+    where
+        R: Send + 'static,
+        F: Fn(&T) -> R + Send + Sync + 'static,
+    {
+        if self.data.len() < DEFAULT_PARALLEL_THRESHOLD {
+            return Ok(Vector::new(self.data.iter().map(f).collect::<Vec<_>>()));
+        }
+        par::map_on(&self.data, f, &ThreadPool::new(NUM_THREADS)).map(Vector::new)
+    }
+
+    /// 对两个向量相同位置的元素调用 `f`，返回新向量
+    ///
+    /// 长度达到 `DEFAULT_PARALLEL_THRESHOLD` 时按连续区间在线程池上并行计算
+    ///
+    /// # 返回值
+    /// 长度不同时返回 `MatrixError::LengthMismatch`，`f` panic 时返回 `MatrixError::WorkerFailed`
+    pub fn zip_with<R, F>(&self, other: &Vector<T>, f: F) -> Result<Vector<R>, MatrixError>
+    where
+        R: Send + 'static,
+        F: Fn(&T, &T) -> R + Send + Sync + 'static,
+    {
+        if self.data.len() != other.data.len() {
+            return Err(MatrixError::LengthMismatch {
+                a: self.data.len(),
+                b: other.data.len(),
+            });
+        }
+        if self.data.len() < DEFAULT_PARALLEL_THRESHOLD {
+            let data = self.data.iter().zip(&other.data).map(|(a, b)| f(a, b));
+            return Ok(Vector::new(data.collect::<Vec<_>>()));
+        }
+
+        let pool = ThreadPool::new(NUM_THREADS);
+        let f = Arc::new(f);
+        let chunk_len = self.data.len().div_ceil(pool.size());
+        let tasks = self
+            .data
+            .chunks(chunk_len)
+            .zip(other.data.chunks(chunk_len))
+            .enumerate()
+            .map(|(i, (a, b))| {
+                let (a, b, f) = (a.to_vec(), b.to_vec(), f.clone());
+                (i * chunk_len, move || {
+                    a.iter().zip(&b).map(|(x, y)| f(x, y)).collect::<Vec<_>>()
+                })
+            });
+        let chunks = par::run_tasks(&pool, tasks)?;
+        Ok(Vector::new(
+            chunks.into_iter().flatten().collect::<Vec<_>>(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_zip_with() -> anyhow::Result<()> {
+        let a = Vector::new([1, 2, 3]);
+        let b = Vector::new([10, 20, 30]);
+        assert_eq!(*a.map(|x| x * x)?, [1, 4, 9]);
+        assert_eq!(*a.zip_with(&b, |x, y| x + y)?, [11, 22, 33]);
+        assert_eq!(
+            a.zip_with(&Vector::new([1]), |x, y| x + y).err(),
+            Some(MatrixError::LengthMismatch { a: 3, b: 1 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_map_zip_with_parallel() -> anyhow::Result<()> {
+        let n = DEFAULT_PARALLEL_THRESHOLD * 2 + 3;
+        let a = Vector::new((0..n as u64).collect::<Vec<_>>());
+        let b = a.map(|x| x * 2)?;
+        assert_eq!(*b, (0..n as u64).map(|x| x * 2).collect::<Vec<_>>());
+        let c = b.zip_with(&a, |x, y| x - y)?;
+        assert_eq!(*c, *a);
+        Ok(())
+    }
+}
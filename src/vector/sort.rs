@@ -0,0 +1,72 @@
+use alloc::vec::Vec;
+
+use super::Vector;
+use crate::error::MatrixError;
+use crate::par;
+
+impl<T: Ord + Send> Vector<T> {
+    /// 使用线程池归并排序对元素进行稳定排序
+    ///
+    /// # 返回值
+    /// 比较时 panic 返回 `MatrixError::WorkerFailed`，此时向量中的元素不变但顺序未定义
+    pub fn par_sort(&mut self) -> Result<(), MatrixError> {
+        par::sort(&mut self.data)
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Ord + Clone + Send + 'static,
+{
+    /// 返回使向量有序的下标排列，`self[order[0]]` 为最小元素，相等的元素保持原有先后顺序
+    pub fn argsort(&self) -> Result<Vector<usize>, MatrixError> {
+        sorted_order(self.data.clone()).map(Vector::new)
+    }
+}
+
+impl<T: Clone> Vector<T> {
+    /// 按 `key` 计算的键使用线程池归并排序进行稳定排序
+    ///
+    /// 每个元素的键只计算一次，排序完成后按得到的下标排列重排元素
+    pub fn par_sort_by_key<K, F>(&mut self, key: F) -> Result<(), MatrixError>
+    where
+        K: Ord + Clone + Send + 'static,
+        F: Fn(&T) -> K,
+    {
+        let order = sorted_order(self.data.iter().map(key).collect())?;
+        self.data = order.iter().map(|&i| self.data[i].clone()).collect();
+        Ok(())
+    }
+}
+
+/// 把键与下标配对后并行排序，返回排序后的下标；下标参与比较，因此结果是稳定的
+fn sorted_order<K>(keys: Vec<K>) -> Result<Vec<usize>, MatrixError>
+where
+    K: Ord + Clone + Send + 'static,
+{
+    let mut pairs = keys.into_iter().zip(0..).collect::<Vec<(K, usize)>>();
+    par::sort(&mut pairs)?;
+    Ok(pairs.into_iter().map(|(_, i)| i).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_par_sort() -> anyhow::Result<()> {
+        let mut v = Vector::new([5, 3, 9, 1, 3, 7, 0, 2]);
+        assert_eq!(*v.argsort()?, [6, 3, 7, 1, 4, 0, 5, 2]);
+        v.par_sort()?;
+        assert_eq!(*v, [0, 1, 2, 3, 3, 5, 7, 9]);
+
+        let mut words = Vector::new(["pear", "fig", "apple", "kiwi", "plum"]);
+        words.par_sort_by_key(|w| w.len())?;
+        assert_eq!(*words, ["fig", "pear", "kiwi", "plum", "apple"]);
+
+        let mut empty = Vector::<i32>::new([]);
+        empty.par_sort()?;
+        assert!(empty.argsort()?.is_empty());
+        Ok(())
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+
+use super::Vector;
+use crate::error::MatrixError;
+
+impl<T: Clone> Vector<T> {
+    /// 按顺序拼接多个向量
+    pub fn concat(parts: &[Vector<T>]) -> Self {
+        let mut data = Vec::with_capacity(parts.iter().map(|v| v.data.len()).sum());
+        for part in parts {
+            data.extend_from_slice(&part.data);
+        }
+        Self { data }
+    }
+
+    /// 按长度 `size` 切分成多个向量，最后一个可能更短，`size` 为 0 时按 1 处理
+    ///
+    /// 每一块都是独立的向量，可以直接交给不同的线程处理
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vector<T>> + '_ {
+        self.data.chunks(size.max(1)).map(Vector::new)
+    }
+}
+
+impl<T> Vector<T> {
+    /// 在下标 `n` 处拆分为 `[0, n)` 和 `[n, len)` 两个向量，不复制元素
+    ///
+    /// # 返回值
+    /// `n` 大于长度时返回 `MatrixError::IndexOutOfBounds`，形状按 `len×1` 的列向量给出
+    pub fn split_at(mut self, n: usize) -> Result<(Vector<T>, Vector<T>), MatrixError> {
+        if n > self.data.len() {
+            return Err(MatrixError::IndexOutOfBounds {
+                index: (n, 0),
+                shape: (self.data.len(), 1),
+            });
+        }
+        let tail = self.data.split_off(n);
+        Ok((self, Vector { data: tail }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_concat_split_chunks() -> anyhow::Result<()> {
+        let v = Vector::concat(&[Vector::new([1, 2]), Vector::new([]), Vector::new([3, 4, 5])]);
+        assert_eq!(*v, [1, 2, 3, 4, 5]);
+
+        let chunks = v.chunks(2).collect::<Vec<_>>();
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(*chunks[2], [5]);
+        assert_eq!(v.chunks(0).count(), 5);
+
+        let (head, tail) = v.split_at(3)?;
+        assert_eq!(*head, [1, 2, 3]);
+        assert_eq!(*tail, [4, 5]);
+        assert_eq!(
+            tail.split_at(3).err(),
+            Some(MatrixError::IndexOutOfBounds {
+                index: (3, 0),
+                shape: (2, 1)
+            })
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,160 @@
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::AddAssign;
+use num_traits::{Float, Zero};
+
+use super::Vector;
+use crate::error::MatrixError;
+use crate::matrix::NUM_THREADS;
+use crate::par;
+use crate::pool::ThreadPool;
+
+/// 长度达到该值时统计量改为在线程池上分块并行计算
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 1 << 14;
+
+impl<T> Vector<T>
+where
+    T: Clone + Zero + AddAssign + Send + Sync + 'static,
+{
+    /// 元素之和，空向量返回零
+    ///
+    /// # 返回值
+    /// 返回Result<T, MatrixError>，并行计算失败时返回 `MatrixError::WorkerFailed`
+    pub fn sum(&self) -> Result<T, MatrixError> {
+        let partials = chunked(&self.data, sum_slice)?;
+        Ok(sum_slice(&partials))
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Float + AddAssign + Send + Sync + 'static,
+{
+    /// 算术平均值，空向量返回 `None`
+    pub fn mean(&self) -> Result<Option<T>, MatrixError> {
+        if self.data.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(self.sum()? / len_as(self.data.len())))
+    }
+
+    /// 总体方差（除以 `n`），空向量返回 `None`
+    ///
+    /// 先求平均值，再分块累加与平均值之差的平方，避免 `E[x²] - E[x]²` 的相消误差
+    pub fn variance(&self) -> Result<Option<T>, MatrixError> {
+        let Some(mean) = self.mean()? else {
+            return Ok(None);
+        };
+        let partials = chunked(&self.data, move |chunk| {
+            let mut sum = T::zero();
+            for &x in chunk {
+                sum += (x - mean) * (x - mean);
+            }
+            sum
+        })?;
+        Ok(Some(sum_slice(&partials) / len_as(self.data.len())))
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: PartialOrd + Clone + Send + Sync + 'static,
+{
+    /// 最小值，空向量返回 `None`，相等时取第一个
+    pub fn min(&self) -> Result<Option<T>, MatrixError> {
+        self.best(|x, best| x < best)
+    }
+
+    /// 最大值，空向量返回 `None`，相等时取第一个
+    pub fn max(&self) -> Result<Option<T>, MatrixError> {
+        self.best(|x, best| x > best)
+    }
+
+    /// 先在各区间内选出最优元素，再按区间顺序合并
+    fn best(&self, better: fn(&T, &T) -> bool) -> Result<Option<T>, MatrixError> {
+        let partials = chunked(&self.data, move |chunk| best_of(chunk.iter(), better))?;
+        Ok(best_of(partials.iter().flatten(), better))
+    }
+}
+
+fn best_of<'a, T: Clone + 'a>(
+    values: impl Iterator<Item = &'a T>,
+    better: fn(&T, &T) -> bool,
+) -> Option<T> {
+    let mut best: Option<&T> = None;
+    for x in values {
+        if best.is_none_or(|b| better(x, b)) {
+            best = Some(x);
+        }
+    }
+    best.cloned()
+}
+
+fn sum_slice<T: Clone + Zero + AddAssign>(values: &[T]) -> T {
+    let mut sum = T::zero();
+    for x in values {
+        sum += x.clone();
+    }
+    sum
+}
+
+fn len_as<T: Float>(len: usize) -> T {
+    T::from(len).unwrap_or_else(T::infinity)
+}
+
+/// 对每个连续区间调用 `f`，按区间顺序返回结果
+///
+/// 长度低于 `DEFAULT_PARALLEL_THRESHOLD` 时整个切片作为一个区间在当前线程上计算
+fn chunked<T, R, F>(data: &[T], f: F) -> Result<Vec<R>, MatrixError>
+where
+    T: Clone + Send + 'static,
+    R: Send + 'static,
+    F: Fn(&[T]) -> R + Send + Sync + 'static,
+{
+    if data.len() < DEFAULT_PARALLEL_THRESHOLD {
+        return Ok(vec![f(data)]);
+    }
+    par::run_chunks(data, &ThreadPool::new(NUM_THREADS), move |chunk| f(&chunk))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vector_stats() -> anyhow::Result<()> {
+        let v = Vector::new([2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!(v.sum()?, 40.0);
+        assert_eq!(v.mean()?, Some(5.0));
+        assert_eq!(v.variance()?, Some(4.0));
+        assert_eq!(v.min()?, Some(2.0));
+        assert_eq!(v.max()?, Some(9.0));
+
+        let empty = Vector::<f64>::new([]);
+        assert_eq!(empty.sum()?, 0.0);
+        assert_eq!(empty.mean()?, None);
+        assert_eq!(empty.variance()?, None);
+        assert_eq!(empty.max()?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_vector_stats_parallel() -> anyhow::Result<()> {
+        let n = DEFAULT_PARALLEL_THRESHOLD * 3 + 1;
+        let v = Vector::new(
+            (0..n as i64)
+                .map(|x| (x * 7919) % n as i64)
+                .collect::<Vec<_>>(),
+        );
+        assert_eq!(v.sum()?, (n as i64 - 1) * n as i64 / 2);
+        assert_eq!(v.min()?, Some(0));
+        assert_eq!(v.max()?, Some(n as i64 - 1));
+
+        let f = Vector::new((0..n).map(|x| x as f64).collect::<Vec<_>>());
+        let mean = (n - 1) as f64 / 2.0;
+        assert_eq!(f.mean()?, Some(mean));
+        let variance = ((n * n) as f64 - 1.0) / 12.0;
+        assert!((f.variance()?.unwrap_or_default() - variance).abs() < 1e-6 * variance);
+        Ok(())
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::thread;
+use std::time::Duration;
+
+use crate::error::WorkerError;
+use crate::pool::ThreadPool;
+use crate::task_group::TaskGroup;
+
+/// 默认的最大重试次数
+pub const DEFAULT_MAX_RETRIES: usize = 3;
+
+/// 默认的首次重试等待时间
+pub const DEFAULT_BACKOFF: Duration = Duration::from_millis(10);
+
+/// 带重试和死信处理的工作队列
+///
+/// 任务返回 `Err` 时在同一个工作线程上等待一段时间后重试，等待时间每次翻倍，
+/// 重试 `max_retries` 次后仍然失败的任务连同最后一次的错误被投递到死信通道。
+/// 重试期间会占用工作线程，适合偶发失败的 I/O 类任务
+///
+/// ```
+/// use concurrency::{ThreadPool, WorkQueue};
+///
+/// let pool = ThreadPool::new(2);
+/// let mut queue = WorkQueue::new(&pool).max_retries(1);
+/// queue.submit(|| Ok(()));
+/// let id = queue.submit(|| Err("unreachable host"));
+/// queue.join().unwrap();
+/// let dead = queue.dead_letters().try_recv().unwrap();
+/// assert_eq!((dead.id, dead.attempts, dead.error), (id, 2, "unreachable host"));
+/// ```
+pub struct WorkQueue<'a, E> {
+    group: TaskGroup<'a, ()>,
+    submitted: usize,
+    max_retries: usize,
+    backoff: Duration,
+    dead_tx: Sender<DeadLetter<E>>,
+    dead_rx: Receiver<DeadLetter<E>>,
+}
+
+/// 最终失败的任务
+///
+/// # 字段
+/// * `id`: `submit` 返回的任务编号
+/// * `attempts`: 总共执行的次数
+/// * `error`: 最后一次执行返回的错误
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeadLetter<E> {
+    pub id: usize,
+    pub attempts: usize,
+    pub error: E,
+}
+
+impl<'a, E: Send + 'static> WorkQueue<'a, E> {
+    /// 创建在 `pool` 上执行任务的工作队列
+    pub fn new(pool: &'a ThreadPool) -> Self {
+        let (dead_tx, dead_rx) = mpsc::channel();
+        Self {
+            group: TaskGroup::new(pool),
+            submitted: 0,
+            max_retries: DEFAULT_MAX_RETRIES,
+            backoff: DEFAULT_BACKOFF,
+            dead_tx,
+            dead_rx,
+        }
+    }
+
+    /// 设置失败后的最大重试次数，0 表示不重试
+    pub fn max_retries(mut self, max_retries: usize) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// 设置首次重试前的等待时间，之后每次翻倍
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// 提交任务，返回任务编号
+    pub fn submit<F>(&mut self, mut task: F) -> usize
+    where
+        F: FnMut() -> Result<(), E> + Send + 'static,
+    {
+        let (max_retries, mut backoff) = (self.max_retries, self.backoff);
+        let dead_tx = self.dead_tx.clone();
+        let id = self.submitted;
+        self.submitted += 1;
+        self.group.spawn(move || {
+            let mut attempts = 0;
+            loop {
+                attempts += 1;
+                let Err(error) = task() else {
+                    return;
+                };
+                if attempts > max_retries {
+                    let _ = dead_tx.send(DeadLetter {
+                        id,
+                        attempts,
+                        error,
+                    });
+                    return;
+                }
+                thread::sleep(backoff);
+                backoff = backoff.saturating_mul(2);
+            }
+        });
+        id
+    }
+
+    /// 死信通道，接收重试耗尽后仍然失败的任务
+    pub fn dead_letters(&self) -> &Receiver<DeadLetter<E>> {
+        &self.dead_rx
+    }
+
+    /// 等待所有已提交的任务结束（成功或进入死信通道）
+    ///
+    /// # 返回值
+    /// 有任务 panic 时返回第一个 panic 对应的错误，panic 的任务不会重试
+    pub fn join(&mut self) -> Result<(), WorkerError> {
+        self.group.join_all().into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_work_queue_retries_until_success() {
+        let pool = ThreadPool::new(2);
+        let mut queue = WorkQueue::new(&pool).backoff(Duration::from_millis(1));
+        let calls = Arc::new(AtomicUsize::new(0));
+        queue.submit({
+            let calls = calls.clone();
+            move || match calls.fetch_add(1, Ordering::SeqCst) {
+                0 | 1 => Err("flaky"),
+                _ => Ok(()),
+            }
+        });
+        queue.join().unwrap();
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+        assert!(queue.dead_letters().try_recv().is_err());
+    }
+
+    #[test]
+    fn test_work_queue_dead_letters() {
+        let pool = ThreadPool::new(2);
+        let mut queue = WorkQueue::new(&pool).max_retries(2).backoff(Duration::ZERO);
+        let ids: Vec<_> = (0..3)
+            .map(|i| queue.submit(move || if i == 1 { Err(i) } else { Ok(()) }))
+            .collect();
+        queue.submit(|| panic!("boom"));
+        assert!(queue.join().is_err());
+        let dead: Vec<_> = queue.dead_letters().try_iter().collect();
+        assert_eq!(
+            dead,
+            [DeadLetter {
+                id: ids[1],
+                attempts: 3,
+                error: 1
+            }]
+        );
+    }
+}